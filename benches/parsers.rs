@@ -0,0 +1,82 @@
+//! Times the nom and winnow parsers for 2023 day 2 on the same generated games,
+//! with `cargo bench --features winnow --bench parsers`.
+
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{
+    runner,
+    year2023::day2::{self, Game},
+};
+
+/// How many times each parser is run.
+const RUNS: usize = 50;
+
+/// How many games are generated, about ten times a real puzzle input.
+const GAMES: u32 = 1000;
+
+/// `GAMES` games in the puzzle's format, from a fixed seed so every run parses
+/// the same text.
+fn games() -> String {
+    let mut seed: u64 = 2;
+    let mut random = |range: u64| {
+        seed = (seed * 1_103_515_245 + 12_345) % 2_147_483_648;
+        seed % range
+    };
+    let mut text = String::new();
+    for id in 1..=GAMES {
+        let mut rounds = Vec::new();
+        for _ in 0..1 + random(6) {
+            let mut cubes = Vec::new();
+            for color in ["red", "green", "blue"] {
+                if random(3) > 0 {
+                    cubes.push(format!("{} {color}", 1 + random(20)));
+                }
+            }
+            if !cubes.is_empty() {
+                rounds.push(cubes.join(", "));
+            }
+        }
+        writeln!(text, "Game {id}: {}", rounds.join("; ")).unwrap();
+    }
+    text
+}
+
+/// The sorted times of `RUNS` runs of `parser` over `input`.
+fn measure(parser: fn(&str) -> anyhow::Result<Vec<Game>>, input: &str) -> Vec<Duration> {
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(parser(black_box(input)).unwrap());
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    times
+}
+
+fn main() {
+    let input = games();
+    assert_eq!(
+        day2::nom_games_parser(&input).unwrap(),
+        day2::winnow_games_parser(&input).unwrap()
+    );
+    println!("| Parser | min | p50 | p90 | max |");
+    println!("|---|---|---|---|---|");
+    for (name, parser) in [
+        ("nom", day2::nom_games_parser as fn(&str) -> _),
+        ("winnow", day2::winnow_games_parser),
+    ] {
+        let times = measure(parser, &input);
+        println!(
+            "| {name} | {:.2?} | {:.2?} | {:.2?} | {:.2?} |",
+            runner::percentile(&times, 0),
+            runner::percentile(&times, 50),
+            runner::percentile(&times, 90),
+            runner::percentile(&times, 100)
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Times every registered generator and solver on its saved puzzle input with
+//! criterion, as two series per part named `year/day D part P/generator` and
+//! `.../solver`. `cargo bench --bench solutions -- 2023/day` only runs the 2023
+//! days, and `-- solver` only the solvers.
+
+use std::{hint::black_box, time::Duration};
+
+use advent_of_code::{
+    input,
+    runner::{self, Stage},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Adds a generator and a solver benchmark for each registered `(year, day, part)`,
+/// skipping days whose input isn't saved and parts that fail on it. A run times its
+/// generator and solver itself, so each series adds up just its own stage.
+fn solutions(c: &mut Criterion) {
+    let mut solutions = runner::solutions();
+    solutions.sort_by_key(|solution| (solution.year, solution.day, solution.part));
+    for year in solutions.chunk_by(|a, b| a.year == b.year) {
+        let mut group = c.benchmark_group(year[0].year.to_string());
+        for day in year.chunk_by(|a, b| a.day == b.day) {
+            let Ok(input) = input::load(day[0].year, day[0].day) else {
+                eprintln!(
+                    "{} day {}: no saved input, skipped",
+                    day[0].year, day[0].day
+                );
+                continue;
+            };
+            for solution in day {
+                if let Err(e) = solution.run_on(&input) {
+                    eprintln!(
+                        "{} day {} part {}: {e:#}, skipped",
+                        solution.year, solution.day, solution.part
+                    );
+                    continue;
+                }
+                for stage in [Stage::Generator, Stage::Solver] {
+                    let name = format!(
+                        "day {} part {}/{}",
+                        solution.day,
+                        solution.part,
+                        stage.name()
+                    );
+                    group.bench_function(name, |b| {
+                        b.iter_custom(|iters| {
+                            (0..iters)
+                                .map(|_| {
+                                    let run = solution.run_on(black_box(&input));
+                                    stage.time(&run.expect("it ran before"))
+                                })
+                                .sum::<Duration>()
+                        });
+                    });
+                }
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
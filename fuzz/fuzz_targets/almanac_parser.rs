@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day5::{almanac_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Seeds and ranges are pushed through every map. Numbers past a u32, and maps
+//  whose ranges overlap or leave gaps, are what could break the shifting.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(almanac) = almanac_parser(input) {
+            let _ = part1(&almanac);
+            let _ = part2(&almanac);
+        }
+    }
+});
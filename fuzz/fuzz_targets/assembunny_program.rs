@@ -0,0 +1,24 @@
+#![no_main]
+
+use advent_of_code::assembunny::{parse, Vm};
+use libfuzzer_sys::fuzz_target;
+
+/// How many instructions to run, since a program can loop forever.
+const STEPS: usize = 1000;
+
+//The peephole pass looks for arithmetic loops at every address, and a `tgl`
+//  can rewrite the program and rerun it mid-run, so toggles landing on loops
+//  and on their own operands are worth trying.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(program) = parse(input) {
+            let mut vm = Vm::new(&program);
+            for _ in 0..STEPS {
+                if vm.is_halted() {
+                    break;
+                }
+                vm.step();
+            }
+        }
+    }
+});
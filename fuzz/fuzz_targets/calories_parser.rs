@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2022::day1::{calories_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Each elf's total is summed while reading, so an elf carrying more than a u32
+//  holds, or the top three together overflowing one, is the case to watch.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(totals) = calories_parser(input) {
+            let _ = part1(&totals);
+            let _ = part2(&totals);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day4::{cards_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Copies of later cards pile up as each card wins, and a card's points double
+//  with each match, so long piles and cards with many matches can overflow.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(cards) = cards_parser(input) {
+            let _ = part1(&cards);
+            let _ = part2(&cards);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day17::{city_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//The crucible searches turn limits over the grid, so blocks with no way to
+//  the far corner and one-wide cities must end without an answer, not a panic.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(city) = city_parser(input) {
+            let _ = part1(&city);
+            let _ = part2(&city);
+        }
+    }
+});
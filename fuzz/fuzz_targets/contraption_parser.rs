@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day16::{contraption_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Beams split and bounce off mirrors until they loop, and every edge tile is
+//  tried as an entry, so splitters aimed at each other are worth trying.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(contraption) = contraption_parser(input) {
+            let _ = part1(&contraption);
+            let _ = part2(&contraption);
+        }
+    }
+});
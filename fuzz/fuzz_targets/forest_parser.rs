@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2022::day8::{forest_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Views are measured from every tree to each edge, so forests of a single row
+//  or column, where every tree is on the edge, are the shapes to try.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(forest) = forest_parser(input) {
+            let _ = part1(&forest);
+            let _ = part2(&forest);
+        }
+    }
+});
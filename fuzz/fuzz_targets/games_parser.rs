@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day2::{games_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Cube counts add up within a round while parsing and multiply into each
+//  game's power, so huge counts and ids must come back as errors or `None`.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(games) = games_parser(input) {
+            let _ = part1(&games);
+            let _ = part2(&games);
+        }
+    }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use advent_of_code::year2023::day21::{garden_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Part two extrapolates from walks across several copies of the map, which
+//  needs a square map; any other shape, or a start boxed in by rocks, must not
+//  panic.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(garden) = garden_parser(input) {
+            let _ = part1(&garden);
+            let _ = part2(&garden);
+        }
+    }
+});
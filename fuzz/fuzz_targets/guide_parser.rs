@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2022::day2::{guide_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Every round scores the same handful of ways, so this mostly checks that
+//  letters and line endings outside A-C and X-Z are refused.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(rounds) = guide_parser(input) {
+            let _ = part1(&rounds);
+            let _ = part2(&rounds);
+        }
+    }
+});
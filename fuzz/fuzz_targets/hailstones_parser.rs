@@ -0,0 +1,16 @@
+#![no_main]
+
+use advent_of_code::year2023::day24::{hailstones_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Both parts solve for crossings exactly, with cross products of positions
+//  and velocities, so coordinates near the limits of an i64 must give `None`
+//  rather than overflow.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(hailstones) = hailstones_parser(input) {
+            let _ = part1(&hailstones);
+            let _ = part2(&hailstones);
+        }
+    }
+});
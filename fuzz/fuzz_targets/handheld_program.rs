@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::handheld::{parse, repair, run};
+use libfuzzer_sys::fuzz_target;
+
+//Repairing tries every flipped `jmp` and `nop`, and a run follows jumps that
+//  can land anywhere, so jumps far before or past the program are what matter.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(program) = parse(input) {
+            let _ = run(&program);
+            let _ = repair(&program);
+        }
+    }
+});
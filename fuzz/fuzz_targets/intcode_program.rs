@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::intcode::{disassemble, parse};
+use libfuzzer_sys::fuzz_target;
+
+//The disassembler walks any memory it is given, including opcodes and modes
+//  that don't decode. Programs aren't run here: memory grows to whatever
+//  address is written, so any program can ask for more than there is.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(program) = parse(input) {
+            let _ = disassemble(&program);
+        }
+    }
+});
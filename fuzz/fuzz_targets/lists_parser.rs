@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2024::day1::{lists_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Differences and similarity scores multiply and add location ids, so ids
+//  near the top of a u64 have to overflow into `None`.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(lists) = lists_parser(input) {
+            let _ = part1(&lists);
+            let _ = part2(&lists);
+        }
+    }
+});
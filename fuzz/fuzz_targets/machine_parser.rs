@@ -0,0 +1,16 @@
+#![no_main]
+
+use advent_of_code::year2023::day20::{machine_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Pulses are pushed through the modules a thousand times, then part two looks
+//  for the counters feeding `rx`, so machines without it, or wired oddly, must
+//  not panic.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(machine) = machine_parser(input) {
+            let _ = part1(&machine);
+            let _ = part2(&machine);
+        }
+    }
+});
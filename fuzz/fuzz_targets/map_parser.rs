@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day23::{map_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//The longest hike is found over the junctions of the trails, so mazes with
+//  many junctions, no exit, or slopes pointing nowhere are the shapes to try.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(map) = map_parser(input) {
+            let _ = part1(&map);
+            let _ = part2(&map);
+        }
+    }
+});
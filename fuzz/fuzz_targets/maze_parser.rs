@@ -0,0 +1,16 @@
+#![no_main]
+
+use advent_of_code::year2023::day10::{maze_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//The pipe under the start is worked out from its neighbours and the loop
+//  walked from there, so starts that join no loop, or pipes that lead off the
+//  map, must give `None`.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(maze) = maze_parser(input) {
+            let _ = part1(&maze);
+            let _ = part2(&maze);
+        }
+    }
+});
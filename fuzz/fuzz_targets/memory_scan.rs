@@ -0,0 +1,14 @@
+#![no_main]
+
+use advent_of_code::year2024::day3::{part1, part2, scan};
+use libfuzzer_sys::fuzz_target;
+
+//Anything can be scanned, so this throws arbitrary text, including cut-off
+//  `mul(` instructions and stray `do()`s, at the scanner and both parts.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let instructions = scan(input);
+        let _ = part1(&instructions);
+        let _ = part2(&instructions);
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2021::day24::monad_parser;
+use libfuzzer_sys::fuzz_target;
+
+//Finding a model number runs the program on the ALU to check it, so that has to
+//  cope with any program that parses, such as one whose arithmetic overflows.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(monad) = monad_parser(input) {
+            let _ = monad.model_number(true);
+            let _ = monad.model_number(false);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2022::day9::{motions_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Every step of every motion is simulated for each knot, so motion lengths
+//  are capped to keep a run quick; longer ones must be refused.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(motions) = motions_parser(input) {
+            let _ = part1(&motions);
+            let _ = part2(&motions);
+        }
+    }
+});
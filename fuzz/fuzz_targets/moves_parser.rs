@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2015::day3::{moves_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Every move is followed and each house visited is counted, so this mostly
+//  checks that characters other than the four arrows are refused.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(moves) = moves_parser(input) {
+            let _ = part1(&moves);
+            let _ = part2(&moves);
+        }
+    }
+});
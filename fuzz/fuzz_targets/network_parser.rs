@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day8::{network_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//The ghosts each walk until they loop, and their loops are combined with
+//  the least common multiple, which can overflow or never meet a `Z` node.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(network) = network_parser(input) {
+            let _ = part1(&network);
+            let _ = part2(&network);
+        }
+    }
+});
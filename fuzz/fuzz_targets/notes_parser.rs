@@ -0,0 +1,16 @@
+#![no_main]
+
+use advent_of_code::year2022::day22::{notes_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//The path is walked on the flat map and on the folded cube, so boards that
+//  can't fold, rows with no open tile and very long forward moves are the cases
+//  to try.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(notes) = notes_parser(input) {
+            let _ = part1(&notes);
+            let _ = part2(&notes);
+        }
+    }
+});
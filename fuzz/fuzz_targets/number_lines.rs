@@ -0,0 +1,19 @@
+#![no_main]
+
+use advent_of_code::{util::parse::number_lines, year2023::day9, year2024::day2};
+use libfuzzer_sys::fuzz_target;
+
+//The same rows of numbers feed 2023 day 9 and 2024 day 2. Extrapolating
+//  a history takes differences many times over, which soon overflow an i64.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(histories) = number_lines::<i64>(input) {
+            let _ = day9::part1(&histories);
+            let _ = day9::part2(&histories);
+        }
+        if let Ok(reports) = number_lines::<u32>(input) {
+            let _ = day2::part1(&reports);
+            let _ = day2::part2(&reports);
+        }
+    }
+});
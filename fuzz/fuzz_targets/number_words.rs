@@ -0,0 +1,13 @@
+#![no_main]
+
+use advent_of_code::year2023::day1::{part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//Both parts read digits, and part two spelled out numbers, straight from the
+//  text, so lines without any digit, or overlapping words, must not panic.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = part1(input);
+        let _ = part2(input);
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2022::day4::{pairs_parser, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+//The sections are only compared, so this mostly checks that ranges written
+//  backwards, or past a u32, don't trip anything up.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(pairs) = pairs_parser(input) {
+            let _ = part1(&pairs);
+            let _ = part2(&pairs);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day13::{part1, part2, patterns_parser};
+use libfuzzer_sys::fuzz_target;
+
+//Each pattern is checked for a mirror line with and without one smudge, so
+//  one-row, one-column and mirror-free patterns are the shapes to try.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(patterns) = patterns_parser(input) {
+            let _ = part1(&patterns);
+            let _ = part2(&patterns);
+        }
+    }
+});
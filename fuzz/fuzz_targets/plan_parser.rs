@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day18::{part1, part2, plan_parser};
+use libfuzzer_sys::fuzz_target;
+
+//The lagoon's area comes from the shoelace formula over every corner, with
+//  distances from both the plain and the colour-coded instructions.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(plan) = plan_parser(input) {
+            let _ = part1(&plan);
+            let _ = part2(&plan);
+        }
+    }
+});
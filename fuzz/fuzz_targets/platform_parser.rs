@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day14::{part1, part2, platform_parser};
+use libfuzzer_sys::fuzz_target;
+
+//Part two spins the platform until a layout repeats, so platforms whose
+//  cycle starts late, or that never move at all, matter.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(platform) = platform_parser(input) {
+            let _ = part1(&platform);
+            let _ = part2(&platform);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day7::{part1, part2, plays_parser};
+use libfuzzer_sys::fuzz_target;
+
+//The winnings multiply each bid by its rank, so very large bids have to
+//  give `None` instead of overflowing.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(plays) = plays_parser(input) {
+            let _ = part1(&plays);
+            let _ = part2(&plays);
+        }
+    }
+});
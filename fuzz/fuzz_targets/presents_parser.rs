@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2015::day2::{part1, part2, presents_parser};
+use libfuzzer_sys::fuzz_target;
+
+//Paper and ribbon both multiply the dimensions together, so presents too big
+//  for a u32 have to give `None` instead of overflowing.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(presents) = presents_parser(input) {
+            let _ = part1(&presents);
+            let _ = part2(&presents);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2022::day5::{part1, part2, procedure_parser};
+use libfuzzer_sys::fuzz_target;
+
+//The crates are moved a stack at a time, and moves are checked against the
+//  stacks as they are read, so no move can empty or name a missing stack.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(procedure) = procedure_parser(input) {
+            let _ = part1(&procedure);
+            let _ = part2(&procedure);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day6::{part1, part2, races_parser};
+use libfuzzer_sys::fuzz_target;
+
+//The winning holds are found from the roots of a quadratic, and part two
+//  joins the columns into one race, whose digits can overflow a u64.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(races) = races_parser(input) {
+            let _ = part1(&races);
+            let _ = part2(&races);
+        }
+    }
+});
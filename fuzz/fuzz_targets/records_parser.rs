@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day12::{part1, part2, records_parser};
+use libfuzzer_sys::fuzz_target;
+
+//The arrangements are counted with memoised recursion, and part two unfolds
+//  each record five times, so long rows of unknowns can count past a u64.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(records) = records_parser(input) {
+            let _ = part1(&records);
+            let _ = part2(&records);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2022::day10::{part1, part2, register_parser};
+use libfuzzer_sys::fuzz_target;
+
+//The X register is tracked through every cycle and multiplied by the cycle
+//  number, so values near the limits of an i64 must not overflow.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(values) = register_parser(input) {
+            let _ = part1(&values);
+            let _ = part2(&values);
+        }
+    }
+});
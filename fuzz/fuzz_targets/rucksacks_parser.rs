@@ -0,0 +1,16 @@
+#![no_main]
+
+use advent_of_code::year2022::day3::{part1, part2, rucksacks_parser};
+use libfuzzer_sys::fuzz_target;
+
+//Each rucksack is split in half and the elves grouped in threes, so halves
+//  or groups sharing no item, or more than one, and a short last group are the
+//  cases to try.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(rucksacks) = rucksacks_parser(input) {
+            let _ = part1(&rucksacks);
+            let _ = part2(&rucksacks);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2021::day19::{part1, part2, scanners_parser};
+use libfuzzer_sys::fuzz_target;
+
+//Each scanner is matched against the others in every rotation, which only
+//  stays cheap with coordinates below the parser's bound.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(scanners) = scanners_parser(input) {
+            let _ = part1(&scanners);
+            let _ = part2(&scanners);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::{util::grid::Grid, year2023::day3};
+use libfuzzer_sys::fuzz_target;
+
+//Numbers are read across each row of the schematic, so long runs of digits
+//  that don't fit in a u32 must give `None` instead of overflowing.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(schematic) = input.parse::<Grid<u8>>() {
+            let _ = day3::part1(&schematic);
+            let _ = day3::part2(&schematic);
+        }
+    }
+});
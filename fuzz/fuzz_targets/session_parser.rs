@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2022::day7::{part1, part2, session_parser};
+use libfuzzer_sys::fuzz_target;
+
+//Directory sizes are totalled while the session is read, so `cd`s above the
+//  root and sizes that overflow a u64 are the cases to try.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(sizes) = session_parser(input) {
+            let _ = part1(&sizes);
+            let _ = part2(&sizes);
+        }
+    }
+});
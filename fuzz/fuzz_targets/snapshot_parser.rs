@@ -0,0 +1,17 @@
+#![no_main]
+
+use advent_of_code::year2023::day22::{part1, part2, snapshot_parser, Stack};
+use libfuzzer_sys::fuzz_target;
+
+//The generator also lets the bricks fall, so that has to cope with any
+//  snapshot that parses, such as bricks that overlap or sit on the ground; then
+//  both parts knock each brick out in turn.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(bricks) = snapshot_parser(input) {
+            let stack = Stack::settle(bricks);
+            let _ = part1(&stack);
+            let _ = part2(&stack);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day15::{part1, part2, steps_parser};
+use libfuzzer_sys::fuzz_target;
+
+//Every step is hashed, and part two fills and empties the boxes, so labels
+//  that are removed before they are ever added are worth trying.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(steps) = steps_parser(input) {
+            let _ = part1(&steps);
+            let _ = part2(&steps);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use advent_of_code::year2023::day19::{part1, part2, system_parser};
+use libfuzzer_sys::fuzz_target;
+
+//Parts are sent through the workflows, and part two sends whole ranges of
+//  ratings, so ratings and thresholds near the limits of a u64 matter.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(system) = system_parser(input) {
+            let _ = part1(&system);
+            let _ = part2(&system);
+        }
+    }
+});
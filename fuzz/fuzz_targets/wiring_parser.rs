@@ -0,0 +1,14 @@
+#![no_main]
+
+use advent_of_code::year2023::day25::{part1, wiring_parser};
+use libfuzzer_sys::fuzz_target;
+
+//The wires to cut come from a minimum cut of the whole graph, so graphs that
+//  are already in pieces, or need more than three cuts, must give `None`.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(graph) = wiring_parser(input) {
+            let _ = part1(&graph);
+        }
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use advent_of_code::wrist_device::{parse_program, Device};
+use libfuzzer_sys::fuzz_target;
+
+/// How many instructions to run, since a program can loop forever.
+const STEPS: usize = 1000;
+
+//The shortcut finder looks for the divisor-summing loop anywhere in the
+//  program, and running it can jump or write anywhere and overflow.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(program) = parse_program(input) {
+            let _ = program.divisor_sum_loop();
+            let mut device = Device::new(&program);
+            for _ in 0..STEPS {
+                if device.is_halted() || device.step().is_err() {
+                    break;
+                }
+            }
+        }
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use advent_of_code::wrist_device::{parse_samples, resolve_opcodes};
+use libfuzzer_sys::fuzz_target;
+
+//Every sample is tried against every opcode, on register values the sample
+//  gives, which can be out of range or big enough to overflow.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok((samples, _)) = parse_samples(input) {
+            let _ = resolve_opcodes(&samples);
+        }
+    }
+});
@@ -0,0 +1,398 @@
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{i64 as number, line_ending, one_of, space1},
+    combinator::{all_consuming, map, opt},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, terminated},
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+/// An instruction argument: one of the registers `a` to `d`, or a constant.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operand {
+    Register(usize),
+    Value(i64),
+}
+
+/// An assembunny instruction, including `tgl` from 2016 day 23 and `out` from day 25.
+///
+/// `tgl` can turn an instruction into one whose operands make no sense, such as
+/// copying into a constant; such instructions are skipped.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Cpy(Operand, Operand),
+    Inc(Operand),
+    Dec(Operand),
+    Jnz(Operand, Operand),
+    Tgl(Operand),
+    Out(Operand),
+}
+
+impl Instruction {
+    /// The instruction `tgl` turns this one into.
+    #[must_use]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Inc(x) => Self::Dec(x),
+            Self::Dec(x) | Self::Tgl(x) | Self::Out(x) => Self::Inc(x),
+            Self::Jnz(x, y) => Self::Cpy(x, y),
+            Self::Cpy(x, y) => Self::Jnz(x, y),
+        }
+    }
+}
+
+/// A run of instructions the peephole pass found to be doing arithmetic by looping.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Shortcut {
+    /// `inc target; dec counter; jnz counter -2` adds `counter` to `target`.
+    Add { target: usize, counter: usize },
+    /// `cpy source inner` followed by an add loop on `inner` and then
+    /// `dec outer; jnz outer -5` adds `source * outer` to `target`.
+    Multiply {
+        source: Operand,
+        target: usize,
+        inner: usize,
+        outer: usize,
+    },
+}
+
+impl Shortcut {
+    /// The number of instructions the shortcut stands in for.
+    const fn len(self) -> usize {
+        match self {
+            Self::Add { .. } => 3,
+            Self::Multiply { .. } => 6,
+        }
+    }
+}
+
+/// Matches `inc target; dec counter; jnz counter -2`, in either order of the first two.
+fn add_loop(block: &[Instruction]) -> Option<(usize, usize)> {
+    use Instruction::{Dec, Inc, Jnz};
+    use Operand::{Register, Value};
+    match block {
+        [Inc(Register(target)), Dec(Register(counter)), Jnz(Register(test), Value(-2)), ..]
+        | [Dec(Register(counter)), Inc(Register(target)), Jnz(Register(test), Value(-2)), ..]
+            if test == counter && target != counter =>
+        {
+            Some((*target, *counter))
+        }
+        _ => None,
+    }
+}
+
+fn shortcut(block: &[Instruction]) -> Option<Shortcut> {
+    use Instruction::{Cpy, Dec, Jnz};
+    use Operand::{Register, Value};
+    if let [Cpy(source, Register(inner)), rest @ ..] = block {
+        if let (
+            Some((target, counter)),
+            [_, _, _, Dec(Register(outer)), Jnz(Register(test), Value(-5)), ..],
+        ) = (add_loop(rest), rest)
+        {
+            let distinct = counter == *inner
+                && test == outer
+                && ![target, *inner].contains(outer)
+                && ![Register(target), Register(*inner), Register(*outer)].contains(source);
+            if distinct {
+                return Some(Shortcut::Multiply {
+                    source: *source,
+                    target,
+                    inner: *inner,
+                    outer: *outer,
+                });
+            }
+        }
+    }
+    add_loop(block).map(|(target, counter)| Shortcut::Add { target, counter })
+}
+
+/// The peephole pass: the shortcut, if any, that can stand in for the
+/// instructions starting at each address.
+fn optimize(program: &[Instruction]) -> Vec<Option<Shortcut>> {
+    (0..program.len())
+        .map(|address| shortcut(&program[address..]))
+        .collect()
+}
+
+/// An assembunny computer with registers `a` to `d`, which wrap around on
+/// overflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vm {
+    program: Vec<Instruction>,
+    shortcuts: Vec<Option<Shortcut>>,
+    registers: [i64; 4],
+    ip: usize,
+}
+
+impl Vm {
+    #[must_use]
+    pub fn new(program: &[Instruction]) -> Self {
+        Self {
+            program: program.to_vec(),
+            shortcuts: optimize(program),
+            registers: [0; 4],
+            ip: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn register(&self, register: usize) -> i64 {
+        self.registers[register]
+    }
+
+    pub fn set_register(&mut self, register: usize, value: i64) {
+        self.registers[register] = value;
+    }
+
+    #[must_use]
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    #[must_use]
+    pub fn is_halted(&self) -> bool {
+        self.ip >= self.program.len()
+    }
+
+    const fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.registers[register],
+            Operand::Value(value) => value,
+        }
+    }
+
+    /// Runs the shortcut at the instruction pointer, if there is one and the
+    /// loop it replaces would run its usual course.
+    fn take_shortcut(&mut self) -> bool {
+        let Some(shortcut) = self.shortcuts[self.ip] else {
+            return false;
+        };
+        match shortcut {
+            Shortcut::Add { target, counter } => {
+                if self.registers[counter] <= 0 {
+                    return false;
+                }
+                self.registers[target] =
+                    self.registers[target].wrapping_add(self.registers[counter]);
+                self.registers[counter] = 0;
+            }
+            Shortcut::Multiply {
+                source,
+                target,
+                inner,
+                outer,
+            } => {
+                let source = self.value(source);
+                if source <= 0 || self.registers[outer] <= 0 {
+                    return false;
+                }
+                let product = source.wrapping_mul(self.registers[outer]);
+                self.registers[target] = self.registers[target].wrapping_add(product);
+                self.registers[inner] = 0;
+                self.registers[outer] = 0;
+            }
+        }
+        self.ip += shortcut.len();
+        true
+    }
+
+    /// Executes a single instruction, or a whole loop the peephole pass
+    /// recognised, returning anything it output.
+    pub fn step(&mut self) -> Option<i64> {
+        if self.is_halted() || self.take_shortcut() {
+            return None;
+        }
+        let mut output = None;
+        let mut next = self.ip + 1;
+        match self.program[self.ip] {
+            Instruction::Cpy(source, Operand::Register(target)) => {
+                self.registers[target] = self.value(source);
+            }
+            Instruction::Inc(Operand::Register(target)) => {
+                self.registers[target] = self.registers[target].wrapping_add(1);
+            }
+            Instruction::Dec(Operand::Register(target)) => {
+                self.registers[target] = self.registers[target].wrapping_sub(1);
+            }
+            Instruction::Jnz(test, offset) => {
+                if self.value(test) != 0 {
+                    //Jumping before the start halts the machine just like jumping past the end.
+                    next = self
+                        .ip
+                        .checked_add_signed(self.value(offset) as isize)
+                        .unwrap_or(usize::MAX);
+                }
+            }
+            Instruction::Tgl(offset) => {
+                let address = self.ip.checked_add_signed(self.value(offset) as isize);
+                if let Some(instruction) = address.and_then(|address| self.program.get_mut(address))
+                {
+                    *instruction = instruction.toggled();
+                    self.shortcuts = optimize(&self.program);
+                }
+            }
+            Instruction::Out(value) => output = Some(self.value(value)),
+            //Left over from a toggle, with a constant where a register should be.
+            Instruction::Cpy(..) | Instruction::Inc(_) | Instruction::Dec(_) => {}
+        }
+        self.ip = next;
+        output
+    }
+
+    /// Runs until the program halts.
+    pub fn run(&mut self) {
+        while !self.is_halted() {
+            self.step();
+        }
+    }
+
+    /// Runs until the program outputs something or halts.
+    pub fn next_output(&mut self) -> Option<i64> {
+        while !self.is_halted() {
+            if let Some(value) = self.step() {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+fn register_parser(input: &str) -> IResult<&str, usize> {
+    map(one_of("abcd"), |register| register as usize - 'a' as usize)(input)
+}
+
+fn operand_parser(input: &str) -> IResult<&str, Operand> {
+    alt((
+        map(register_parser, Operand::Register),
+        map(number, Operand::Value),
+    ))(input)
+}
+
+fn instruction_parser(input: &str) -> IResult<&str, Instruction> {
+    let pair = || separated_pair(operand_parser, space1, operand_parser);
+    alt((
+        map(preceded(tag("cpy "), pair()), |(x, y)| {
+            Instruction::Cpy(x, y)
+        }),
+        map(preceded(tag("inc "), operand_parser), Instruction::Inc),
+        map(preceded(tag("dec "), operand_parser), Instruction::Dec),
+        map(preceded(tag("jnz "), pair()), |(x, y)| {
+            Instruction::Jnz(x, y)
+        }),
+        map(preceded(tag("tgl "), operand_parser), Instruction::Tgl),
+        map(preceded(tag("out "), operand_parser), Instruction::Out),
+    ))(input)
+}
+
+fn program_parser(input: &str) -> IResult<&str, Vec<Instruction>> {
+    all_consuming(terminated(
+        separated_list1(line_ending, instruction_parser),
+        opt(line_ending),
+    ))(input)
+}
+
+/// Parses an assembunny program, one instruction per line.
+pub fn parse(input: &str) -> Result<Vec<Instruction>> {
+    complete(input, program_parser)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(input: &str, a: i64) -> Vm {
+        let mut vm = Vm::new(&parse(input).unwrap());
+        vm.set_register(0, a);
+        vm.run();
+        vm
+    }
+
+    #[test]
+    fn test_parse() {
+        let program = parse("cpy 41 a\njnz a -2\ntgl c\n").unwrap();
+        assert_eq!(
+            program,
+            vec![
+                Instruction::Cpy(Operand::Value(41), Operand::Register(0)),
+                Instruction::Jnz(Operand::Register(0), Operand::Value(-2)),
+                Instruction::Tgl(Operand::Register(2)),
+            ]
+        );
+        assert!(parse("mul a b").is_err());
+        assert!(parse("inc e").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        //2016 day 12.
+        let vm = run("cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a", 0);
+        assert_eq!(vm.register(0), 42);
+        //Registers wrap like a machine's would, and the add loop shortcut agrees.
+        let vm = run(
+            "cpy 9223372036854775807 a\ncpy 2 c\ninc a\ndec c\njnz c -2",
+            0,
+        );
+        assert_eq!(vm.register(0), i64::MIN + 1);
+    }
+
+    #[test]
+    fn test_toggle() {
+        //2016 day 23.
+        let vm = run("cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a", 0);
+        assert_eq!(vm.register(0), 3);
+    }
+
+    #[test]
+    fn test_shortcuts() {
+        let program = parse("cpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5").unwrap();
+        assert_eq!(
+            optimize(&program),
+            vec![
+                Some(Shortcut::Multiply {
+                    source: Operand::Register(1),
+                    target: 0,
+                    inner: 2,
+                    outer: 3,
+                }),
+                Some(Shortcut::Add {
+                    target: 0,
+                    counter: 2
+                }),
+                None,
+                None,
+                None,
+                None,
+            ]
+        );
+        //Copying into the outer counter would change the loop's meaning.
+        let program = parse("cpy d c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5").unwrap();
+        assert_eq!(optimize(&program)[0], None);
+    }
+
+    #[test]
+    fn test_multiply() {
+        //The shortcut must give the same answer as looping would.
+        let multiply = "cpy 7 b\ncpy 6 d\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5";
+        let vm = run(multiply, 3);
+        assert_eq!(vm.registers, [45, 7, 0, 0]);
+        let mut slow = Vm::new(&parse(multiply).unwrap());
+        slow.shortcuts.fill(None);
+        slow.set_register(0, 3);
+        slow.run();
+        assert_eq!(slow.registers, vm.registers);
+    }
+
+    #[test]
+    fn test_output() {
+        let mut vm = Vm::new(&parse("cpy 2 a\nout a\ndec a\njnz a -2").unwrap());
+        assert_eq!(vm.next_output(), Some(2));
+        assert_eq!(vm.next_output(), Some(1));
+        assert_eq!(vm.next_output(), None);
+        assert!(vm.is_halted());
+    }
+}
@@ -0,0 +1,232 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{
+    builder::RangedU64ValueParser, error::ErrorKind, Args, CommandFactory, Parser, Subcommand,
+    ValueEnum,
+};
+
+use crate::runner::{self, Solution, DEFAULT_YEAR, YEAR_VAR};
+
+/// Runs and times Advent of Code solutions. With no command, runs them.
+#[derive(Debug, PartialEq, Eq, Clone, Parser)]
+#[command(name = "aoc", args_conflicts_with_subcommands = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    run: Options,
+}
+
+impl Cli {
+    /// The command given, treating no command as `run`, or an error for options
+    /// that parse but don't make sense together.
+    pub fn into_command(self) -> clap::error::Result<Command> {
+        let command = self.command.unwrap_or(Command::Run(self.run));
+        if let Command::Run(options) = &command {
+            if options.output.is_some() && options.format != Format::Json {
+                return Err(Self::command()
+                    .error(ErrorKind::ArgumentConflict, "--output needs --format json"));
+            }
+        }
+        Ok(command)
+    }
+}
+
+/// What the `aoc` binary was asked to do.
+#[derive(Debug, PartialEq, Eq, Clone, Subcommand)]
+pub enum Command {
+    /// Runs the chosen solutions, or all of a year's if no day is given.
+    Run(Options),
+    /// Downloads a day's puzzle input, unless a good copy is already saved.
+    Fetch {
+        #[arg(long, env = YEAR_VAR, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Download the input even if it is already saved.
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Works out an answer on the saved input and sends it to the site.
+    Submit {
+        #[arg(long, env = YEAR_VAR, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+    },
+    /// Runs every solution for the year several times and writes a summary of the
+    /// timings.
+    Bench {
+        #[arg(long, env = YEAR_VAR, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long, default_value_t = DEFAULT_RUNS, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        runs: usize,
+        /// The directory to write the summary into.
+        #[arg(long, default_value = "target")]
+        dir: PathBuf,
+    },
+    /// Runs a day, then runs it again each time its source or input changes,
+    /// showing how the answers and timings moved.
+    Watch {
+        #[arg(long, env = YEAR_VAR, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+    },
+}
+
+/// How many times `aoc bench` runs each solution unless told otherwise.
+pub const DEFAULT_RUNS: usize = 10;
+
+/// Which solutions to work on and how.
+#[derive(Debug, PartialEq, Eq, Clone, Args)]
+pub struct Options {
+    /// Defaults to the `AOC_YEAR` environment variable, then 2023.
+    #[arg(long, env = YEAR_VAR, default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+    #[arg(long)]
+    pub day: Option<u8>,
+    #[arg(long)]
+    pub part: Option<u8>,
+    /// Input to read instead of the saved puzzle input.
+    #[arg(long, requires = "day")]
+    pub input: Option<PathBuf>,
+    /// Let generators that can skip malformed lines, and say so.
+    #[arg(long)]
+    pub lenient: bool,
+    /// Read the input a line at a time rather than whole, for solutions that can.
+    #[arg(long, conflicts_with = "profile")]
+    pub stream: bool,
+    /// Run every part of every day and show the results as a table.
+    #[arg(long, conflicts_with_all = ["day", "part"])]
+    pub all: bool,
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Where to write the results instead of standard output.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+    /// Draw a flamegraph of each day instead of printing answers.
+    #[arg(long, conflicts_with_all = ["all", "output"])]
+    pub profile: bool,
+}
+
+/// How `aoc run` shows its results.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// For people to read.
+    #[default]
+    Text,
+    /// A JSON array, for other tools to read.
+    Json,
+}
+
+impl Options {
+    /// The registered solutions these options pick, in day and part order.
+    pub fn solutions(&self) -> Result<Vec<Solution>> {
+        let year = self.year;
+        let solutions: Vec<Solution> = runner::for_year(year)
+            .into_iter()
+            .filter(|solution| self.day.is_none_or(|day| solution.day == day))
+            .filter(|solution| self.part.is_none_or(|part| solution.part == part))
+            .collect();
+        if solutions.is_empty() {
+            let mut wanted = year.to_string();
+            if let Some(day) = self.day {
+                wanted += &format!(" day {day}");
+            }
+            if let Some(part) = self.part {
+                wanted += &format!(" part {part}");
+            }
+            return Err(anyhow::anyhow!("no solutions registered for {wanted}"));
+        }
+        Ok(solutions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> clap::error::Result<Command> {
+        Cli::try_parse_from(std::iter::once("aoc").chain(args.iter().copied()))
+            .and_then(Cli::into_command)
+    }
+
+    #[test]
+    fn test_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_run() {
+        let command = parse(&[
+            "run", "--year", "2023", "--day=2", "--part", "1", "--input", "x.txt",
+        ])
+        .unwrap();
+        assert_eq!(
+            command,
+            Command::Run(Options {
+                year: 2023,
+                day: Some(2),
+                part: Some(1),
+                input: Some(PathBuf::from("x.txt")),
+                lenient: false,
+                stream: false,
+                all: false,
+                format: Format::Text,
+                output: None,
+                profile: false,
+            })
+        );
+        let Command::Run(options) = parse(&["--lenient", "--year", "2015"]).unwrap() else {
+            panic!("expected run");
+        };
+        assert_eq!(
+            (options.year, options.day, options.lenient),
+            (2015, None, true)
+        );
+    }
+
+    #[test]
+    fn test_run_errors() {
+        assert!(parse(&["run", "--input", "x.txt"]).is_err());
+        assert!(parse(&["run", "--all", "--day", "2"]).is_err());
+        assert!(parse(&["run", "--output", "out.json"]).is_err());
+        assert!(parse(&["run", "--format", "yaml"]).is_err());
+        assert!(parse(&["run", "--all", "--profile"]).is_err());
+        assert!(parse(&["run", "--stream", "--profile"]).is_err());
+        assert!(parse(&["run", "--all", "--format=json", "--output", "out.json"]).is_ok());
+    }
+
+    #[test]
+    fn test_bench() {
+        let Command::Bench { runs, dir, .. } = parse(&["bench"]).unwrap() else {
+            panic!("expected bench");
+        };
+        assert_eq!((runs, dir), (DEFAULT_RUNS, PathBuf::from("target")));
+        assert!(parse(&["bench", "--runs", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_solutions() {
+        let Command::Run(options) = parse(&["--year", "2023", "--day", "9"]).unwrap() else {
+            panic!("expected run");
+        };
+        let parts: Vec<u8> = options
+            .solutions()
+            .unwrap()
+            .iter()
+            .map(|s| s.part)
+            .collect();
+        assert_eq!(parts, vec![1, 2]);
+        let missing = Options {
+            day: Some(26),
+            ..options
+        };
+        let error = missing.solutions().unwrap_err();
+        assert_eq!(error.to_string(), "no solutions registered for 2023 day 26");
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    fmt,
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use crate::input;
+
+/// The environment variable holding the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The file the session cookie is read from when [`SESSION_VAR`] isn't set.
+pub const SESSION_FILE: &str = ".aoc-session";
+
+/// Tells the site who is making automated requests, as it asks.
+pub const USER_AGENT: &str = "github.com/pittengermdp/advent_of_code";
+
+const SITE: &str = "https://adventofcode.com";
+
+/// The session cookie, from [`SESSION_VAR`] or else [`SESSION_FILE`].
+pub fn session() -> Result<String> {
+    let session = match std::env::var(SESSION_VAR) {
+        Ok(session) => session,
+        Err(_) => std::fs::read_to_string(SESSION_FILE).with_context(|| {
+            format!("no session cookie: set {SESSION_VAR} or save it in {SESSION_FILE}")
+        })?,
+    };
+    let session = session.trim();
+    if session.is_empty() {
+        return Err(anyhow::anyhow!("the session cookie is empty"));
+    }
+    Ok(session.to_string())
+}
+
+/// A reply from the site.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    /// The body of a successful reply, or an error saying what went wrong for the
+    /// puzzle on `day` of `year`.
+    pub fn into_body(self, year: u16, day: u8) -> Result<String> {
+        match self.status {
+            200 => Ok(self.body),
+            404 => Err(anyhow::anyhow!(
+                "{year} day {day} isn't available, or hasn't unlocked yet"
+            )),
+            400 | 500 if self.body.contains("log in") => Err(anyhow::anyhow!(
+                "the session cookie has expired or is wrong; copy a new one from the site"
+            )),
+            status => Err(anyhow::anyhow!(
+                "adventofcode.com replied {status}: {}",
+                self.body.trim()
+            )),
+        }
+    }
+}
+
+/// Sends a request to `path` on the site, posting the `form` fields if there are
+/// any.
+pub fn request(path: &str, form: &[(&str, &str)], session: &str) -> Result<Response> {
+    let url = format!("{SITE}{path}");
+    let request = if form.is_empty() {
+        ureq::get(&url)
+    } else {
+        ureq::post(&url)
+    }
+    .set("Cookie", &format!("session={session}"))
+    .set("User-Agent", USER_AGENT);
+    let reply = if form.is_empty() {
+        request.call()
+    } else {
+        request.send_form(form)
+    };
+    let reply = match reply {
+        Ok(reply) | Err(ureq::Error::Status(_, reply)) => reply,
+        Err(e) => return Err(e).with_context(|| format!("requesting {url}")),
+    };
+    Ok(Response {
+        status: reply.status(),
+        body: reply.into_string().context("the reply wasn't text")?,
+    })
+}
+
+/// Why `text` can't be a puzzle input, if it can't: it is empty, or it is a web
+/// page saved by mistake.
+pub fn check_input(text: &str) -> Result<()> {
+    let start: String = text.trim_start().chars().take(9).collect();
+    let start = start.to_ascii_lowercase();
+    if text.trim().is_empty() {
+        Err(anyhow::anyhow!("the input is empty"))
+    } else if start.starts_with("<!doctype") || start.starts_with("<html") {
+        Err(anyhow::anyhow!(
+            "the input is a web page, not a puzzle input"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Where [`fetch`] got an input from.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Fetched {
+    /// A good copy was already saved.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Makes sure the puzzle input for `day` of `year` is saved where [`input::load`]
+/// looks for it. A saved copy that passes [`check_input`] is kept unless `refresh`
+/// is set; anything else is downloaded again.
+pub fn fetch(year: u16, day: u8, refresh: bool) -> Result<Fetched> {
+    let path = input::path(year, day);
+    if !refresh {
+        if let Ok(saved) = std::fs::read_to_string(&path) {
+            if check_input(&saved).is_ok() {
+                return Ok(Fetched::Cached(path));
+            }
+        }
+    }
+    let response = request(&format!("/{year}/day/{day}/input"), &[], &session()?)?;
+    let body = response.into_body(year, day)?;
+    check_input(&body).with_context(|| format!("downloading {year} day {day}"))?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, body).with_context(|| format!("writing {}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Right,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after the last answer, with how long is left to wait.
+    Wait(String),
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+    /// A reply that wasn't understood, kept to show.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict from the page the site replies with.
+    #[must_use]
+    pub fn from_page(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Self::Right
+        } else if page.contains("your answer is too high") {
+            Self::TooHigh
+        } else if page.contains("your answer is too low") {
+            Self::TooLow
+        } else if page.contains("That's not the right answer") {
+            Self::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            let left = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map_or("a while", |(left, _)| left);
+            Self::Wait(left.to_string())
+        } else if page.contains("Did you already complete it") {
+            Self::AlreadySolved
+        } else {
+            Self::Unknown(page.trim().chars().take(200).collect())
+        }
+    }
+
+    /// Whether the site actually judged the answer, so sending it again would get
+    /// the same verdict.
+    #[must_use]
+    pub const fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Right | Self::TooHigh | Self::TooLow | Self::Wrong
+        )
+    }
+
+    /// The name the verdict is recorded under in [`SUBMISSIONS_FILE`].
+    #[must_use]
+    pub const fn key(&self) -> &'static str {
+        match self {
+            Self::Right => "right",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::Wrong => "wrong",
+            Self::Wait(_) => "wait",
+            Self::AlreadySolved => "already-solved",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+
+    /// The final verdict recorded under `key`, if it is one.
+    #[must_use]
+    pub fn from_key(key: &str) -> Option<Self> {
+        [Self::Right, Self::TooHigh, Self::TooLow, Self::Wrong]
+            .into_iter()
+            .find(|verdict| verdict.key() == key)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Right => write!(f, "that's the right answer"),
+            Self::TooHigh => write!(f, "wrong, too high"),
+            Self::TooLow => write!(f, "wrong, too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::Wait(left) => write!(f, "answered too recently, wait {left}"),
+            Self::AlreadySolved => write!(f, "this part is already solved"),
+            Self::Unknown(page) => write!(f, "unexpected reply: {page}"),
+        }
+    }
+}
+
+/// Where every submitted answer and its verdict are recorded, one per line as
+/// tab-separated year, day, part, answer and verdict.
+pub const SUBMISSIONS_FILE: &str = "input/submissions.txt";
+
+/// The final verdict already recorded in `log` for `answer` to `part` of `day` of
+/// `year`, if it has been sent before.
+#[must_use]
+pub fn recorded(log: &str, year: u16, day: u8, part: u8, answer: &str) -> Option<Verdict> {
+    let key = [year.to_string(), day.to_string(), part.to_string()];
+    log.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            match fields[..] {
+                [y, d, p, a, verdict] if [y, d, p] == key && a == answer => {
+                    Verdict::from_key(verdict)
+                }
+                _ => None,
+            }
+        })
+        .next_back()
+}
+
+/// Submits `answer` for `part` of `day` of `year` and records the verdict. An
+/// answer that was already judged isn't sent again; its recorded verdict is
+/// returned instead.
+pub fn submit(year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+    let log = std::fs::read_to_string(SUBMISSIONS_FILE).unwrap_or_default();
+    if let Some(verdict) = recorded(&log, year, day, part, answer) {
+        return Ok(verdict);
+    }
+    //Open the log before sending, so a verdict is never lost to a missing folder.
+    if let Some(dir) = Path::new(SUBMISSIONS_FILE).parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(SUBMISSIONS_FILE)
+        .with_context(|| format!("opening {SUBMISSIONS_FILE}"))?;
+    let level = part.to_string();
+    let response = request(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+        &session()?,
+    )?;
+    let verdict = Verdict::from_page(&response.into_body(year, day)?);
+    writeln!(file, "{year}\t{day}\t{part}\t{answer}\t{}", verdict.key())?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16, body: &str) -> Response {
+        Response {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_into_body() {
+        assert_eq!(response(200, "1 2\n").into_body(2023, 9).unwrap(), "1 2\n");
+        let error = response(404, "Not Found").into_body(2023, 26).unwrap_err();
+        assert!(error.to_string().starts_with("2023 day 26 isn't available"));
+        let expired = response(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let error = expired.into_body(2023, 1).unwrap_err();
+        assert!(error.to_string().contains("session cookie has expired"));
+        let error = response(503, "busy\n").into_body(2023, 1).unwrap_err();
+        assert_eq!(error.to_string(), "adventofcode.com replied 503: busy");
+    }
+
+    #[test]
+    fn test_check_input() {
+        assert!(check_input("0 3 6 9\n").is_ok());
+        assert!(check_input("<.>\n").is_ok());
+        assert!(check_input("").is_err());
+        assert!(check_input(" \n\n").is_err());
+        assert!(check_input("<!DOCTYPE html>\n<html lang=\"en-us\">").is_err());
+        assert!(check_input("\n<html><head>").is_err());
+    }
+
+    #[test]
+    fn test_verdict() {
+        let page = |text: &str| format!("<main><article><p>{text}</p></article></main>");
+        assert_eq!(
+            Verdict::from_page(&page(
+                "That's the right answer!  You are one gold star closer."
+            )),
+            Verdict::Right
+        );
+        assert_eq!(
+            Verdict::from_page(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck..."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_page(&page(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have 39s left to wait."
+            )),
+            Verdict::Wait("39s".to_string())
+        );
+        assert_eq!(
+            Verdict::from_page(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::AlreadySolved
+        );
+        assert!(!Verdict::AlreadySolved.is_final());
+        assert_eq!(Verdict::from_key("too-low"), Some(Verdict::TooLow));
+        assert_eq!(Verdict::from_key("wait"), None);
+    }
+
+    #[test]
+    fn test_recorded() {
+        let log = "2023\t2\t1\t2000\ttoo-high\n2023\t2\t1\t8\twait\n2023\t2\t2\t8\tright\n";
+        assert_eq!(recorded(log, 2023, 2, 1, "2000"), Some(Verdict::TooHigh));
+        assert_eq!(recorded(log, 2023, 2, 1, "8"), None);
+        assert_eq!(recorded(log, 2023, 2, 2, "8"), Some(Verdict::Right));
+        assert_eq!(recorded(log, 2022, 2, 2, "8"), None);
+    }
+}
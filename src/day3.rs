@@ -1,18 +0,0 @@
-use anyhow::Result;
-
-#[aoc(day3, part1)]
-#[must_use]
-pub fn part1(input: &str) -> u32 {
-    todo!()
-}
-
-#[aoc(day3, part2)]
-#[must_use]
-pub fn part2(input: &str) -> u64 {
-    todo!()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-}
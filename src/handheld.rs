@@ -0,0 +1,197 @@
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{i32 as number, line_ending, space1},
+    combinator::value,
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+/// An instruction of the 2020 handheld game console's boot code.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Instruction {
+    pub operation: Operation,
+    pub argument: i64,
+}
+
+impl Instruction {
+    /// The instruction with `jmp` and `nop` swapped, or `None` for an `acc`.
+    #[must_use]
+    pub const fn flipped(self) -> Option<Self> {
+        let operation = match self.operation {
+            Operation::Acc => return None,
+            Operation::Jmp => Operation::Nop,
+            Operation::Nop => Operation::Jmp,
+        };
+        Some(Self {
+            operation,
+            argument: self.argument,
+        })
+    }
+}
+
+/// How a run of the boot code ended, with the accumulator at that point.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    /// Ran off the end onto the instruction just after the last one.
+    Terminated(i64),
+    /// Was about to run an instruction for the second time.
+    Looped(i64),
+    /// Jumped somewhere other than the program or just past its end.
+    OutOfBounds(i64),
+}
+
+/// Runs the boot code until it terminates or would go round a loop.
+///
+/// There is no other state than the accumulator and instruction pointer, so
+/// reaching the same instruction twice means running forever.
+#[must_use]
+pub fn run(program: &[Instruction]) -> Outcome {
+    let mut visited = vec![false; program.len()];
+    let mut accumulator = 0;
+    let mut ip = 0;
+    loop {
+        if ip == program.len() {
+            return Outcome::Terminated(accumulator);
+        }
+        let Some(seen) = visited.get_mut(ip) else {
+            return Outcome::OutOfBounds(accumulator);
+        };
+        if *seen {
+            return Outcome::Looped(accumulator);
+        }
+        *seen = true;
+        let Instruction {
+            operation,
+            argument,
+        } = program[ip];
+        let offset = match operation {
+            Operation::Acc => {
+                accumulator += argument;
+                1
+            }
+            Operation::Jmp => argument,
+            Operation::Nop => 1,
+        };
+        let Some(next) = ip.checked_add_signed(offset as isize) else {
+            return Outcome::OutOfBounds(accumulator);
+        };
+        ip = next;
+    }
+}
+
+/// Every program made by flipping a single `jmp` or `nop`, with the address flipped.
+pub fn mutations(program: &[Instruction]) -> impl Iterator<Item = (usize, Vec<Instruction>)> + '_ {
+    program
+        .iter()
+        .enumerate()
+        .filter_map(|(address, instruction)| {
+            let mut mutated = program.to_vec();
+            mutated[address] = instruction.flipped()?;
+            Some((address, mutated))
+        })
+}
+
+/// Finds the one flip that makes the boot code terminate, returning the address
+/// flipped and the final accumulator.
+#[must_use]
+pub fn repair(program: &[Instruction]) -> Option<(usize, i64)> {
+    mutations(program).find_map(|(address, mutated)| match run(&mutated) {
+        Outcome::Terminated(accumulator) => Some((address, accumulator)),
+        _ => None,
+    })
+}
+
+fn operation_parser(input: &str) -> IResult<&str, Operation> {
+    alt((
+        value(Operation::Acc, tag("acc")),
+        value(Operation::Jmp, tag("jmp")),
+        value(Operation::Nop, tag("nop")),
+    ))(input)
+}
+
+fn instruction_parser(input: &str) -> IResult<&str, Instruction> {
+    let (input, (operation, argument)) = separated_pair(operation_parser, space1, number)(input)?;
+    Ok((
+        input,
+        Instruction {
+            operation,
+            argument: i64::from(argument),
+        },
+    ))
+}
+
+/// Parses boot code, one instruction per line with a signed argument.
+///
+/// Arguments must fit in an `i32`: a run adds each at most once, so the
+/// accumulator then can't overflow.
+pub fn parse(input: &str) -> Result<Vec<Instruction>> {
+    complete(input, separated_list0(line_ending, instruction_parser))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn test_parse() {
+        let program = parse(EXAMPLE).unwrap();
+        assert_eq!(program.len(), 9);
+        assert_eq!(
+            program[7],
+            Instruction {
+                operation: Operation::Jmp,
+                argument: -4
+            }
+        );
+        assert!(parse("acc +2147483648").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let program = parse(EXAMPLE).unwrap();
+        assert_eq!(run(&program), Outcome::Looped(5));
+        assert_eq!(
+            run(&parse("acc +2\njmp -5").unwrap()),
+            Outcome::OutOfBounds(2)
+        );
+        assert_eq!(
+            run(&parse("jmp +2\nacc +1").unwrap()),
+            Outcome::Terminated(0)
+        );
+    }
+
+    #[test]
+    fn test_mutations() {
+        let program = parse(EXAMPLE).unwrap();
+        let flipped: Vec<usize> = mutations(&program).map(|(address, _)| address).collect();
+        assert_eq!(flipped, vec![0, 2, 4, 7]);
+    }
+
+    #[test]
+    fn test_repair() {
+        assert_eq!(repair(&parse(EXAMPLE).unwrap()), Some((7, 8)));
+        assert_eq!(repair(&parse("acc +1").unwrap()), None);
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    borrow::Cow,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+/// Where the puzzle input for `day` of `year` is kept: `input/<year>/day<day>.txt`.
+#[must_use]
+pub fn path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from("input")
+        .join(year.to_string())
+        .join(format!("day{day}.txt"))
+}
+
+/// Reads the puzzle input for `day` of `year`, [`normalize`]d.
+pub fn load(year: u16, day: u8) -> Result<String> {
+    read(&path(year, day))
+}
+
+/// Reads the input in the file at `path`, [`normalize`]d.
+pub fn read(path: &Path) -> Result<String> {
+    let input =
+        std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    Ok(normalize(&input).into_owned())
+}
+
+/// `input` tidied so generators see the same text whatever saved it: no byte order
+/// mark, `\n` line endings, and no blank lines at the end, though a last line still
+/// ends with a newline. Borrows `input` if it was already tidy.
+#[must_use]
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut body = input.trim_end_matches(['\r', '\n']);
+    //Lines holding only spaces count as blank too.
+    while let Some(start) = body.rfind('\n') {
+        if !body[start..].trim().is_empty() {
+            break;
+        }
+        body = body[..start].trim_end_matches(['\r', '\n']);
+    }
+    if body.trim().is_empty() {
+        return Cow::Borrowed("");
+    }
+    if !body.contains('\r') && input.len() == body.len() + 1 {
+        return Cow::Borrowed(input);
+    }
+    let mut tidy = body.replace("\r\n", "\n");
+    tidy.push('\n');
+    Cow::Owned(tidy)
+}
+
+/// Opens the input in the file at `path` to be read a line at a time, for inputs
+/// too big to hold in memory.
+pub fn open(path: &Path) -> Result<BufReader<File>> {
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    Ok(BufReader::new(file))
+}
+
+/// Parses each line of `reader` with `parse` as it is read, skipping blank lines.
+/// Errors say which line they came from.
+pub fn read_lines<R, T, F>(reader: R, mut parse: F) -> impl Iterator<Item = Result<T>>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T>,
+{
+    reader
+        .lines()
+        .zip(1..)
+        .filter(|(line, _)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(move |(line, number)| {
+            let line = line.with_context(|| format!("reading line {number}"))?;
+            parse(&line).with_context(|| format!("line {number}"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(path(2015, 7), PathBuf::from("input/2015/day7.txt"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert!(matches!(normalize("1\n2\n"), Cow::Borrowed("1\n2\n")));
+        assert_eq!(normalize("\u{feff}1\r\n2\r\n\r\n  \n"), "1\n2\n");
+        assert_eq!(normalize("1\n  2  "), "1\n  2  \n");
+        assert_eq!(normalize("  \n\n"), "");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_read_lines() {
+        let reader = "1\r\n\n22\n333\n".as_bytes();
+        let lengths: Vec<usize> = read_lines(reader, |line| Ok(line.len()))
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(lengths, vec![1, 2, 3]);
+        let mut numbers = read_lines("7\n\nx".as_bytes(), |line| Ok(line.parse::<u8>()?));
+        assert_eq!(numbers.next().unwrap().unwrap(), 7);
+        let error = numbers.next().unwrap().unwrap_err();
+        assert_eq!(error.to_string(), "line 3");
+        assert!(numbers.next().is_none());
+        assert!(open(&path(1999, 1)).is_err());
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let error = load(1999, 1).unwrap_err();
+        assert!(error.to_string().contains("input/1999/day1.txt"));
+    }
+}
@@ -0,0 +1,103 @@
+use super::{Instruction, Mode, Opcode};
+
+impl Opcode {
+    /// The mnemonic used when listing programs.
+    #[must_use]
+    pub const fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Multiply => "mul",
+            Self::Input => "in",
+            Self::Output => "out",
+            Self::JumpIfTrue => "jt",
+            Self::JumpIfFalse => "jf",
+            Self::LessThan => "lt",
+            Self::Equals => "eq",
+            Self::AdjustRelativeBase => "arb",
+            Self::Halt => "hlt",
+        }
+    }
+}
+
+/// Renders a parameter: `[12]` for position mode, `12` for immediate and
+/// `[rb+12]` for relative.
+fn parameter(value: i64, mode: Mode) -> String {
+    match mode {
+        Mode::Position => format!("[{value}]"),
+        Mode::Immediate => value.to_string(),
+        Mode::Relative if value < 0 => format!("[rb{value}]"),
+        Mode::Relative => format!("[rb+{value}]"),
+    }
+}
+
+/// Lists the instruction at `address`, returning it with the number of values it
+/// takes up. Anything that isn't a valid instruction is listed as a single value
+/// of data.
+#[must_use]
+pub fn disassemble_at(memory: &[i64], address: usize) -> (String, usize) {
+    let value = |address: usize| memory.get(address).copied().unwrap_or_default();
+    let Ok(Instruction { opcode, modes }) = Instruction::decode(value(address)) else {
+        return (format!("data {}", value(address)), 1);
+    };
+    let parameters: Vec<String> = modes
+        .iter()
+        .take(opcode.arity())
+        .enumerate()
+        .map(|(offset, &mode)| parameter(value(address + 1 + offset), mode))
+        .collect();
+    let text = if parameters.is_empty() {
+        opcode.mnemonic().to_string()
+    } else {
+        format!("{} {}", opcode.mnemonic(), parameters.join(", "))
+    };
+    (text, 1 + opcode.arity())
+}
+
+/// Lists a whole program, one instruction per line, each prefixed with its
+/// address.
+///
+/// The listing sweeps straight through memory, so data stored after the code
+/// may be shown as whatever instructions it happens to decode as.
+#[must_use]
+pub fn disassemble(program: &[i64]) -> String {
+    let width = program.len().saturating_sub(1).to_string().len();
+    let mut listing = String::new();
+    let mut address = 0;
+    while address < program.len() {
+        let (text, length) = disassemble_at(program, address);
+        listing.push_str(&format!("{address:>width$}: {text}\n"));
+        address += length;
+    }
+    listing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disassemble_at() {
+        assert_eq!(
+            disassemble_at(&[1002, 4, 3, 4], 0),
+            ("mul [4], 3, [4]".to_string(), 4)
+        );
+        assert_eq!(disassemble_at(&[204, -1], 0), ("out [rb-1]".to_string(), 2));
+        assert_eq!(disassemble_at(&[109, 19], 0), ("arb 19".to_string(), 2));
+        assert_eq!(disassemble_at(&[42], 0), ("data 42".to_string(), 1));
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let listing = disassemble(&[1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]);
+        assert_eq!(
+            listing,
+            " 0: add [9], [10], [3]
+ 4: mul [3], [11], [0]
+ 8: hlt
+ 9: data 30
+10: data 40
+11: data 50
+"
+        );
+    }
+}
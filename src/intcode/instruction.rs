@@ -0,0 +1,100 @@
+use anyhow::Result;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Opcode {
+    /// The number of parameters that follow the opcode.
+    #[must_use]
+    pub const fn arity(self) -> usize {
+        match self {
+            Self::Add | Self::Multiply | Self::LessThan | Self::Equals => 3,
+            Self::JumpIfTrue | Self::JumpIfFalse => 2,
+            Self::Input | Self::Output | Self::AdjustRelativeBase => 1,
+            Self::Halt => 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+/// An opcode together with the mode of each of its parameters.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    /// Splits a value into its opcode, in the last two digits, and the parameter
+    /// modes, one per digit above that from the first parameter up.
+    pub fn decode(value: i64) -> Result<Self> {
+        if value < 0 {
+            return Err(anyhow::anyhow!("negative instruction {value}"));
+        }
+        let opcode = match value % 100 {
+            1 => Opcode::Add,
+            2 => Opcode::Multiply,
+            3 => Opcode::Input,
+            4 => Opcode::Output,
+            5 => Opcode::JumpIfTrue,
+            6 => Opcode::JumpIfFalse,
+            7 => Opcode::LessThan,
+            8 => Opcode::Equals,
+            9 => Opcode::AdjustRelativeBase,
+            99 => Opcode::Halt,
+            other => return Err(anyhow::anyhow!("unknown opcode {other} in {value}")),
+        };
+        let mut modes = [Mode::Position; 3];
+        let mut digits = value / 100;
+        for mode in &mut modes {
+            *mode = match digits % 10 {
+                0 => Mode::Position,
+                1 => Mode::Immediate,
+                2 => Mode::Relative,
+                other => return Err(anyhow::anyhow!("unknown mode {other} in {value}")),
+            };
+            digits /= 10;
+        }
+        if digits != 0 {
+            return Err(anyhow::anyhow!("too many modes in {value}"));
+        }
+        Ok(Self { opcode, modes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            Instruction::decode(1002).unwrap(),
+            Instruction {
+                opcode: Opcode::Multiply,
+                modes: [Mode::Position, Mode::Immediate, Mode::Position]
+            }
+        );
+        assert_eq!(Instruction::decode(204).unwrap().modes[0], Mode::Relative);
+        assert!(Instruction::decode(42).is_err());
+        assert!(Instruction::decode(302).is_err());
+        assert!(Instruction::decode(-1).is_err());
+    }
+}
@@ -0,0 +1,411 @@
+mod disassemble;
+mod instruction;
+
+use std::{
+    collections::{BTreeSet, VecDeque},
+    sync::mpsc::{Receiver, Sender},
+};
+
+use anyhow::Result;
+use nom::{
+    character::complete::{char, i64 as number, multispace0},
+    multi::separated_list1,
+    sequence::{preceded, terminated},
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+pub use disassemble::{disassemble, disassemble_at};
+pub use instruction::{Instruction, Mode, Opcode};
+
+/// Why the machine stopped running.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum State {
+    Halted,
+    /// The next instruction reads input, but none is queued.
+    AwaitingInput,
+    /// The next instruction is at this breakpoint.
+    Breakpoint(usize),
+}
+
+/// An Intcode computer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vm {
+    memory: Vec<i64>,
+    ip: usize,
+    relative_base: i64,
+    input: VecDeque<i64>,
+    output: VecDeque<i64>,
+    halted: bool,
+    breakpoints: BTreeSet<usize>,
+}
+
+impl Vm {
+    #[must_use]
+    pub fn new(program: &[i64]) -> Self {
+        Self {
+            memory: program.to_vec(),
+            ip: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+            halted: false,
+            breakpoints: BTreeSet::new(),
+        }
+    }
+
+    /// Memory as far as it has been written or loaded. Reads beyond the end are 0.
+    #[must_use]
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    #[must_use]
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or_default()
+    }
+
+    /// Writes to memory, growing it if need be.
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+    }
+
+    #[must_use]
+    pub const fn ip(&self) -> usize {
+        self.ip
+    }
+
+    #[must_use]
+    pub const fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    #[must_use]
+    pub const fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    pub fn extend_input(&mut self, values: impl IntoIterator<Item = i64>) {
+        self.input.extend(values);
+    }
+
+    /// The oldest output not yet taken.
+    pub fn pop_output(&mut self) -> Option<i64> {
+        self.output.pop_front()
+    }
+
+    /// Every output not yet taken, oldest first.
+    pub fn take_output(&mut self) -> Vec<i64> {
+        self.output.drain(..).collect()
+    }
+
+    /// The address a parameter refers to, for modes that refer to one.
+    fn address(&self, offset: usize, mode: Mode) -> Result<usize> {
+        let parameter = self.read(self.ip + offset);
+        let address = match mode {
+            Mode::Position => parameter,
+            Mode::Relative => self.relative_base + parameter,
+            Mode::Immediate => {
+                return Err(anyhow::anyhow!(
+                    "immediate mode parameter used as an address at {}",
+                    self.ip
+                ))
+            }
+        };
+        usize::try_from(address)
+            .map_err(|_| anyhow::anyhow!("negative address {address} at {}", self.ip))
+    }
+
+    fn parameter(&self, offset: usize, mode: Mode) -> Result<i64> {
+        match mode {
+            Mode::Immediate => Ok(self.read(self.ip + offset)),
+            _ => Ok(self.read(self.address(offset, mode)?)),
+        }
+    }
+
+    /// Makes `run` stop before executing the instruction at `address`.
+    pub fn set_breakpoint(&mut self, address: usize) {
+        self.breakpoints.insert(address);
+    }
+
+    pub fn clear_breakpoint(&mut self, address: usize) {
+        self.breakpoints.remove(&address);
+    }
+
+    /// The instruction about to be executed, as the disassembler would list it.
+    #[must_use]
+    pub fn current_instruction(&self) -> String {
+        disassemble_at(&self.memory, self.ip).0
+    }
+
+    /// Executes a single instruction, returning the state if it couldn't run or
+    /// halted the machine.
+    pub fn step(&mut self) -> Result<Option<State>> {
+        if self.halted {
+            return Ok(Some(State::Halted));
+        }
+        let Instruction { opcode, modes } = Instruction::decode(self.read(self.ip))
+            .map_err(|e| anyhow::anyhow!("at {}: {e}", self.ip))?;
+        let mut next = self.ip + 1 + opcode.arity();
+        match opcode {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => {
+                let (a, b) = (self.parameter(1, modes[0])?, self.parameter(2, modes[1])?);
+                let value = match opcode {
+                    Opcode::Add => a + b,
+                    Opcode::Multiply => a * b,
+                    Opcode::LessThan => i64::from(a < b),
+                    _ => i64::from(a == b),
+                };
+                let target = self.address(3, modes[2])?;
+                self.write(target, value);
+            }
+            Opcode::Input => {
+                let Some(value) = self.input.pop_front() else {
+                    return Ok(Some(State::AwaitingInput));
+                };
+                let target = self.address(1, modes[0])?;
+                self.write(target, value);
+            }
+            Opcode::Output => {
+                let value = self.parameter(1, modes[0])?;
+                self.output.push_back(value);
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.parameter(1, modes[0])? != 0;
+                if condition == (opcode == Opcode::JumpIfTrue) {
+                    let target = self.parameter(2, modes[1])?;
+                    next = usize::try_from(target)
+                        .map_err(|_| anyhow::anyhow!("jump to {target} at {}", self.ip))?;
+                }
+            }
+            Opcode::AdjustRelativeBase => self.relative_base += self.parameter(1, modes[0])?,
+            Opcode::Halt => {
+                self.halted = true;
+                return Ok(Some(State::Halted));
+            }
+        }
+        self.ip = next;
+        Ok(None)
+    }
+
+    /// Runs until the program halts, needs input that hasn't been queued, or
+    /// reaches a breakpoint. Resuming from a breakpoint always executes at least
+    /// the instruction there.
+    pub fn run(&mut self) -> Result<State> {
+        loop {
+            if let Some(state) = self.step()? {
+                return Ok(state);
+            }
+            if self.breakpoints.contains(&self.ip) {
+                return Ok(State::Breakpoint(self.ip));
+            }
+        }
+    }
+
+    /// Runs the program to completion on `input`, returning everything it output.
+    pub fn run_with_input(&mut self, input: impl IntoIterator<Item = i64>) -> Result<Vec<i64>> {
+        self.extend_input(input);
+        loop {
+            match self.run()? {
+                State::Halted => return Ok(self.take_output()),
+                State::AwaitingInput => {
+                    return Err(anyhow::anyhow!("ran out of input at {}", self.ip))
+                }
+                State::Breakpoint(_) => {}
+            }
+        }
+    }
+
+    /// Runs the program to completion, blocking on `input` whenever it needs more
+    /// and sending each output as soon as the program stops for input or halts.
+    ///
+    /// This lets several machines run on their own threads, wired together.
+    pub fn run_with_channels(&mut self, input: &Receiver<i64>, output: &Sender<i64>) -> Result<()> {
+        loop {
+            let state = self.run()?;
+            for value in self.output.drain(..) {
+                output
+                    .send(value)
+                    .map_err(|_| anyhow::anyhow!("output channel closed"))?;
+            }
+            match state {
+                State::Halted => return Ok(()),
+                State::AwaitingInput => {
+                    let value = input
+                        .recv()
+                        .map_err(|_| anyhow::anyhow!("input channel closed at {}", self.ip))?;
+                    self.push_input(value);
+                }
+                State::Breakpoint(_) => {}
+            }
+        }
+    }
+}
+
+fn program_parser(input: &str) -> IResult<&str, Vec<i64>> {
+    terminated(separated_list1(char(','), number), multispace0)(input)
+}
+
+/// Parses a comma separated Intcode program.
+pub fn parse(input: &str) -> Result<Vec<i64>> {
+    complete(input, preceded(multispace0, program_parser))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, thread};
+
+    use super::*;
+
+    fn program(input: &str) -> Vec<i64> {
+        parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(program("1,0,-3,99\n"), vec![1, 0, -3, 99]);
+        assert!(parse("1,,2").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn test_add_and_multiply() {
+        //2019 day 2.
+        let mut vm = Vm::new(&program("1,9,10,3,2,3,11,0,99,30,40,50"));
+        assert_eq!(vm.run().unwrap(), State::Halted);
+        assert_eq!(vm.read(0), 3500);
+        for (start, end) in [
+            ("1,0,0,0,99", "2,0,0,0,99"),
+            ("2,4,4,5,99,0", "2,4,4,5,99,9801"),
+            ("1,1,1,4,99,5,6,0,99", "30,1,1,4,2,5,6,0,99"),
+        ] {
+            let mut vm = Vm::new(&program(start));
+            vm.run().unwrap();
+            assert_eq!(vm.memory(), program(end));
+        }
+    }
+
+    #[test]
+    fn test_modes() {
+        //2019 day 5.
+        let mut vm = Vm::new(&program("1002,4,3,4,33"));
+        vm.run().unwrap();
+        assert_eq!(vm.read(4), 99);
+        let mut vm = Vm::new(&program("1101,100,-1,4,0"));
+        vm.run().unwrap();
+        assert_eq!(vm.read(4), 99);
+    }
+
+    #[test]
+    fn test_input_output() {
+        let echo = program("3,0,4,0,99");
+        assert_eq!(Vm::new(&echo).run_with_input([42]).unwrap(), vec![42]);
+        let mut vm = Vm::new(&echo);
+        assert_eq!(vm.run().unwrap(), State::AwaitingInput);
+        vm.push_input(7);
+        assert_eq!(vm.run().unwrap(), State::Halted);
+        assert_eq!(vm.pop_output(), Some(7));
+        assert!(Vm::new(&echo).run_with_input([]).is_err());
+    }
+
+    #[test]
+    fn test_comparisons_and_jumps() {
+        //2019 day 5: 999 below 8, 1000 for 8 and 1001 above.
+        let compare = program(
+            "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+             1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+             999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+        );
+        for (input, expected) in [(7, 999), (8, 1000), (9, 1001)] {
+            assert_eq!(
+                Vm::new(&compare).run_with_input([input]).unwrap(),
+                vec![expected]
+            );
+        }
+        let equal_to_eight = program("3,9,8,9,10,9,4,9,99,-1,8");
+        assert_eq!(
+            Vm::new(&equal_to_eight).run_with_input([8]).unwrap(),
+            vec![1]
+        );
+        let less_than_eight = program("3,3,1107,-1,8,3,4,3,99");
+        assert_eq!(
+            Vm::new(&less_than_eight).run_with_input([9]).unwrap(),
+            vec![0]
+        );
+        let is_nonzero = program("3,3,1105,-1,9,1101,0,0,12,4,12,99,1");
+        assert_eq!(Vm::new(&is_nonzero).run_with_input([0]).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_relative_base() {
+        //2019 day 9.
+        let quine = program("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+        assert_eq!(Vm::new(&quine).run_with_input([]).unwrap(), quine);
+        let large = program("1102,34915192,34915192,7,4,7,99,0");
+        let output = Vm::new(&large).run_with_input([]).unwrap();
+        assert_eq!(output[0].to_string().len(), 16);
+        let large = program("104,1125899906842624,99");
+        assert_eq!(
+            Vm::new(&large).run_with_input([]).unwrap(),
+            vec![1_125_899_906_842_624]
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let mut vm = Vm::new(&program("1,9,10,3,2,3,11,0,99,30,40,50"));
+        assert_eq!(vm.current_instruction(), "add [9], [10], [3]");
+        assert_eq!(vm.step().unwrap(), None);
+        assert_eq!((vm.ip(), vm.read(3)), (4, 70));
+        assert_eq!(vm.step().unwrap(), None);
+        assert_eq!(vm.step().unwrap(), Some(State::Halted));
+        assert!(vm.is_halted());
+    }
+
+    #[test]
+    fn test_breakpoint() {
+        let mut vm = Vm::new(&program("104,1,104,2,104,3,99"));
+        vm.set_breakpoint(4);
+        assert_eq!(vm.run().unwrap(), State::Breakpoint(4));
+        assert_eq!(vm.take_output(), vec![1, 2]);
+        assert_eq!(vm.current_instruction(), "out 3");
+        assert_eq!(vm.run().unwrap(), State::Halted);
+        assert_eq!(vm.take_output(), vec![3]);
+
+        let mut vm = Vm::new(&program("104,1,104,2,104,3,99"));
+        vm.set_breakpoint(4);
+        vm.clear_breakpoint(4);
+        assert_eq!(vm.run_with_input([]).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(Vm::new(&program("42")).run().is_err());
+        assert!(Vm::new(&program("1101,1,1,-1,99")).run().is_err());
+        assert!(Vm::new(&program("11101,1,1,0,99")).run().is_err());
+    }
+
+    #[test]
+    fn test_channels() {
+        //Two machines that each add one to what they're given, wired in a line.
+        let increment = program("3,9,1001,9,1,9,4,9,99,0");
+        let (to_first, first_input) = mpsc::channel();
+        let (to_second, second_input) = mpsc::channel();
+        let (to_main, results) = mpsc::channel();
+        let increment = &increment;
+        thread::scope(|scope| {
+            scope.spawn(move || Vm::new(increment).run_with_channels(&first_input, &to_second));
+            scope.spawn(move || Vm::new(increment).run_with_channels(&second_input, &to_main));
+            to_first.send(40).unwrap();
+        });
+        assert_eq!(results.recv().unwrap(), 42);
+    }
+}
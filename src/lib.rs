@@ -4,8 +4,22 @@ extern crate aoc_runner;
 extern crate aoc_runner_derive;
 extern crate crypto;
 
-pub mod day1;
-pub mod day2;
-pub mod day3;
+pub mod assembunny;
+pub mod cli;
+pub mod client;
+pub mod handheld;
+pub mod input;
+pub mod intcode;
+pub mod prelude;
+pub mod profile;
+pub mod runner;
+pub mod util;
+pub mod watch;
+pub mod wrist_device;
+pub mod year2015;
+pub mod year2021;
+pub mod year2022;
+pub mod year2023;
+pub mod year2024;
 
 aoc_lib! { year = 2023 }
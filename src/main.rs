@@ -1,7 +1,139 @@
 extern crate advent_of_code;
-extern crate aoc_runner;
-extern crate aoc_runner_derive;
 
-use aoc_runner_derive::aoc_main;
+use std::path::Path;
 
-aoc_main! { lib = advent_of_code }
+use anyhow::Context;
+use clap::Parser;
+
+use advent_of_code::{
+    cli::{Cli, Command, Format, Options},
+    client::{self, Fetched},
+    input, profile,
+    runner::{self, Benchmark, Outcome},
+    watch,
+};
+
+/// Runs registered solutions on their saved inputs, or on `--input`. The year comes
+/// from `--year`, then the `AOC_YEAR` environment variable, then defaults to 2023;
+/// with no `--day`, every day of the year is run. With `--lenient`, generators that
+/// can skip malformed lines and say so, and with `--stream` solvers read the input a
+/// line at a time instead of loading it whole, where they can. With `--all` the
+/// results are shown as a table of answers and timings. `--format json` writes them
+/// as JSON instead, to `--output` if given. `aoc fetch --day D` downloads an input,
+/// and `aoc submit --day D --part P` sends an answer. `aoc bench` times every
+/// solution over several runs and writes a summary under `target/`, and
+/// `aoc watch --day D` re-runs a day whenever its source or input changes.
+fn main() -> anyhow::Result<()> {
+    let command = Cli::parse().into_command().unwrap_or_else(|e| e.exit());
+    match command {
+        Command::Run(options) => run(&options),
+        Command::Fetch { year, day, refresh } => {
+            match client::fetch(year, day, refresh)? {
+                Fetched::Cached(path) => println!("Already saved at {}", path.display()),
+                Fetched::Downloaded(path) => {
+                    println!("Saved {year} day {day} to {}", path.display());
+                }
+            }
+            Ok(())
+        }
+        Command::Submit { year, day, part } => {
+            let solution = runner::find(year, day, part).with_context(|| {
+                format!("no solution registered for {year} day {day} part {part}")
+            })?;
+            let answer = solution.run_on_input()?.answer;
+            println!("{year} day {day} part {part}: {answer}");
+            println!("{}", client::submit(year, day, part, &answer)?);
+            Ok(())
+        }
+        Command::Bench { year, runs, dir } => bench(year, runs, &dir),
+        Command::Watch { year, day } => watch::watch(year, day),
+    }
+}
+
+fn run(options: &Options) -> anyhow::Result<()> {
+    runner::set_lenient(options.lenient);
+    let solutions = options.solutions()?;
+    if options.profile {
+        for day in solutions.chunk_by(|a, b| a.day == b.day) {
+            let (year, number) = (options.year, day[0].day);
+            let input = match &options.input {
+                Some(path) => input::read(path)?,
+                None => input::load(year, number)?,
+            };
+            let path = profile::record(year, number, day, &input)?;
+            println!("Profiled day {number}: {}", path.display());
+        }
+        return Ok(());
+    }
+    let text = options.format == Format::Text;
+    if text {
+        println!("Advent of code {}", options.year);
+    }
+    let mut outcomes = Vec::new();
+    for solution in solutions {
+        let run = match (&options.input, options.stream) {
+            (Some(path), true) => solution.stream_file(path),
+            (None, true) => solution.stream_file(&input::path(solution.year, solution.day)),
+            (Some(path), false) => solution.run_on_file(path),
+            (None, false) => solution.run_on_input(),
+        };
+        match &run {
+            Ok(run) if text && !options.all => println!(
+                "Day {} - Part {}: {}\n\tgenerator: {:?}, solver: {:?}\n",
+                solution.day, solution.part, run.answer, run.generator, run.solver
+            ),
+            Ok(_) => (),
+            Err(e) => eprintln!(
+                "Day {} - Part {}: FAILED:\n{e:#}\n",
+                solution.day, solution.part
+            ),
+        }
+        for warning in runner::take_warnings() {
+            eprintln!("Day {} - Part {}: {warning}\n", solution.day, solution.part);
+        }
+        outcomes.push(Outcome { solution, run });
+    }
+    match options.format {
+        Format::Text if options.all => print!("{}", runner::timing_table(&outcomes)),
+        Format::Text => (),
+        Format::Json => {
+            let json = runner::json(&outcomes);
+            match &options.output {
+                Some(path) => std::fs::write(path, json)
+                    .with_context(|| format!("writing {}", path.display()))?,
+                None => print!("{json}"),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn bench(year: u16, runs: usize, dir: &Path) -> anyhow::Result<()> {
+    let solutions = runner::for_year(year);
+    if solutions.is_empty() {
+        return Err(anyhow::anyhow!("no solutions registered for {year}"));
+    }
+    let benchmarks: Vec<Benchmark> = solutions
+        .into_iter()
+        .map(|solution| {
+            eprintln!("Day {} - Part {}", solution.day, solution.part);
+            match input::load(solution.year, solution.day) {
+                Ok(input) => Benchmark::measure(solution, &input, runs),
+                Err(e) => Benchmark {
+                    solution,
+                    runs: Err(e),
+                },
+            }
+        })
+        .collect();
+    std::fs::create_dir_all(dir)?;
+    for (name, contents) in [
+        ("benchmarks.json", runner::benchmark_json(&benchmarks)),
+        ("benchmarks.md", runner::benchmark_markdown(&benchmarks)),
+    ] {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).with_context(|| format!("writing {}", path.display()))?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
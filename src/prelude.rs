@@ -0,0 +1,4 @@
+//! The helpers nearly every day reaches for, so a solver can pull them all in with
+//! `use crate::prelude::*;`.
+
+pub use crate::util::{iter::IterExt, wide::AccumulateExt};
@@ -0,0 +1,100 @@
+use std::{
+    fs::File,
+    io::Write,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+
+use crate::runner::{self, Solution};
+
+/// How many times a second call stacks are sampled.
+pub const FREQUENCY: i32 = 1000;
+
+/// How long each day is run over and over for, so quick days still collect enough
+/// samples to show where their time goes.
+pub const DURATION: Duration = Duration::from_secs(1);
+
+/// Where the flamegraph of `day` of `year` is written.
+#[must_use]
+pub fn path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from("target")
+        .join("profiles")
+        .join(format!("{year}-day{day}.svg"))
+}
+
+/// Samples call stacks while running `solutions` on `input` for at least
+/// `duration`, and writes them to `out` as a flamegraph SVG.
+pub fn flamegraph(
+    solutions: &[Solution],
+    input: &str,
+    duration: Duration,
+    out: impl Write,
+) -> Result<()> {
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(FREQUENCY)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .context("starting the profiler")?;
+    let start = Instant::now();
+    loop {
+        for solution in solutions {
+            solution
+                .run_on(input)
+                .with_context(|| format!("running day {} part {}", solution.day, solution.part))?;
+        }
+        if start.elapsed() >= duration {
+            break;
+        }
+    }
+    //Lenient generators would otherwise pile up a warning for every run.
+    let _ = runner::take_warnings();
+    guard
+        .report()
+        .build()
+        .context("collecting the profile")?
+        .flamegraph(out)
+        .context("drawing the flamegraph")?;
+    Ok(())
+}
+
+/// Profiles `solutions` for `day` of `year` on `input` with [`flamegraph`],
+/// returning where the SVG was written.
+pub fn record(year: u16, day: u8, solutions: &[Solution], input: &str) -> Result<PathBuf> {
+    let path = path(year, day);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let file = File::create(&path).with_context(|| format!("creating {}", path.display()))?;
+    flamegraph(solutions, input, DURATION, file)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            path(2023, 2),
+            PathBuf::from("target/profiles/2023-day2.svg")
+        );
+    }
+
+    #[test]
+    fn test_flamegraph() {
+        let solutions = [
+            runner::find(2023, 9, 1).unwrap(),
+            runner::find(2023, 9, 2).unwrap(),
+        ];
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+        let mut svg = Vec::new();
+        flamegraph(&solutions, input, Duration::from_millis(50), &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains("<svg"), "{svg}");
+        let error = flamegraph(&solutions[..1], "x", Duration::ZERO, Vec::new()).unwrap_err();
+        assert_eq!(error.to_string(), "running day 9 part 1");
+    }
+}
@@ -0,0 +1,789 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    io::BufRead,
+    panic::{AssertUnwindSafe, UnwindSafe},
+    path::Path,
+    time::Duration,
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{input, util::parse::Skipped};
+
+/// The environment variable that picks the year when no `--year` is given.
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+/// The year used when nothing else picks one.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// A solver for one part of one day's puzzle, taking the raw puzzle input.
+///
+/// cargo-aoc can only register a single year, so every year's solutions are also
+/// listed here to let them live side by side.
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The generator and solver, as they were named when registered.
+    pub implementation: &'static str,
+    pub run: fn(&str) -> Result<Run>,
+    /// The same solver reading its input a line at a time, if it has one.
+    pub stream: Option<fn(&mut dyn BufRead) -> Result<Run>>,
+}
+
+impl Solution {
+    /// Runs the solver on `input`. Generators panic on input they can't parse, so
+    /// a panic is caught and returned as an error, letting other days still run.
+    pub fn run_on(&self, input: &str) -> Result<Run> {
+        caught(|| (self.run)(input))
+    }
+
+    /// Runs the streaming solver on `reader`, or an error if there isn't one.
+    pub fn stream_from(&self, reader: &mut dyn BufRead) -> Result<Run> {
+        let stream = self.stream.ok_or_else(|| {
+            anyhow::anyhow!(
+                "{} day {} part {} can't stream its input",
+                self.year,
+                self.day,
+                self.part
+            )
+        })?;
+        caught(AssertUnwindSafe(|| stream(reader)))
+    }
+
+    /// Runs the streaming solver on the file at `path` without reading it whole.
+    pub fn stream_file(&self, path: &Path) -> Result<Run> {
+        self.stream_from(&mut input::open(path)?)
+    }
+
+    /// Runs the solver on the puzzle input saved for its year and day.
+    pub fn run_on_input(&self) -> Result<Run> {
+        self.run_on(&input::load(self.year, self.day)?)
+    }
+
+    /// Runs the solver on the input in the file at `path`.
+    pub fn run_on_file(&self, path: &Path) -> Result<Run> {
+        self.run_on(&input::read(path)?)
+    }
+}
+
+/// What `run` returns, or an error if it panicked.
+fn caught(run: impl FnOnce() -> Result<Run> + UnwindSafe) -> Result<Run> {
+    std::panic::catch_unwind(run).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .or_else(|| panic.downcast_ref::<&str>().copied())
+            .unwrap_or("unknown panic");
+        Err(anyhow::anyhow!("panicked: {message}"))
+    })
+}
+
+/// An answer and how long it took to work out.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Run {
+    pub answer: String,
+    /// Time spent tidying and parsing the input.
+    pub generator: Duration,
+    pub solver: Duration,
+}
+
+impl Run {
+    pub fn new(answer: &impl Answer, generator: Duration, solver: Duration) -> Result<Self> {
+        Ok(Self {
+            answer: answer.answer()?,
+            generator,
+            solver,
+        })
+    }
+
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.generator + self.solver
+    }
+}
+
+/// A solution and what came of running it.
+#[derive(Debug)]
+pub struct Outcome {
+    pub solution: Solution,
+    pub run: Result<Run>,
+}
+
+/// The outcomes laid out as a table with a row for each part, giving its answer
+/// and timings, so slow days stand out.
+#[must_use]
+pub fn timing_table(outcomes: &[Outcome]) -> String {
+    let header = ["Day", "Part", "Answer", "Generator", "Solver", "Total"].map(String::from);
+    let rows: Vec<[String; 6]> = outcomes
+        .iter()
+        .map(|outcome| {
+            let (day, part) = (outcome.solution.day, outcome.solution.part);
+            match &outcome.run {
+                Ok(run) => [
+                    day.to_string(),
+                    part.to_string(),
+                    run.answer.clone(),
+                    format!("{:.2?}", run.generator),
+                    format!("{:.2?}", run.solver),
+                    format!("{:.2?}", run.total()),
+                ],
+                Err(_) => [day, part]
+                    .map(|n| n.to_string())
+                    .into_iter()
+                    .chain(["FAILED", "-", "-", "-"].map(String::from))
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .expect("six columns"),
+            }
+        })
+        .collect();
+    let mut widths = header.clone().map(|title| title.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |row: &[String; 6]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            //The answer is left-aligned; numbers and times line up on the right.
+            .map(|(i, (cell, width))| {
+                if i == 2 {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect();
+        cells.join(" | ").trim_end().to_string()
+    };
+    let rule = widths.map(|width| "-".repeat(width)).join("-+-");
+    std::iter::once(line(&header))
+        .chain(std::iter::once(rule))
+        .chain(rows.iter().map(line))
+        .map(|row| row + "\n")
+        .collect()
+}
+
+thread_local! {
+    static LENIENT: Cell<bool> = const { Cell::new(false) };
+    static WARNINGS: RefCell<Vec<Skipped>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Sets whether generators that can should skip malformed lines instead of
+/// failing. cargo-aoc fixes what a generator takes, so this can't be passed in.
+pub fn set_lenient(lenient: bool) {
+    LENIENT.with(|cell| cell.set(lenient));
+}
+
+/// Whether generators should skip malformed lines, as set by [`set_lenient`].
+#[must_use]
+pub fn lenient() -> bool {
+    LENIENT.with(Cell::get)
+}
+
+/// Records the lines a lenient generator skipped, for the runner to report.
+pub fn warn(skipped: Skipped) {
+    if !skipped.is_empty() {
+        WARNINGS.with(|warnings| warnings.borrow_mut().push(skipped));
+    }
+}
+
+/// Every warning recorded since the last call.
+#[must_use]
+pub fn take_warnings() -> Vec<Skipped> {
+    WARNINGS.with(|warnings| warnings.take())
+}
+
+/// Anything a solver can return as its answer.
+pub trait Answer {
+    fn answer(&self) -> Result<String>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn answer(&self) -> Result<String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String, &str);
+
+impl<T: Answer> Answer for Option<T> {
+    fn answer(&self) -> Result<String> {
+        self.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("no answer found"))?
+            .answer()
+    }
+}
+
+/// Builds a [`Solution`] from a solver and, if it needs one, the generator that
+/// parses its input. The input is [`normalize`](crate::input::normalize)d first.
+/// A `stream` solver after them reads a `BufRead` instead; it parses as it goes, so
+/// all of its time counts as solving.
+#[macro_export]
+macro_rules! solution {
+    ($year:literal, $day:literal, $part:literal, $generator:path => $solver:path, stream $streaming:path) => {
+        $crate::runner::Solution {
+            stream: Some(|reader| {
+                let start = ::std::time::Instant::now();
+                let answer = $streaming(reader)?;
+                $crate::runner::Run::new(&answer, ::std::time::Duration::ZERO, start.elapsed())
+            }),
+            ..$crate::solution!($year, $day, $part, $generator => $solver)
+        }
+    };
+    ($year:literal, $day:literal, $part:literal, $generator:path => $solver:path) => {
+        $crate::runner::Solution {
+            year: $year,
+            day: $day,
+            part: $part,
+            implementation: concat!(stringify!($generator), " => ", stringify!($solver)),
+            run: |input| {
+                let start = ::std::time::Instant::now();
+                let generated = $generator(&$crate::input::normalize(input));
+                let generator = start.elapsed();
+                let start = ::std::time::Instant::now();
+                let answer = $solver(&generated);
+                $crate::runner::Run::new(&answer, generator, start.elapsed())
+            },
+            stream: None,
+        }
+    };
+    ($year:literal, $day:literal, $part:literal, $solver:path) => {
+        $crate::runner::Solution {
+            year: $year,
+            day: $day,
+            part: $part,
+            implementation: stringify!($solver),
+            run: |input| {
+                let start = ::std::time::Instant::now();
+                let input = $crate::input::normalize(input);
+                let generator = start.elapsed();
+                let start = ::std::time::Instant::now();
+                let answer = $solver(&input);
+                $crate::runner::Run::new(&answer, generator, start.elapsed())
+            },
+            stream: None,
+        }
+    };
+}
+
+/// Which solution a JSON record is about.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct SolutionId {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub implementation: String,
+}
+
+impl From<&Solution> for SolutionId {
+    fn from(solution: &Solution) -> Self {
+        Self {
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            implementation: solution.implementation.to_string(),
+        }
+    }
+}
+
+/// What [`json`] writes for each part: its answer, or error, and its timings in
+/// nanoseconds.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Record {
+    #[serde(flatten)]
+    pub solution: SolutionId,
+    pub answer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generator_ns: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solver_ns: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_ns: Option<u64>,
+}
+
+//Nanoseconds as a u64 last for centuries, and serde can't flatten a u128.
+fn nanos(time: Duration) -> u64 {
+    u64::try_from(time.as_nanos()).unwrap_or(u64::MAX)
+}
+
+impl From<&Outcome> for Record {
+    fn from(outcome: &Outcome) -> Self {
+        let run = outcome.run.as_ref().ok();
+        Self {
+            solution: SolutionId::from(&outcome.solution),
+            answer: run.map(|run| run.answer.clone()),
+            error: outcome.run.as_ref().err().map(|e| format!("{e:#}")),
+            generator_ns: run.map(|run| nanos(run.generator)),
+            solver_ns: run.map(|run| nanos(run.solver)),
+            total_ns: run.map(|run| nanos(run.total())),
+        }
+    }
+}
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).expect("records are plain data") + "\n"
+}
+
+/// The outcomes as a JSON array of [`Record`]s for other tools to read.
+#[must_use]
+pub fn json(outcomes: &[Outcome]) -> String {
+    to_json(&outcomes.iter().map(Record::from).collect::<Vec<_>>())
+}
+
+/// Timings from running one solution over and over on the same input.
+#[derive(Debug)]
+pub struct Benchmark {
+    pub solution: Solution,
+    /// Every run, or the error that stopped them.
+    pub runs: Result<Vec<Run>>,
+}
+
+/// The percentiles of total time that benchmark summaries give.
+pub const PERCENTILES: [usize; 3] = [50, 90, 99];
+
+impl Benchmark {
+    /// Runs `solution` on `input` `times` times, stopping at the first failure. A
+    /// generator that panics on bad input counts as failing, so one bad day doesn't
+    /// stop the rest being measured.
+    pub fn measure(solution: Solution, input: &str, times: usize) -> Self {
+        let runs = (0..times).map(|_| solution.run_on(input)).collect();
+        //Lenient generators would otherwise pile up a warning for every run.
+        let _ = take_warnings();
+        Self { solution, runs }
+    }
+
+    /// The time `stage` took in every run, fastest first.
+    #[must_use]
+    pub fn times(&self, stage: Stage) -> Vec<Duration> {
+        let mut times: Vec<Duration> = self
+            .runs
+            .iter()
+            .flatten()
+            .map(|run| stage.time(run))
+            .collect();
+        times.sort_unstable();
+        times
+    }
+
+    /// The total time of every run, fastest first.
+    #[must_use]
+    pub fn totals(&self) -> Vec<Duration> {
+        self.times(Stage::Total)
+    }
+}
+
+/// Which part of a run a series of benchmark times covers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Stage {
+    Generator,
+    Solver,
+    Total,
+}
+
+impl Stage {
+    pub const ALL: [Self; 3] = [Self::Generator, Self::Solver, Self::Total];
+
+    #[must_use]
+    pub fn time(self, run: &Run) -> Duration {
+        match self {
+            Self::Generator => run.generator,
+            Self::Solver => run.solver,
+            Self::Total => run.total(),
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Generator => "generator",
+            Self::Solver => "solver",
+            Self::Total => "total",
+        }
+    }
+}
+
+/// The time that `percent` percent of the `sorted` times are no slower than, by
+/// nearest rank, or zero if there are none.
+#[must_use]
+pub fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied().unwrap_or_default()
+}
+
+/// The minimum, [`PERCENTILES`] and maximum of `sorted`.
+fn summary(sorted: &[Duration]) -> Vec<(String, Duration)> {
+    std::iter::once(("min".to_string(), percentile(sorted, 0)))
+        .chain(
+            PERCENTILES
+                .iter()
+                .map(|&percent| (format!("p{percent}"), percentile(sorted, percent))),
+        )
+        .chain(std::iter::once((
+            "max".to_string(),
+            sorted.last().copied().unwrap_or_default(),
+        )))
+        .collect()
+}
+
+/// What [`benchmark_json`] writes for each solution.
+#[derive(Debug, Serialize)]
+struct BenchmarkRecord {
+    #[serde(flatten)]
+    solution: SolutionId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    runs: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// The [`summary`] of each [`Stage`], keyed like `generator_p50_ns`, with total
+    /// times keyed like `p50_ns`.
+    #[serde(flatten)]
+    times: BTreeMap<String, u64>,
+}
+
+impl From<&Benchmark> for BenchmarkRecord {
+    fn from(benchmark: &Benchmark) -> Self {
+        let runs = benchmark.runs.as_ref().ok();
+        Self {
+            solution: SolutionId::from(&benchmark.solution),
+            answer: runs
+                .and_then(|runs| runs.first())
+                .map(|run| run.answer.clone()),
+            runs: runs.map(Vec::len),
+            error: benchmark.runs.as_ref().err().map(|e| format!("{e:#}")),
+            times: runs
+                .map(|_| {
+                    Stage::ALL
+                        .into_iter()
+                        .flat_map(|stage| {
+                            let prefix = match stage {
+                                Stage::Total => String::new(),
+                                stage => format!("{}_", stage.name()),
+                            };
+                            summary(&benchmark.times(stage))
+                                .into_iter()
+                                .map(move |(name, time)| {
+                                    (format!("{prefix}{name}_ns"), nanos(time))
+                                })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The benchmarks as JSON, with each solution's answer, number of runs and summary
+/// of generator, solver and total times in nanoseconds.
+#[must_use]
+pub fn benchmark_json(benchmarks: &[Benchmark]) -> String {
+    to_json(
+        &benchmarks
+            .iter()
+            .map(BenchmarkRecord::from)
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// The benchmarks as a Markdown table, with a row each for the generator, solver
+/// and total times of every part.
+#[must_use]
+pub fn benchmark_markdown(benchmarks: &[Benchmark]) -> String {
+    let mut columns = ["Day", "Part", "Stage", "Runs"].map(String::from).to_vec();
+    columns.extend(summary(&[]).into_iter().map(|(name, _)| name));
+    let mut table = format!("| {} |\n", columns.join(" | "));
+    table += &format!("|{}\n", "---:|".repeat(columns.len()));
+    for benchmark in benchmarks {
+        let (day, part) = (benchmark.solution.day, benchmark.solution.part);
+        let Ok(runs) = &benchmark.runs else {
+            table += &format!("| {day} | {part} | failed |\n");
+            continue;
+        };
+        for stage in Stage::ALL {
+            let times: Vec<String> = summary(&benchmark.times(stage))
+                .into_iter()
+                .map(|(_, time)| format!("{time:.2?}"))
+                .collect();
+            table += &format!(
+                "| {day} | {part} | {} | {} | {} |\n",
+                stage.name(),
+                runs.len(),
+                times.join(" | ")
+            );
+        }
+    }
+    table
+}
+
+/// Every registered solution, across all years.
+#[must_use]
+pub fn solutions() -> Vec<Solution> {
+    [
+        crate::year2015::solutions(),
+        crate::year2021::solutions(),
+        crate::year2022::solutions(),
+        crate::year2023::solutions(),
+        crate::year2024::solutions(),
+    ]
+    .concat()
+}
+
+/// The solution for `part` of `day` in `year`, if there is one.
+#[must_use]
+pub fn find(year: u16, day: u8, part: u8) -> Option<Solution> {
+    solutions()
+        .into_iter()
+        .find(|solution| (solution.year, solution.day, solution.part) == (year, day, part))
+}
+
+/// Every registered solution for `year`, in day and part order.
+#[must_use]
+pub fn for_year(year: u16) -> Vec<Solution> {
+    let mut solutions: Vec<_> = solutions()
+        .into_iter()
+        .filter(|solution| solution.year == year)
+        .collect();
+    solutions.sort_by_key(|solution| (solution.day, solution.part));
+    solutions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solutions_are_unique() {
+        let mut keys: Vec<_> = solutions()
+            .iter()
+            .map(|solution| (solution.year, solution.day, solution.part))
+            .collect();
+        let registered = keys.len();
+        keys.sort_unstable();
+        keys.dedup();
+        assert_eq!(keys.len(), registered);
+    }
+
+    #[test]
+    fn test_run() {
+        let solution = find(2023, 9, 1).unwrap();
+        let run = (solution.run)("0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45").unwrap();
+        assert_eq!(run.answer, "114");
+        assert_eq!(run.total(), run.generator + run.solver);
+    }
+
+    #[test]
+    fn test_for_year() {
+        let solutions = for_year(2015);
+        assert!(!solutions.is_empty());
+        assert!(solutions.iter().all(|solution| solution.year == 2015));
+        assert_eq!((solutions[0].day, solutions[0].part), (1, 1));
+        assert!(for_year(1999).is_empty());
+    }
+
+    #[test]
+    fn test_run_on_input() {
+        let solution = Solution {
+            year: 1999,
+            ..find(2023, 9, 1).unwrap()
+        };
+        let error = solution.run_on_input().unwrap_err();
+        assert!(error.to_string().contains("input/1999/day9.txt"));
+    }
+
+    #[test]
+    fn test_stream_from() {
+        let example = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+        let solution = find(2023, 9, 1).unwrap();
+        let run = solution.stream_from(&mut example.as_bytes()).unwrap();
+        assert_eq!(
+            (run.answer.as_str(), run.generator),
+            ("114", Duration::ZERO)
+        );
+        let error = solution.stream_from(&mut "1 x".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 1");
+        let error = find(2023, 2, 1)
+            .unwrap()
+            .stream_from(&mut example.as_bytes())
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2023 day 2 part 1 can't stream its input"
+        );
+        assert!(solution
+            .stream_file(Path::new("input/1999/day9.txt"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_no_answer() {
+        assert_eq!(Some(7).answer().unwrap(), "7");
+        assert!(None::<u32>.answer().is_err());
+    }
+
+    #[test]
+    fn test_run_on() {
+        let solution = find(2023, 2, 1).unwrap();
+        assert_eq!(solution.run_on("Game 7: 1 red").unwrap().answer, "7");
+        let error = solution.run_on("Game x").unwrap_err().to_string();
+        assert!(error.starts_with("panicked: "), "{error}");
+    }
+
+    #[test]
+    fn test_lenient() {
+        let solution = find(2023, 2, 1).unwrap();
+        let input = "Game 1: 3 blue\nGame x: 1 red\nGame 2: 20 red";
+        assert!(!lenient());
+        assert!(std::panic::catch_unwind(|| (solution.run)(input)).is_err());
+        set_lenient(true);
+        assert_eq!((solution.run)(input).unwrap().answer, "1");
+        set_lenient(false);
+        let warnings = take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].samples, vec![(2, "Game x: 1 red".to_string())]);
+        assert!(take_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_timing_table() {
+        let solution = find(2023, 9, 1).unwrap();
+        let outcomes = [
+            Outcome {
+                solution,
+                run: Ok(Run {
+                    answer: "114".to_string(),
+                    generator: Duration::from_micros(1500),
+                    solver: Duration::from_micros(250),
+                }),
+            },
+            Outcome {
+                solution: find(2023, 9, 2).unwrap(),
+                run: Err(anyhow::anyhow!("no input")),
+            },
+        ];
+        assert_eq!(
+            timing_table(&outcomes),
+            "Day | Part | Answer | Generator |   Solver |  Total
+----+------+--------+-----------+----------+-------
+  9 |    1 | 114    |    1.50ms | 250.00µs | 1.75ms
+  9 |    2 | FAILED |         - |        - |      -
+"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let outcomes = [
+            Outcome {
+                solution: find(2023, 9, 1).unwrap(),
+                run: Ok(Run {
+                    answer: "114".to_string(),
+                    generator: Duration::from_micros(2),
+                    solver: Duration::from_nanos(500),
+                }),
+            },
+            Outcome {
+                solution: find(2023, 1, 1).unwrap(),
+                run: Err(anyhow::anyhow!("bad \"input\"\n")),
+            },
+        ];
+        let json = json(&outcomes);
+        let records: Vec<Record> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            records,
+            vec![
+                Record {
+                    solution: SolutionId {
+                        year: 2023,
+                        day: 9,
+                        part: 1,
+                        implementation: "day9::input_generator => day9::part1".to_string(),
+                    },
+                    answer: Some("114".to_string()),
+                    error: None,
+                    generator_ns: Some(2000),
+                    solver_ns: Some(500),
+                    total_ns: Some(2500),
+                },
+                Record {
+                    solution: SolutionId::from(&outcomes[1].solution),
+                    answer: None,
+                    error: Some("bad \"input\"\n".to_string()),
+                    generator_ns: None,
+                    solver_ns: None,
+                    total_ns: None,
+                },
+            ]
+        );
+        assert!(json.contains(
+            r#""answer": null,
+    "error": "bad \"input\"\n"
+  }"#
+        ));
+        assert_eq!(super::json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_percentile() {
+        let times: Vec<Duration> = (1..=10).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&times, 0), Duration::from_millis(1));
+        assert_eq!(percentile(&times, 50), Duration::from_millis(5));
+        assert_eq!(percentile(&times, 90), Duration::from_millis(9));
+        assert_eq!(percentile(&times, 99), Duration::from_millis(10));
+        assert_eq!(percentile(&[], 50), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_benchmark() {
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+        let benchmarks = [
+            Benchmark::measure(find(2023, 9, 1).unwrap(), input, 5),
+            Benchmark::measure(find(2023, 4, 1).unwrap(), "Card x", 5),
+        ];
+        let totals = benchmarks[0].totals();
+        assert_eq!(totals.len(), 5);
+        assert!(totals.is_sorted());
+        let json: serde_json::Value = serde_json::from_str(&benchmark_json(&benchmarks)).unwrap();
+        assert_eq!(
+            json[0]["implementation"],
+            "day9::input_generator => day9::part1"
+        );
+        assert_eq!(json[0]["answer"], "114");
+        assert_eq!(json[0]["runs"], 5);
+        assert!(json[0]["p50_ns"].as_u64().unwrap() <= json[0]["max_ns"].as_u64().unwrap());
+        assert!(
+            json[0]["generator_min_ns"].as_u64().unwrap() <= json[0]["min_ns"].as_u64().unwrap()
+        );
+        assert!(json[0]["solver_max_ns"].as_u64().unwrap() <= json[0]["max_ns"].as_u64().unwrap());
+        assert_eq!(json[1]["day"], 4);
+        assert!(json[1]["error"].as_str().unwrap().starts_with("panicked: "));
+        assert_eq!(json[1].get("runs"), None);
+        let markdown = benchmark_markdown(&benchmarks);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(
+            lines[0],
+            "| Day | Part | Stage | Runs | min | p50 | p90 | p99 | max |"
+        );
+        assert_eq!(lines[1], "|---:|---:|---:|---:|---:|---:|---:|---:|---:|");
+        assert!(lines[2].starts_with("| 9 | 1 | generator | 5 | "));
+        assert!(lines[3].starts_with("| 9 | 1 | solver | 5 | "));
+        assert!(lines[4].starts_with("| 9 | 1 | total | 5 | "));
+        assert_eq!(lines[5], "| 4 | 1 | failed |");
+    }
+}
@@ -0,0 +1,295 @@
+use std::ops::RangeInclusive;
+
+use super::{point::Point, point3::Point3};
+
+/// An axis-aligned rectangle of grid points, both corners included.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Aabb {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Aabb {
+    /// The rectangle with `a` and `b` at opposite corners, whichever way round.
+    #[must_use]
+    pub fn new(a: Point, b: Point) -> Self {
+        Self {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// The smallest rectangle holding every one of `points`, or `None` if there
+    /// are none.
+    pub fn bounding(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), Self::expand))
+    }
+
+    pub fn xs(&self) -> RangeInclusive<i64> {
+        self.min.x..=self.max.x
+    }
+
+    pub fn ys(&self) -> RangeInclusive<i64> {
+        self.min.y..=self.max.y
+    }
+
+    /// The number of points along each axis.
+    #[must_use]
+    pub fn size(&self) -> Point {
+        self.max - self.min + Point::new(1, 1)
+    }
+
+    /// The number of points inside.
+    #[must_use]
+    pub fn area(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.xs().contains(&point.x) && self.ys().contains(&point.y)
+    }
+
+    /// Whether every point of `other` is inside this.
+    #[must_use]
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// The smallest rectangle holding both.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(
+            Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        )
+    }
+
+    /// The points the two share, if any.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Self { min, max })
+    }
+
+    /// The smallest rectangle holding this and `point`.
+    #[must_use]
+    pub fn expand(self, point: Point) -> Self {
+        self.union(&Self::new(point, point))
+    }
+
+    /// This grown by `margin` on every side, or shrunk if it is negative.
+    #[must_use]
+    pub fn grow(&self, margin: i64) -> Self {
+        let margin = Point::new(margin, margin);
+        Self::new(self.min - margin, self.max + margin)
+    }
+
+    /// This moved by `offset`.
+    #[must_use]
+    pub fn shift(&self, offset: Point) -> Self {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// Every point inside, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let xs = self.xs();
+        self.ys()
+            .flat_map(move |y| xs.clone().map(move |x| Point::new(x, y)))
+    }
+}
+
+/// An axis-aligned box of points in 3D space, both corners included.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Aabb3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Aabb3 {
+    /// The box with `a` and `b` at opposite corners, whichever way round.
+    #[must_use]
+    pub fn new(a: Point3, b: Point3) -> Self {
+        Self {
+            min: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    /// The smallest box holding every one of `points`, or `None` if there are none.
+    pub fn bounding(points: impl IntoIterator<Item = Point3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), Self::expand))
+    }
+
+    pub fn xs(&self) -> RangeInclusive<i64> {
+        self.min.x..=self.max.x
+    }
+
+    pub fn ys(&self) -> RangeInclusive<i64> {
+        self.min.y..=self.max.y
+    }
+
+    pub fn zs(&self) -> RangeInclusive<i64> {
+        self.min.z..=self.max.z
+    }
+
+    /// The number of points along each axis.
+    #[must_use]
+    pub fn size(&self) -> Point3 {
+        self.max - self.min + Point3::new(1, 1, 1)
+    }
+
+    /// The number of points inside.
+    #[must_use]
+    pub fn volume(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point3) -> bool {
+        self.xs().contains(&point.x) && self.ys().contains(&point.y) && self.zs().contains(&point.z)
+    }
+
+    /// Whether every point of `other` is inside this.
+    #[must_use]
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// The smallest box holding both.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point3::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            max: Point3::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        }
+    }
+
+    /// The points the two share, if any.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point3::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Point3::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Self { min, max })
+    }
+
+    /// The smallest box holding this and `point`.
+    #[must_use]
+    pub fn expand(self, point: Point3) -> Self {
+        self.union(&Self::new(point, point))
+    }
+
+    /// This grown by `margin` on every side, or shrunk if it is negative.
+    #[must_use]
+    pub fn grow(&self, margin: i64) -> Self {
+        let margin = Point3::new(margin, margin, margin);
+        Self::new(self.min - margin, self.max + margin)
+    }
+
+    /// This moved by `offset`.
+    #[must_use]
+    pub fn shift(&self, offset: Point3) -> Self {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// The rectangle this covers when seen from above, looking down the `z` axis.
+    #[must_use]
+    pub const fn footprint(&self) -> Aabb {
+        Aabb {
+            min: Point::new(self.min.x, self.min.y),
+            max: Point::new(self.max.x, self.max.y),
+        }
+    }
+
+    /// Every point inside, `x` fastest and `z` slowest.
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let (xs, ys) = (self.xs(), self.ys());
+        self.zs().flat_map(move |z| {
+            let xs = xs.clone();
+            ys.clone()
+                .flat_map(move |y| xs.clone().map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aabb() {
+        let a = Aabb::new(Point::new(4, 1), Point::new(0, 3));
+        assert_eq!(a.min, Point::new(0, 1));
+        assert_eq!(a.size(), Point::new(5, 3));
+        assert_eq!(a.area(), 15);
+        assert_eq!(a.points().count(), 15);
+        assert!(a.contains(Point::new(4, 3)) && !a.contains(Point::new(5, 3)));
+        let b = Aabb::new(Point::new(3, 3), Point::new(6, 6));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Aabb::new(Point::new(3, 3), Point::new(4, 3)))
+        );
+        assert_eq!(a.union(&b), Aabb::new(Point::new(0, 1), Point::new(6, 6)));
+        assert!(a.union(&b).contains_box(&a));
+        assert_eq!(a.intersection(&b.shift(Point::new(1, 1))), None);
+        assert_eq!(a.grow(1).area(), 7 * 5);
+        assert_eq!(
+            Aabb::bounding([Point::new(2, -1), Point::new(-3, 4), Point::new(0, 0)]),
+            Some(Aabb::new(Point::new(-3, -1), Point::new(2, 4)))
+        );
+        assert_eq!(Aabb::bounding([]), None);
+    }
+
+    #[test]
+    fn test_aabb3() {
+        let cube = Aabb3::new(Point3::new(10, 10, 10), Point3::new(12, 12, 12));
+        assert_eq!(cube.volume(), 27);
+        assert_eq!(cube.points().count(), 27);
+        assert_eq!(cube.points().next(), Some(cube.min));
+        assert_eq!(cube.points().nth(1), Some(Point3::new(11, 10, 10)));
+        let other = Aabb3::new(Point3::new(11, 11, 11), Point3::new(13, 13, 13));
+        //The overlap of the first two cuboids in 2021 day 22's smaller example.
+        let overlap = cube.intersection(&other).unwrap();
+        assert_eq!(overlap.volume(), 8);
+        assert_eq!(cube.volume() + other.volume() - overlap.volume(), 46);
+        assert!(cube.union(&other).contains_box(&overlap));
+        assert_eq!(cube.intersection(&other.shift(Point3::new(2, 0, 0))), None);
+        assert_eq!(
+            cube.footprint(),
+            Aabb::new(Point::new(10, 10), Point::new(12, 12))
+        );
+        assert_eq!(
+            Aabb3::bounding([Point3::new(1, 5, -2), Point3::new(3, 0, 4)]).map(|b| b.size()),
+            Some(Point3::new(3, 6, 7))
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::{collections::HashSet, hash::Hash};
+
+use super::{counter::Counter, grid::Grid, point::Point};
+
+/// A grid stepped a generation at a time by a rule giving each cell's next state
+/// from the current grid, as in the Game of Life.
+///
+/// Keeps a second grid to write the next generation into, so stepping doesn't
+/// allocate.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Automaton<T> {
+    current: Grid<T>,
+    next: Grid<T>,
+    generation: usize,
+}
+
+impl<T: Clone + PartialEq> Automaton<T> {
+    #[must_use]
+    pub fn new(grid: Grid<T>) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            generation: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    #[must_use]
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    /// The number of steps taken so far.
+    #[must_use]
+    pub const fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Moves on a generation, giving each cell the state `rule` picks from the
+    /// current grid, its point and its current state. Returns whether any cell
+    /// changed.
+    pub fn step(&mut self, mut rule: impl FnMut(&Grid<T>, Point, &T) -> T) -> bool {
+        let mut changed = false;
+        for (point, cell) in self.current.enumerate() {
+            let state = rule(&self.current, point, cell);
+            changed |= state != *cell;
+            self.next[point] = state;
+        }
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    /// Like [`step`](Self::step), for rules that only need each cell's state and how
+    /// many of its eight neighbours are `alive`.
+    pub fn step_counted(
+        &mut self,
+        alive: impl Fn(&T) -> bool,
+        mut rule: impl FnMut(&T, usize) -> T,
+    ) -> bool {
+        let counts = neighbor_counts(&self.current, alive);
+        self.step(|_, point, cell| rule(cell, counts[point]))
+    }
+
+    /// Steps until a generation changes nothing, returning the number of that step
+    /// counting from 1.
+    pub fn run_until_stable(&mut self, mut rule: impl FnMut(&Grid<T>, Point, &T) -> T) -> usize {
+        while self.step(&mut rule) {}
+        self.generation
+    }
+}
+
+/// How many of the eight neighbours of each cell are `alive`.
+///
+/// Counting outwards from the live cells is quicker than having every cell look
+/// around itself when few are alive.
+#[must_use]
+pub fn neighbor_counts<T>(grid: &Grid<T>, alive: impl Fn(&T) -> bool) -> Grid<usize> {
+    let mut counts = Grid::new(grid.width(), grid.height(), 0);
+    for (point, _) in grid.enumerate().filter(|&(_, cell)| alive(cell)) {
+        for neighbor in grid.neighbors8(point) {
+            counts[neighbor] += 1;
+        }
+    }
+    counts
+}
+
+/// Moves an unbounded set of live cells on a generation. `neighbors` gives the
+/// cells around one, in however many dimensions, and `rule` says whether a cell
+/// is alive next from whether it is now and how many live neighbours it has.
+///
+/// Only cells next to a live one are considered, so a dead cell with no live
+/// neighbours always stays dead.
+#[must_use]
+pub fn step_sparse<P, I>(
+    live: &HashSet<P>,
+    neighbors: impl Fn(P) -> I,
+    rule: impl Fn(bool, usize) -> bool,
+) -> HashSet<P>
+where
+    P: Copy + Eq + Hash,
+    I: IntoIterator<Item = P>,
+{
+    let counts: Counter<P> = live.iter().flat_map(|&cell| neighbors(cell)).collect();
+    let mut next: HashSet<P> = counts
+        .iter()
+        .filter(|&(cell, count)| rule(live.contains(cell), count))
+        .map(|(&cell, _)| cell)
+        .collect();
+    if rule(true, 0) {
+        next.extend(live.iter().filter(|&cell| counts.get(cell) == 0));
+    }
+    next
+}
+
+/// The 3^N - 1 cells touching `cell` in N dimensions, diagonals included.
+pub fn moore<const N: usize>(cell: [i64; N]) -> impl Iterator<Item = [i64; N]> {
+    let around = 3_usize.pow(N as u32);
+    (0..around)
+        .filter(move |&offset| offset != around / 2)
+        .map(move |mut offset| {
+            let mut neighbor = cell;
+            for coordinate in &mut neighbor {
+                *coordinate += (offset % 3) as i64 - 1;
+                offset /= 3;
+            }
+            neighbor
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(alive: bool, neighbors: usize) -> bool {
+        neighbors == 3 || (alive && neighbors == 2)
+    }
+
+    #[test]
+    fn test_blinker() {
+        let grid = Grid::parse(".....\n..#..\n..#..\n..#..\n.....\n", |b| Ok(b == b'#')).unwrap();
+        let mut automaton = Automaton::new(grid.clone());
+        assert!(automaton.step_counted(|&cell| cell, |&cell, count| life(cell, count)));
+        assert_eq!(automaton.grid(), &grid.transpose());
+        automaton.step_counted(|&cell| cell, |&cell, count| life(cell, count));
+        assert_eq!(automaton.generation(), 2);
+        assert_eq!(automaton.into_grid(), grid);
+    }
+
+    #[test]
+    fn test_seating() {
+        //2020 day 11: seats empty out or fill up until nothing changes.
+        let layout = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+";
+        let mut automaton = Automaton::new(Grid::parse(layout, Ok).unwrap());
+        let steps = automaton.run_until_stable(|grid, point, &seat| {
+            let occupied = grid.neighbors8(point).filter(|&n| grid[n] == b'#').count();
+            match seat {
+                b'L' if occupied == 0 => b'#',
+                b'#' if occupied >= 4 => b'L',
+                _ => seat,
+            }
+        });
+        assert_eq!(steps, 6);
+        let occupied = automaton
+            .grid()
+            .enumerate()
+            .filter(|&(_, &seat)| seat == b'#');
+        assert_eq!(occupied.count(), 37);
+    }
+
+    #[test]
+    fn test_moore() {
+        assert_eq!(moore([0]).collect::<Vec<_>>(), vec![[-1], [1]]);
+        assert_eq!(moore([5, 5]).count(), 8);
+        assert!(moore([0, 0, 0, 0]).all(|cell| cell != [0; 4]));
+        assert_eq!(moore([0, 0, 0, 0]).collect::<HashSet<_>>().len(), 80);
+    }
+
+    #[test]
+    fn test_conway_cubes() {
+        //2020 day 17's glider, in three dimensions and then four.
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let mut cubes: HashSet<[i64; 3]> = glider.iter().map(|&(x, y)| [x, y, 0]).collect();
+        let mut hypercubes: HashSet<[i64; 4]> = glider.iter().map(|&(x, y)| [x, y, 0, 0]).collect();
+        for _ in 0..6 {
+            cubes = step_sparse(&cubes, moore, life);
+            hypercubes = step_sparse(&hypercubes, moore, life);
+        }
+        assert_eq!(cubes.len(), 112);
+        assert_eq!(hypercubes.len(), 848);
+    }
+
+    #[test]
+    fn test_sparse_points() {
+        let blinker: HashSet<Point> = (0..3).map(|x| Point::new(x, 0)).collect();
+        let around = |point: Point| moore([point.x, point.y]).map(|[x, y]| Point::new(x, y));
+        let next = step_sparse(&blinker, around, life);
+        assert_eq!(next, (-1..2).map(|y| Point::new(1, y)).collect());
+        assert_eq!(step_sparse(&next, around, life), blinker);
+    }
+}
@@ -0,0 +1,318 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+use super::{grid::Grid, point::Point};
+
+/// A rectangular grid of on/off cells packed 64 to a word, for visited sets and
+/// simulations where a `HashSet<Point>` or `Grid<bool>` is too slow.
+///
+/// Each row starts on a fresh word, so whole rows can be shifted and combined a
+/// word at a time. Bits past the end of a row are always clear.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    /// The number of words in each row.
+    stride: usize,
+}
+
+impl BitGrid {
+    /// A grid with every cell off.
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(64);
+        Self {
+            words: vec![0; stride * height],
+            width,
+            height,
+            stride,
+        }
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `point` is on the grid, whether or not it is set.
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.bit(point).is_some()
+    }
+
+    /// The word holding `point` and the mask picking it out, if it is on the grid.
+    fn bit(&self, point: Point) -> Option<(usize, u64)> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some((y * self.stride + x / 64, 1 << (x % 64)))
+    }
+
+    /// Whether `point` is set. Points off the grid never are.
+    #[must_use]
+    pub fn get(&self, point: Point) -> bool {
+        self.bit(point)
+            .is_some_and(|(word, mask)| self.words[word] & mask != 0)
+    }
+
+    /// Sets `point`, returning whether it was off before, like [`HashSet::insert`].
+    ///
+    /// # Panics
+    ///
+    /// If `point` is off the grid.
+    ///
+    /// [`HashSet::insert`]: std::collections::HashSet::insert
+    pub fn insert(&mut self, point: Point) -> bool {
+        let (word, mask) = self
+            .bit(point)
+            .unwrap_or_else(|| panic!("{point:?} is off the grid"));
+        let was_off = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        was_off
+    }
+
+    /// Clears `point`, returning whether it was set. Points off the grid are ignored.
+    pub fn remove(&mut self, point: Point) -> bool {
+        let Some((word, mask)) = self.bit(point) else {
+            return false;
+        };
+        let was_on = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_on
+    }
+
+    /// Turns every cell off.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// The number of cells set.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// The number of cells set in row `y`.
+    #[must_use]
+    pub fn row_count(&self, y: usize) -> usize {
+        self.row(y)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.stride..(y + 1) * self.stride]
+    }
+
+    /// Clears the bits past the end of each row that a shift may have set.
+    fn trim(&mut self) {
+        let spare = self.stride * 64 - self.width;
+        if spare == 0 || self.stride == 0 {
+            return;
+        }
+        let mask = u64::MAX >> spare;
+        for row in self.words.chunks_mut(self.stride) {
+            row[row.len() - 1] &= mask;
+        }
+    }
+
+    /// Every set cell moved one to the east, dropping those on the east edge.
+    #[must_use]
+    pub fn shift_east(&self) -> Self {
+        let mut shifted = self.clone();
+        for row in shifted.words.chunks_mut(self.stride.max(1)) {
+            //Work from the high end so each word can borrow the carry from the one below.
+            for i in (0..row.len()).rev() {
+                let carry = if i > 0 { row[i - 1] >> 63 } else { 0 };
+                row[i] = (row[i] << 1) | carry;
+            }
+        }
+        shifted.trim();
+        shifted
+    }
+
+    /// Every set cell moved one to the west, dropping those on the west edge.
+    #[must_use]
+    pub fn shift_west(&self) -> Self {
+        let mut shifted = self.clone();
+        for row in shifted.words.chunks_mut(self.stride.max(1)) {
+            for i in 0..row.len() {
+                let carry = row.get(i + 1).map_or(0, |next| next << 63);
+                row[i] = (row[i] >> 1) | carry;
+            }
+        }
+        shifted
+    }
+
+    /// Every set cell moved one row up, dropping those on the top row.
+    #[must_use]
+    pub fn shift_north(&self) -> Self {
+        let mut shifted = self.clone();
+        if self.height > 0 {
+            shifted.words.copy_within(self.stride.., 0);
+            let last = shifted.words.len() - self.stride;
+            shifted.words[last..].fill(0);
+        }
+        shifted
+    }
+
+    /// Every set cell moved one row down, dropping those on the bottom row.
+    #[must_use]
+    pub fn shift_south(&self) -> Self {
+        let mut shifted = self.clone();
+        if self.height > 0 {
+            let end = shifted.words.len() - self.stride;
+            shifted.words.copy_within(..end, self.stride);
+            shifted.words[..self.stride].fill(0);
+        }
+        shifted
+    }
+
+    /// Every set point, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let y = index / self.stride;
+                let base = (index % self.stride) * 64;
+                //Peel off the lowest set bit until the word is empty.
+                std::iter::successors(Some(word).filter(|&w| w != 0), |&w| {
+                    Some(w & (w - 1)).filter(|&w| w != 0)
+                })
+                .map(move |w| Point::from((base + w.trailing_zeros() as usize, y)))
+            })
+    }
+
+    fn assert_same_size(&self, other: &Self) {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "combining a {}x{} grid with a {}x{} one",
+            self.width,
+            self.height,
+            other.width,
+            other.height
+        );
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for (point, _) in grid.enumerate().filter(|(_, &on)| on) {
+            bits.insert(point);
+        }
+        bits
+    }
+}
+
+impl BitOrAssign<&Self> for BitGrid {
+    fn bitor_assign(&mut self, other: &Self) {
+        self.assert_same_size(other);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+}
+
+impl BitAndAssign<&Self> for BitGrid {
+    fn bitand_assign(&mut self, other: &Self) {
+        self.assert_same_size(other);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: Self) -> BitGrid {
+        let mut union = self.clone();
+        union |= other;
+        union
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: Self) -> BitGrid {
+        let mut intersection = self.clone();
+        intersection &= other;
+        intersection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut bits = BitGrid::new(100, 3);
+        assert!(bits.is_empty());
+        assert!(bits.insert(Point::new(70, 1)));
+        assert!(!bits.insert(Point::new(70, 1)));
+        assert!(bits.insert(Point::new(0, 2)));
+        assert!(bits.get(Point::new(70, 1)));
+        assert!(!bits.get(Point::new(69, 1)));
+        assert!(!bits.get(Point::new(100, 1)));
+        assert!(!bits.contains(Point::new(-1, 0)));
+        assert_eq!(bits.count(), 2);
+        assert_eq!(bits.row_count(1), 1);
+        assert!(bits.remove(Point::new(70, 1)));
+        assert!(!bits.remove(Point::new(70, 1)));
+        assert_eq!(bits.points().collect::<Vec<_>>(), vec![Point::new(0, 2)]);
+        bits.clear();
+        assert!(bits.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "off the grid")]
+    fn test_insert_off_grid() {
+        BitGrid::new(3, 3).insert(Point::new(3, 0));
+    }
+
+    #[test]
+    fn test_shifts() {
+        //Straddle the boundary between the two words in each row.
+        let mut bits = BitGrid::new(65, 2);
+        bits.insert(Point::new(63, 0));
+        bits.insert(Point::new(64, 1));
+        let east: Vec<Point> = bits.shift_east().points().collect();
+        assert_eq!(east, vec![Point::new(64, 0)]);
+        let west: Vec<Point> = bits.shift_west().points().collect();
+        assert_eq!(west, vec![Point::new(62, 0), Point::new(63, 1)]);
+        let north: Vec<Point> = bits.shift_north().points().collect();
+        assert_eq!(north, vec![Point::new(64, 0)]);
+        let south: Vec<Point> = bits.shift_south().points().collect();
+        assert_eq!(south, vec![Point::new(63, 1)]);
+    }
+
+    #[test]
+    fn test_combine() {
+        let grid = Grid::from_rows(vec![vec![true, false], vec![true, true]]).unwrap();
+        let bits = BitGrid::from(&grid);
+        assert_eq!(bits.count(), 3);
+        let mut diagonal = BitGrid::new(2, 2);
+        diagonal.insert(Point::new(1, 0));
+        diagonal.insert(Point::new(1, 1));
+        assert_eq!((&bits | &diagonal).count(), 4);
+        let both: Vec<Point> = (&bits & &diagonal).points().collect();
+        assert_eq!(both, vec![Point::new(1, 1)]);
+    }
+}
@@ -0,0 +1,202 @@
+/// Every way of choosing `k` of `items` where order matters, in lexicographic order
+/// of position, like Python's `itertools.permutations`.
+///
+/// Choosing more items than there are gives nothing; choosing none gives one empty
+/// arrangement.
+#[must_use]
+pub fn permutations<T>(items: &[T], k: usize) -> Permutations<'_, T> {
+    let n = items.len();
+    Permutations {
+        items,
+        indices: (0..n).collect(),
+        cycles: (n.saturating_sub(k) + 1..=n).rev().collect(),
+        k,
+        started: false,
+        done: k > n,
+    }
+}
+
+/// The iterator returned by [`permutations`].
+#[derive(Debug, Clone)]
+pub struct Permutations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    /// How many more values each of the first `k` slots has to take before it
+    /// wraps round.
+    cycles: Vec<usize>,
+    k: usize,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T> Permutations<'a, T> {
+    fn current(&self) -> Vec<&'a T> {
+        self.indices[..self.k]
+            .iter()
+            .map(|&index| &self.items[index])
+            .collect()
+    }
+
+    /// Steps `indices` on to the next arrangement, returning false once they have
+    /// all been seen.
+    fn advance(&mut self) -> bool {
+        let n = self.indices.len();
+        for i in (0..self.k).rev() {
+            self.cycles[i] -= 1;
+            if self.cycles[i] == 0 {
+                //This slot has had every value, so put it back and try the one before.
+                self.indices[i..].rotate_left(1);
+                self.cycles[i] = n - i;
+            } else {
+                self.indices.swap(i, n - self.cycles[i]);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl<'a, T> Iterator for Permutations<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.started && !self.advance() {
+            self.done = true;
+            return None;
+        }
+        self.started = true;
+        Some(self.current())
+    }
+}
+
+/// Every way of choosing `k` of `items` where order doesn't matter, each kept in the
+/// order given, in lexicographic order of position.
+///
+/// Choosing more items than there are gives nothing; choosing none gives one empty
+/// selection.
+#[must_use]
+pub fn combinations<T>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items,
+        indices: (0..k).collect(),
+        started: false,
+        done: k > items.len(),
+    }
+}
+
+/// The iterator returned by [`combinations`].
+#[derive(Debug, Clone)]
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T> Combinations<'a, T> {
+    fn advance(&mut self) -> bool {
+        let (n, k) = (self.items.len(), self.indices.len());
+        //Find the last index that can still move right, move it, and pack the ones
+        //  after it in straight behind.
+        let Some(i) = (0..k).rev().find(|&i| self.indices[i] < n - k + i) else {
+            return false;
+        };
+        self.indices[i] += 1;
+        for j in i + 1..k {
+            self.indices[j] = self.indices[j - 1] + 1;
+        }
+        true
+    }
+}
+
+impl<'a, T> Iterator for Combinations<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.started && !self.advance() {
+            self.done = true;
+            return None;
+        }
+        self.started = true;
+        Some(
+            self.indices
+                .iter()
+                .map(|&index| &self.items[index])
+                .collect(),
+        )
+    }
+}
+
+/// Every unordered pair of different positions in `items`.
+pub fn pairs<T>(items: &[T]) -> impl Iterator<Item = (&T, &T)> {
+    items
+        .iter()
+        .enumerate()
+        .flat_map(move |(i, a)| items[i + 1..].iter().map(move |b| (a, b)))
+}
+
+/// Rearranges `items` into the next permutation in lexicographic order, returning
+/// false (and leaving them sorted) once they were already the last.
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    //The longest run at the end that only goes down is already as late as it can be.
+    let Some(pivot) = (1..items.len()).rev().find(|&i| items[i - 1] < items[i]) else {
+        items.reverse();
+        return false;
+    };
+    let pivot = pivot - 1;
+    let successor = (pivot + 1..items.len())
+        .rev()
+        .find(|&i| items[pivot] < items[i])
+        .expect("the item after the pivot is larger");
+    items.swap(pivot, successor);
+    items[pivot + 1..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permutations() {
+        let found: Vec<Vec<u32>> = permutations(&[1, 2, 3], 2)
+            .map(|chosen| chosen.into_iter().copied().collect())
+            .collect();
+        assert_eq!(found, [[1, 2], [1, 3], [2, 1], [2, 3], [3, 1], [3, 2]]);
+        assert_eq!(permutations(&[1, 2, 3, 4, 5], 3).count(), 60);
+        assert_eq!(permutations(&[1, 2, 3, 4], 4).count(), 24);
+        assert_eq!(permutations(&[1, 2], 3).count(), 0);
+        let empty: Vec<Vec<&u32>> = permutations(&[1, 2], 0).collect();
+        assert_eq!(empty, vec![Vec::<&u32>::new()]);
+    }
+
+    #[test]
+    fn test_combinations() {
+        let found: Vec<String> = combinations(&['a', 'b', 'c', 'd'], 2)
+            .map(|chosen| chosen.into_iter().collect())
+            .collect();
+        assert_eq!(found, ["ab", "ac", "ad", "bc", "bd", "cd"]);
+        assert_eq!(combinations(&[0; 10], 3).count(), 120);
+        assert_eq!(combinations(&[1, 2], 3).count(), 0);
+        assert_eq!(combinations(&[1, 2], 0).count(), 1);
+        assert_eq!(pairs(&[1, 2, 3, 4]).count(), 6);
+    }
+
+    #[test]
+    fn test_next_permutation() {
+        let mut items = [1, 2, 2];
+        let mut seen = vec![items];
+        while next_permutation(&mut items) {
+            seen.push(items);
+        }
+        assert_eq!(seen, vec![[1, 2, 2], [2, 1, 2], [2, 2, 1]]);
+        assert_eq!(items, [1, 2, 2]);
+        assert!(!next_permutation::<u8>(&mut []));
+    }
+}
@@ -0,0 +1,135 @@
+/// A sorted set of coordinates that stands in for a huge, sparse axis.
+///
+/// The coordinates cut the axis into slabs: slab `i` runs from the `i`th coordinate
+/// up to, but not including, the next. Work done once per slab, scaled by the slab's
+/// [`width`](Compressed::width), covers every integer along the way.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Compressed {
+    values: Vec<i64>,
+}
+
+impl Compressed {
+    /// Compresses `values`, which may come in any order and repeat.
+    #[must_use]
+    pub fn new(values: impl IntoIterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+
+    /// The number of distinct coordinates.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The coordinates, smallest first.
+    #[must_use]
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+
+    /// The index of `value`, if it is one of the coordinates.
+    #[must_use]
+    pub fn index(&self, value: i64) -> Option<usize> {
+        self.values.binary_search(&value).ok()
+    }
+
+    /// The coordinate at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If there aren't that many coordinates.
+    #[must_use]
+    pub fn value(&self, index: usize) -> i64 {
+        self.values[index]
+    }
+
+    /// The index of the slab holding `value`, which is the last coordinate at or
+    /// below it. `None` if it is below them all.
+    #[must_use]
+    pub fn slab(&self, value: i64) -> Option<usize> {
+        self.values
+            .partition_point(|&coordinate| coordinate <= value)
+            .checked_sub(1)
+    }
+
+    /// The number of slabs, which is one less than the number of coordinates.
+    #[must_use]
+    pub fn slabs(&self) -> usize {
+        self.values.len().saturating_sub(1)
+    }
+
+    /// How many integers slab `index` covers: the gap to the next coordinate.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is the last coordinate or beyond, so has no slab.
+    #[must_use]
+    pub fn width(&self, index: usize) -> i64 {
+        self.values[index + 1] - self.values[index]
+    }
+
+    /// The widths of every slab in order.
+    pub fn widths(&self) -> impl Iterator<Item = i64> + '_ {
+        self.values.windows(2).map(|pair| pair[1] - pair[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let axis = Compressed::new([1_000_000, 5, -3, 5, 40]);
+        assert_eq!(axis.values(), [-3, 5, 40, 1_000_000]);
+        assert_eq!(axis.index(40), Some(2));
+        assert_eq!(axis.index(41), None);
+        assert_eq!(axis.value(3), 1_000_000);
+        assert_eq!(axis.slab(41), Some(2));
+        assert_eq!(axis.slab(5), Some(1));
+        assert_eq!(axis.slab(-4), None);
+        assert_eq!(axis.slabs(), 3);
+        assert_eq!(axis.width(1), 35);
+        assert_eq!(axis.widths().collect::<Vec<_>>(), vec![8, 35, 999_960]);
+        assert_eq!(axis.widths().sum::<i64>(), 1_000_003);
+        assert_eq!(Compressed::new([]).slabs(), 0);
+    }
+
+    #[test]
+    fn test_union_area() {
+        //Overlapping rectangles as (left, top, right, bottom), right and bottom
+        //  excluded, far too big to paint cell by cell.
+        let rectangles = [
+            (0, 0, 2_000_000, 1_000_000),
+            (1_000_000, 500_000, 3_000_000, 3_000_000),
+            (10, 10, 20, 20),
+        ];
+        let xs = Compressed::new(rectangles.iter().flat_map(|r| [r.0, r.2]));
+        let ys = Compressed::new(rectangles.iter().flat_map(|r| [r.1, r.3]));
+        let mut area = 0;
+        for x in 0..xs.slabs() {
+            for y in 0..ys.slabs() {
+                let (left, top) = (xs.value(x), ys.value(y));
+                let covered = rectangles
+                    .iter()
+                    .any(|r| (r.0..r.2).contains(&left) && (r.1..r.3).contains(&top));
+                if covered {
+                    area += xs.width(x) * ys.width(y);
+                }
+            }
+        }
+        let overlap = 1_000_000 * 500_000;
+        assert_eq!(
+            area,
+            2_000_000 * 1_000_000 + 2_000_000 * 2_500_000 - overlap
+        );
+    }
+}
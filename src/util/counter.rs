@@ -0,0 +1,197 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{AddAssign, SubAssign},
+};
+
+/// A tally of how many times each item has been seen.
+///
+/// Items that aren't counted have a count of zero, and a count that falls to zero
+/// removes its item, so every item present has been seen at least once.
+#[derive(Debug, Clone)]
+pub struct Counter<T> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> PartialEq for Counter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+    }
+}
+
+impl<T: Eq + Hash> Eq for Counter<T> {}
+
+impl<T> Default for Counter<T> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash> Counter<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of distinct items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The number of items seen, counting repeats.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// How many times `item` has been seen.
+    #[must_use]
+    pub fn get(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or_default()
+    }
+
+    /// Counts `item` once more.
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    /// Counts `item` another `n` times.
+    pub fn add_n(&mut self, item: T, n: usize) {
+        if n > 0 {
+            *self.counts.entry(item).or_default() += n;
+        }
+    }
+
+    /// Takes up to `n` off the count of `item`, returning how many were taken.
+    pub fn remove_n(&mut self, item: &T, n: usize) -> usize {
+        let Some(count) = self.counts.get_mut(item) else {
+            return 0;
+        };
+        let taken = n.min(*count);
+        *count -= taken;
+        if *count == 0 {
+            self.counts.remove(item);
+        }
+        taken
+    }
+
+    /// Each item with its count, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, &count)| (item, count))
+    }
+}
+
+impl<T: Eq + Hash + Ord> Counter<T> {
+    /// Each item with its count, most common first. Ties are broken by the items'
+    /// own order, so the result doesn't depend on how the map is laid out.
+    #[must_use]
+    pub fn most_common(&self) -> Vec<(&T, usize)> {
+        let mut common: Vec<(&T, usize)> = self.iter().collect();
+        common.sort_unstable_by(|(a, m), (b, n)| n.cmp(m).then_with(|| a.cmp(b)));
+        common
+    }
+
+    /// The most common item and its count, if anything has been counted.
+    #[must_use]
+    pub fn max(&self) -> Option<(&T, usize)> {
+        self.iter()
+            .min_by(|(a, m), (b, n)| n.cmp(m).then_with(|| a.cmp(b)))
+    }
+
+    /// The least common item and its count, if anything has been counted.
+    #[must_use]
+    pub fn min(&self) -> Option<(&T, usize)> {
+        self.iter()
+            .min_by(|(a, m), (b, n)| m.cmp(n).then_with(|| a.cmp(b)))
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(items);
+        counter
+    }
+}
+
+impl<T> IntoIterator for Counter<T> {
+    type Item = (T, usize);
+    type IntoIter = std::collections::hash_map::IntoIter<T, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.add(item);
+        }
+    }
+}
+
+/// Merges in another tally, adding its counts to these.
+impl<T: Eq + Hash + Clone> AddAssign<&Self> for Counter<T> {
+    fn add_assign(&mut self, other: &Self) {
+        for (item, count) in other.iter() {
+            self.add_n(item.clone(), count);
+        }
+    }
+}
+
+/// Takes another tally's counts off these, stopping at zero.
+impl<T: Eq + Hash> SubAssign<&Self> for Counter<T> {
+    fn sub_assign(&mut self, other: &Self) {
+        for (item, count) in other.iter() {
+            self.remove_n(item, count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        let mut counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(counter.len(), 5);
+        assert_eq!(counter.total(), 11);
+        assert_eq!(counter.get(&'a'), 5);
+        assert_eq!(counter.get(&'z'), 0);
+        counter.add_n('z', 0);
+        assert_eq!(counter.len(), 5);
+        assert_eq!(counter.remove_n(&'d', 3), 1);
+        assert_eq!(counter.len(), 4);
+    }
+
+    #[test]
+    fn test_most_common() {
+        let counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(
+            counter.most_common(),
+            vec![(&'a', 5), (&'b', 2), (&'r', 2), (&'c', 1), (&'d', 1)]
+        );
+        assert_eq!(counter.max(), Some((&'a', 5)));
+        assert_eq!(counter.min(), Some((&'c', 1)));
+        assert_eq!(Counter::<char>::new().max(), None);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut counter: Counter<char> = "aab".chars().collect();
+        counter += &"abc".chars().collect();
+        assert_eq!(counter, "aaabbc".chars().collect());
+        counter -= &"bbbcd".chars().collect();
+        assert_eq!(counter, "aaa".chars().collect());
+    }
+}
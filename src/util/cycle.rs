@@ -0,0 +1,162 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of an eventually periodic sequence: the first `start` states lead
+/// into a loop of `length` states that then repeats forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step whose state is the same as the state after `n` steps.
+    #[must_use]
+    pub const fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Iterates `step` from `initial` until a state repeats, returning the cycle
+/// together with every distinct state seen, in order.
+///
+/// The state at step `n` is `states[n]` for `n < start + length`, and repeats
+/// with period `length` after that.
+pub fn detect<S, F>(initial: S, mut step: F) -> (Cycle, Vec<S>)
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let length = states.len() - start;
+            return (Cycle { start, length }, states);
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// Finds the cycle that iterating `step` from `initial` falls into, using Brent's
+/// algorithm.
+///
+/// Unlike [`detect`] this keeps only a couple of states at a time and needs no
+/// hashing, at the cost of running `step` a few times over. It never returns if the
+/// states don't repeat.
+pub fn find_cycle<S, F>(initial: &S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    //Find the length: the hare runs ahead in stretches of doubling length, with the
+    //  tortoise waiting at the start of each, until it comes back round to it.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    //Find the start: with the hare a whole loop ahead, they first meet on entering it.
+    let mut tortoise = initial.clone();
+    let mut hare = (0..length).fold(initial.clone(), |state, _| step(&state));
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// The state after iterating `step` `n` times from `initial`, skipping round the
+/// cycle rather than running every step.
+pub fn nth<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let steps = find_cycle(&initial, &mut step).equivalent(n);
+    (0..steps).fold(initial, |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_pure_cycle() {
+        let (cycle, states) = detect(0, |&n| (n + 1) % 4);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        assert_eq!(states, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_detect_with_tail() {
+        //1 -> 2 -> 4 -> 8 -> 16 -> 12 -> 4 (mod 20)
+        let (cycle, states) = detect(1, |&n| n * 2 % 20);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 2,
+                length: 4
+            }
+        );
+        assert_eq!(states, vec![1, 2, 4, 8, 16, 12]);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        assert_eq!(
+            find_cycle(&1, |&n| n * 2 % 20),
+            detect(1, |&n| n * 2 % 20).0
+        );
+        assert_eq!(
+            find_cycle(&0, |&n| (n + 1) % 4),
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        //A state that maps to itself is a loop of one.
+        assert_eq!(
+            find_cycle(&5, |&n: &u32| n.saturating_sub(1)),
+            Cycle {
+                start: 5,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_nth() {
+        let step = |&n: &u64| n * 2 % 20;
+        assert_eq!(nth(1, step, 0), 1);
+        assert_eq!(nth(1, step, 3), 8);
+        //Steps 2 to 5 repeat 4, 8, 16, 12.
+        assert_eq!(nth(1, step, 1_000_000_001), 12);
+        let cycle = find_cycle(&1, step);
+        assert_eq!(cycle.equivalent(1), 1);
+        assert_eq!(cycle.equivalent(6), 2);
+    }
+}
@@ -0,0 +1,136 @@
+use super::point::Point;
+
+/// A compass direction on a grid drawn with north at the top, so north is
+/// towards smaller `y`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four straight directions, clockwise from north.
+    pub const ORTHOGONAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// The direction `eighths` eighths of a turn clockwise from this one.
+    const fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// A quarter turn anticlockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// A quarter turn clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The step that moves one cell this way.
+    #[must_use]
+    pub const fn offset(self) -> Point {
+        match self {
+            Self::North => Point::new(0, -1),
+            Self::NorthEast => Point::new(1, -1),
+            Self::East => Point::new(1, 0),
+            Self::SouthEast => Point::new(1, 1),
+            Self::South => Point::new(0, 1),
+            Self::SouthWest => Point::new(-1, 1),
+            Self::West => Point::new(-1, 0),
+            Self::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Reads `U`/`D`/`L`/`R` or an arrow `^`/`v`/`<`/`>`.
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Self::North),
+            'R' | '>' => Some(Self::East),
+            'D' | 'v' => Some(Self::South),
+            'L' | '<' => Some(Self::West),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> anyhow::Result<Self> {
+        Self::from_char(c).ok_or_else(|| anyhow::anyhow!("{c:?} is not a direction"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        assert_eq!(Direction::West.reverse(), Direction::East);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+        }
+        assert!(Direction::SouthWest.is_diagonal());
+        assert!(!Direction::South.is_diagonal());
+    }
+
+    #[test]
+    fn test_offset() {
+        let offsets: Vec<Point> = Direction::ORTHOGONAL.map(Direction::offset).to_vec();
+        assert_eq!(
+            offsets,
+            vec![
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+        assert_eq!(Direction::NorthWest.offset(), Point::new(-1, -1));
+    }
+
+    #[test]
+    fn test_from_char() {
+        assert_eq!(Direction::from_char('U'), Some(Direction::North));
+        assert_eq!(Direction::from_char('v'), Some(Direction::South));
+        assert_eq!(Direction::try_from('<').unwrap(), Direction::West);
+        assert!(Direction::try_from('x').is_err());
+    }
+}
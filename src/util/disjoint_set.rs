@@ -0,0 +1,137 @@
+/// Elements numbered from zero, split into sets that can be joined together.
+///
+/// Uses path compression and union by rank, so any sequence of operations runs in
+/// very nearly constant time each.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    /// The number of elements in each set, kept up to date for roots only.
+    sizes: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// `len` elements, each in a set of its own.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            sets: len,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of separate sets.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.sets
+    }
+
+    /// The root of the set holding `element`, which stands for the whole set.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        //Point everything on the way straight at the root.
+        let mut node = element;
+        while node != root {
+            node = std::mem::replace(&mut self.parents[node], root);
+        }
+        root
+    }
+
+    /// Joins the sets holding `a` and `b`, returning whether they were separate.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        //Hang the shallower tree under the deeper one.
+        let (root, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the set holding `element`.
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Every set's elements in increasing order, with the sets ordered by their
+    /// smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut slots = vec![None; self.len()];
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.sets);
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let slot = *slots[root].get_or_insert_with(|| {
+                components.push(Vec::with_capacity(self.sizes[root]));
+                components.len() - 1
+            });
+            components[slot].push(element);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut sets = DisjointSet::new(6);
+        assert_eq!(sets.count(), 6);
+        assert!(sets.union(0, 3));
+        assert!(sets.union(4, 3));
+        assert!(!sets.union(0, 4));
+        assert!(sets.union(1, 5));
+        assert_eq!(sets.count(), 3);
+        assert!(sets.connected(4, 0));
+        assert!(!sets.connected(4, 5));
+        assert_eq!(sets.size_of(3), 3);
+        assert_eq!(sets.size_of(2), 1);
+        let root = sets.find(5);
+        assert_eq!(sets.size_of(root), 2);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut sets = DisjointSet::new(7);
+        for (a, b) in [(6, 2), (5, 1), (2, 4), (1, 0)] {
+            sets.union(a, b);
+        }
+        assert_eq!(
+            sets.components(),
+            vec![vec![0, 1, 5], vec![2, 4, 6], vec![3]]
+        );
+        assert!(DisjointSet::new(0).components().is_empty());
+    }
+}
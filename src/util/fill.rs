@@ -0,0 +1,106 @@
+use std::{collections::HashSet, hash::Hash};
+
+use super::{grid::Grid, point::Point};
+
+/// The states a flood fill spread to, and the ones it was stopped by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<S: Hash + Eq> {
+    pub filled: HashSet<S>,
+    /// States next to the region that couldn't be passed through.
+    pub boundary: HashSet<S>,
+}
+
+/// Spreads out from `start` through every state `passable` allows, moving between
+/// states with `neighbors`.
+///
+/// If `start` itself can't be passed, nothing is filled and it is the whole boundary.
+pub fn flood_fill<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut passable: impl FnMut(&S) -> bool,
+) -> Region<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut region = Region {
+        filled: HashSet::new(),
+        boundary: HashSet::new(),
+    };
+    if !passable(&start) {
+        region.boundary.insert(start);
+        return region;
+    }
+    let mut stack = vec![start.clone()];
+    region.filled.insert(start);
+    while let Some(state) = stack.pop() {
+        for next in neighbors(&state) {
+            if region.filled.contains(&next) || region.boundary.contains(&next) {
+                continue;
+            }
+            if passable(&next) {
+                region.filled.insert(next.clone());
+                stack.push(next);
+            } else {
+                region.boundary.insert(next);
+            }
+        }
+    }
+    region
+}
+
+/// Flood fills `grid` from `start` through the orthogonally connected cells that
+/// `passable` allows. The edge of the grid stops the fill too, but isn't part of
+/// the boundary.
+pub fn flood_fill_grid<T>(
+    grid: &Grid<T>,
+    start: Point,
+    mut passable: impl FnMut(&T) -> bool,
+) -> Region<Point> {
+    flood_fill(
+        start,
+        |&point| grid.neighbors4(point).collect::<Vec<_>>(),
+        |&point| grid.get(point).is_some_and(&mut passable),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The height map from 2021 day 9, whose basins are walled off by 9s.
+    const HEIGHTS: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn test_flood_fill_grid() {
+        let grid = Grid::digits(HEIGHTS).unwrap();
+        let basin = |x, y| flood_fill_grid(&grid, Point::new(x, y), |&height| height < 9);
+        let sizes: Vec<usize> = [(1, 0), (9, 0), (2, 2), (6, 4)]
+            .iter()
+            .map(|&(x, y)| basin(x, y).filled.len())
+            .collect();
+        assert_eq!(sizes, vec![3, 9, 14, 9]);
+        let top_left = basin(0, 0);
+        let walls = [(2, 0), (0, 2), (1, 1)].map(|(x, y)| Point::new(x, y));
+        assert_eq!(top_left.boundary, HashSet::from(walls));
+        let wall = basin(2, 0);
+        assert!(wall.filled.is_empty());
+        assert_eq!(wall.boundary, HashSet::from([Point::new(2, 0)]));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        //Numbers reachable by steps of 3 and 5 without passing a multiple of 7.
+        let region = flood_fill(
+            1,
+            |&n: &i32| [n - 3, n + 3, n - 5, n + 5],
+            |&n| n % 7 != 0 && n.abs() < 10,
+        );
+        assert!(region.filled.contains(&-2) && region.filled.contains(&9));
+        assert!(!region.filled.contains(&14) && region.boundary.contains(&-7));
+    }
+}
@@ -0,0 +1,246 @@
+use super::{math::gcd, point::Point, rational::Ratio};
+
+/// Twice the area of the polygon with these vertices in order, by the shoelace
+/// formula. Doubling keeps the answer whole for any polygon on the integer grid.
+///
+/// The area is positive when the vertices run anticlockwise with `y` pointing up,
+/// and so clockwise as drawn on a grid where `y` counts rows down.
+pub fn shoelace(vertices: impl IntoIterator<Item = Point>) -> i128 {
+    let mut vertices = vertices.into_iter();
+    let Some(first) = vertices.next() else {
+        return 0;
+    };
+    let cross =
+        |a: Point, b: Point| i128::from(a.x) * i128::from(b.y) - i128::from(b.x) * i128::from(a.y);
+    let (last, twice_area) = vertices.fold((first, 0), |(previous, sum), vertex| {
+        (vertex, sum + cross(previous, vertex))
+    });
+    twice_area + cross(last, first)
+}
+
+/// The number of grid points on the edges of the polygon with these vertices.
+pub fn boundary_points(vertices: impl IntoIterator<Item = Point>) -> i128 {
+    let mut vertices = vertices.into_iter();
+    let Some(first) = vertices.next() else {
+        return 0;
+    };
+    let edge = |a: Point, b: Point| {
+        let Point { x, y } = b - a;
+        i128::from(gcd(x.unsigned_abs(), y.unsigned_abs()))
+    };
+    let (last, boundary) = vertices.fold((first, 0), |(previous, sum), vertex| {
+        (vertex, sum + edge(previous, vertex))
+    });
+    boundary + edge(last, first)
+}
+
+/// The number of grid points strictly inside a polygon whose corners are all on
+/// grid points, from Pick's theorem, given [`shoelace`]'s doubled area and the
+/// number of grid points on its boundary.
+#[must_use]
+pub const fn interior_points(twice_area: i128, boundary: i128) -> i128 {
+    (twice_area.abs() - boundary) / 2 + 1
+}
+
+/// The cross product of the offsets from `origin` to `a` and to `b`: positive when
+/// `b` is anticlockwise of `a` with `y` pointing up, negative when clockwise, and
+/// zero when the three points are in a line.
+#[must_use]
+pub fn cross(origin: Point, a: Point, b: Point) -> i128 {
+    let (a, b) = (a - origin, b - origin);
+    i128::from(a.x) * i128::from(b.y) - i128::from(b.x) * i128::from(a.y)
+}
+
+/// Whether the three points lie on one straight line.
+#[must_use]
+pub fn collinear(a: Point, b: Point, c: Point) -> bool {
+    cross(a, b, c) == 0
+}
+
+/// The straight line from `start` to `end`, both ends included.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Segment {
+    #[must_use]
+    pub const fn new(start: Point, end: Point) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether `point` is on the segment, ends included.
+    #[must_use]
+    pub fn contains(self, point: Point) -> bool {
+        collinear(self.start, self.end, point)
+            && point.x >= self.start.x.min(self.end.x)
+            && point.x <= self.start.x.max(self.end.x)
+            && point.y >= self.start.y.min(self.end.y)
+            && point.y <= self.start.y.max(self.end.y)
+    }
+
+    /// Whether the two segments share any point, counting a touch at an end or an
+    /// overlap along a shared line.
+    #[must_use]
+    pub fn intersects(self, other: Self) -> bool {
+        let side = |segment: Self, point| cross(segment.start, segment.end, point).signum();
+        let (a, b) = (side(self, other.start), side(self, other.end));
+        let (c, d) = (side(other, self.start), side(other, self.end));
+        if a * b < 0 && c * d < 0 {
+            return true;
+        }
+        self.contains(other.start)
+            || self.contains(other.end)
+            || other.contains(self.start)
+            || other.contains(self.end)
+    }
+
+    /// The single point where the two segments meet, which may fall between grid
+    /// points. `None` if they miss, or if they are parallel and so meet nowhere or
+    /// all along an overlap.
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Option<(Ratio, Ratio)> {
+        let direction = self.end - self.start;
+        let other_direction = other.end - other.start;
+        let denom = cross(Point::ORIGIN, direction, other_direction);
+        if denom == 0 || !self.intersects(other) {
+            return None;
+        }
+        //How far along this segment the crossing is, as a fraction of its length.
+        let along = Ratio::checked_new(cross(self.start, other.start, other.end), denom)?;
+        let at = |start: i64, step: i64| {
+            Ratio::from(start).checked_add(along.checked_mul(Ratio::from(step))?)
+        };
+        Some((
+            at(self.start.x, direction.x)?,
+            at(self.start.y, direction.y)?,
+        ))
+    }
+}
+
+/// Where a point is relative to a polygon.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Finds whether `point` is inside the polygon with these vertices in order, by
+/// counting how many edges a ray from it towards increasing `x` crosses.
+#[must_use]
+pub fn locate(point: Point, vertices: &[Point]) -> Location {
+    let mut inside = false;
+    for (i, &start) in vertices.iter().enumerate() {
+        let end = vertices[(i + 1) % vertices.len()];
+        if Segment::new(start, end).contains(point) {
+            return Location::Boundary;
+        }
+        //Counting each edge as holding its lower end but not its upper one means a
+        //  ray through a vertex is counted once, or not at all if it only grazes it.
+        if (start.y > point.y) != (end.y > point.y) {
+            let side = cross(start, end, point);
+            if (side > 0) == (end.y > start.y) {
+                inside = !inside;
+            }
+        }
+    }
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(i64, i64)]) -> Vec<Point> {
+        vertices.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_shoelace() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(shoelace(square.iter().copied()), 32);
+        assert_eq!(shoelace(square.iter().rev().copied()), -32);
+        let triangle = polygon(&[(0, 0), (3, 0), (0, 1)]);
+        assert_eq!(shoelace(triangle), 3);
+        assert_eq!(shoelace([]), 0);
+    }
+
+    #[test]
+    fn test_pick() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        let boundary = boundary_points(square.iter().copied());
+        assert_eq!(boundary, 16);
+        assert_eq!(interior_points(shoelace(square), boundary), 9);
+        //A diagonal edge only passes through the grid points where its slope allows.
+        let triangle = polygon(&[(0, 0), (6, 0), (0, 4)]);
+        let boundary = boundary_points(triangle.iter().copied());
+        assert_eq!(boundary, 6 + 4 + 2);
+        assert_eq!(interior_points(shoelace(triangle), boundary), 7);
+    }
+
+    #[test]
+    fn test_collinear() {
+        assert!(collinear(
+            Point::new(1, 1),
+            Point::new(3, 4),
+            Point::new(7, 10)
+        ));
+        assert!(!collinear(
+            Point::new(1, 1),
+            Point::new(3, 4),
+            Point::new(7, 11)
+        ));
+        assert!(cross(Point::ORIGIN, Point::new(1, 0), Point::new(0, 1)) > 0);
+        //Antennas at (4, 3) and (5, 5) make antinodes at (3, 1) and (6, 7).
+        let (a, b) = (Point::new(4, 3), Point::new(5, 5));
+        assert!(collinear(a, b, a - (b - a)) && collinear(a, b, b + (b - a)));
+    }
+
+    #[test]
+    fn test_segments() {
+        let diagonal = Segment::new(Point::new(0, 0), Point::new(4, 4));
+        let other = Segment::new(Point::new(0, 4), Point::new(4, 0));
+        assert!(diagonal.intersects(other));
+        assert_eq!(
+            diagonal.intersection(other),
+            Some((Ratio::integer(2), Ratio::integer(2)))
+        );
+        let offset = Segment::new(Point::new(0, 1), Point::new(1, 0));
+        let steep = Segment::new(Point::new(0, 0), Point::new(1, 2));
+        assert_eq!(
+            offset.intersection(steep),
+            Some((Ratio::new(1, 3), Ratio::new(2, 3)))
+        );
+        let short = Segment::new(Point::new(0, 4), Point::new(1, 3));
+        assert!(!diagonal.intersects(short));
+        assert_eq!(diagonal.intersection(short), None);
+        //Touching at an end, and overlapping along a line.
+        let touching = Segment::new(Point::new(4, 4), Point::new(9, 0));
+        assert!(diagonal.intersects(touching));
+        let overlap = Segment::new(Point::new(2, 2), Point::new(6, 6));
+        assert!(diagonal.intersects(overlap));
+        assert_eq!(diagonal.intersection(overlap), None);
+        let beyond = Segment::new(Point::new(5, 5), Point::new(6, 6));
+        assert!(!diagonal.intersects(beyond));
+    }
+
+    #[test]
+    fn test_locate() {
+        //An L shape, with a vertex level with the points tested.
+        let shape = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+        assert_eq!(locate(Point::new(1, 1), &shape), Location::Inside);
+        assert_eq!(locate(Point::new(1, 3), &shape), Location::Inside);
+        assert_eq!(locate(Point::new(3, 3), &shape), Location::Outside);
+        assert_eq!(locate(Point::new(1, 2), &shape), Location::Inside);
+        assert_eq!(locate(Point::new(-1, 2), &shape), Location::Outside);
+        assert_eq!(locate(Point::new(3, 2), &shape), Location::Boundary);
+        assert_eq!(locate(Point::new(4, 1), &shape), Location::Boundary);
+        assert_eq!(locate(Point::new(5, 0), &shape), Location::Outside);
+    }
+}
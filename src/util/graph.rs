@@ -0,0 +1,289 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+};
+
+/// An undirected graph whose nodes are numbered from zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    #[must_use]
+    pub fn with_nodes(nodes: usize) -> Self {
+        Self {
+            adjacency: vec![Vec::new(); nodes],
+        }
+    }
+
+    /// Builds a graph from edges between labelled nodes, numbering the labels in
+    /// the order they first appear. Returns the labels in that order too.
+    pub fn from_labelled_edges<T, I>(edges: I) -> (Self, Vec<T>)
+    where
+        T: Clone + Eq + Hash,
+        I: IntoIterator<Item = (T, T)>,
+    {
+        let mut indices = HashMap::new();
+        let mut labels = Vec::new();
+        let mut graph = Self::default();
+        let mut index = |label: T, graph: &mut Self| {
+            *indices.entry(label.clone()).or_insert_with(|| {
+                labels.push(label);
+                graph.adjacency.push(Vec::new());
+                graph.adjacency.len() - 1
+            })
+        };
+        for (a, b) in edges {
+            let (a, b) = (index(a, &mut graph), index(b, &mut graph));
+            graph.add_edge(a, b);
+        }
+        (graph, labels)
+    }
+
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        self.adjacency[a].push(b);
+        self.adjacency[b].push(a);
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    #[must_use]
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+}
+
+/// A way of splitting a graph in two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    /// The number of edges between the two sides.
+    pub weight: u64,
+    /// The nodes on one side; every other node is on the other.
+    pub side: Vec<usize>,
+}
+
+/// Finds a cut through the fewest edges using the Stoer-Wagner algorithm.
+///
+/// Returns `None` for graphs with fewer than two nodes, which can't be split.
+#[must_use]
+pub fn min_cut(graph: &Graph) -> Option<Cut> {
+    //Each node's edges, merged into weights as nodes are contracted together.
+    let mut weights: Vec<HashMap<usize, u64>> = (0..graph.len())
+        .map(|node| {
+            let mut weights = HashMap::new();
+            for &neighbor in graph.neighbors(node) {
+                if neighbor != node {
+                    *weights.entry(neighbor).or_insert(0) += 1;
+                }
+            }
+            weights
+        })
+        .collect();
+    let mut members: Vec<Vec<usize>> = (0..graph.len()).map(|node| vec![node]).collect();
+    let mut active: Vec<usize> = (0..graph.len()).collect();
+
+    let mut best: Option<Cut> = None;
+    while active.len() > 1 {
+        //A phase adds nodes one at a time, always the one most tightly connected
+        //  to those already added. The cut separating the last node from the rest
+        //  is a minimum cut between the last two.
+        let mut added = vec![false; graph.len()];
+        let mut connection = vec![0; graph.len()];
+        let mut queue = BinaryHeap::from([(0, active[0])]);
+        let (mut previous, mut last) = (None, active[0]);
+        let mut cut_weight = 0;
+        while let Some((weight, node)) = queue.pop() {
+            if added[node] || weight != connection[node] {
+                continue;
+            }
+            added[node] = true;
+            (previous, last, cut_weight) = (Some(last), node, weight);
+            for (&neighbor, &edge) in &weights[node] {
+                if !added[neighbor] {
+                    connection[neighbor] += edge;
+                    queue.push((connection[neighbor], neighbor));
+                }
+            }
+        }
+        //Any nodes never reached aren't connected to the rest at all.
+        if let Some(&unreached) = active.iter().find(|&&node| !added[node]) {
+            return Some(Cut {
+                weight: 0,
+                side: members[unreached].clone(),
+            });
+        }
+
+        if best.as_ref().is_none_or(|best| cut_weight < best.weight) {
+            best = Some(Cut {
+                weight: cut_weight,
+                side: members[last].clone(),
+            });
+        }
+
+        //Contract the last node into the one before it.
+        let keep = previous.filter(|&previous| previous != last)?;
+        let merged = std::mem::take(&mut weights[last]);
+        for (neighbor, edge) in merged {
+            weights[neighbor].remove(&last);
+            if neighbor != keep {
+                *weights[keep].entry(neighbor).or_insert(0) += edge;
+                *weights[neighbor].entry(keep).or_insert(0) += edge;
+            }
+        }
+        let moved = std::mem::take(&mut members[last]);
+        members[keep].extend(moved);
+        active.retain(|&node| node != last);
+    }
+    best
+}
+
+/// Orders the nodes of a directed graph so every edge points forwards, where
+/// `edges` maps each node to the nodes that must come after it. When several nodes
+/// could come next, the smallest goes first.
+///
+/// If the edges go round in a loop there is no such order, and the nodes of one
+/// loop are returned instead, starting from its smallest.
+pub fn topo_sort<T>(edges: &HashMap<T, Vec<T>>) -> Result<Vec<T>, Vec<T>>
+where
+    T: Clone + Eq + Hash + Ord,
+{
+    let mut predecessors: HashMap<&T, Vec<&T>> = HashMap::new();
+    for (before, afters) in edges {
+        predecessors.entry(before).or_default();
+        for after in afters {
+            predecessors.entry(after).or_default().push(before);
+        }
+    }
+    let mut waiting: HashMap<&T, usize> = predecessors
+        .iter()
+        .map(|(&node, before)| (node, before.len()))
+        .collect();
+    let mut ready: BinaryHeap<Reverse<&T>> = waiting
+        .iter()
+        .filter(|&(_, &count)| count == 0)
+        .map(|(&node, _)| Reverse(node))
+        .collect();
+    let mut order = Vec::with_capacity(waiting.len());
+    while let Some(Reverse(node)) = ready.pop() {
+        order.push(node.clone());
+        for after in edges.get(node).into_iter().flatten() {
+            let count = waiting.get_mut(after).expect("every node is counted");
+            *count -= 1;
+            if *count == 0 {
+                ready.push(Reverse(after));
+            }
+        }
+    }
+    if order.len() == predecessors.len() {
+        return Ok(order);
+    }
+    //Every node left over still waits on another left over node, so walking back
+    //through those must come round to somewhere it has been before.
+    let left: HashSet<&T> = waiting
+        .into_iter()
+        .filter(|&(_, count)| count > 0)
+        .map(|(node, _)| node)
+        .collect();
+    let mut node = *left.iter().min().expect("some node is left over");
+    let mut walk = Vec::new();
+    let mut seen = HashMap::new();
+    while let Entry::Vacant(entry) = seen.entry(node) {
+        entry.insert(walk.len());
+        walk.push(node);
+        node = predecessors[node]
+            .iter()
+            .copied()
+            .filter(|before| left.contains(before))
+            .min()
+            .expect("a left over node waits on another");
+    }
+    let mut cycle: Vec<T> = walk[seen[node]..]
+        .iter()
+        .rev()
+        .map(|&node| node.clone())
+        .collect();
+    let smallest = cycle
+        .iter()
+        .enumerate()
+        .min_by_key(|&(_, node)| node)
+        .map_or(0, |(i, _)| i);
+    cycle.rotate_left(smallest);
+    Err(cycle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_labelled_edges() {
+        let (graph, labels) = Graph::from_labelled_edges([("a", "b"), ("b", "c"), ("a", "c")]);
+        assert_eq!(labels, vec!["a", "b", "c"]);
+        assert_eq!(graph.neighbors(0), &[1, 2]);
+        assert_eq!(graph.neighbors(1), &[0, 2]);
+    }
+
+    #[test]
+    fn test_min_cut_bridge() {
+        //Two triangles joined by a single edge.
+        let mut graph = Graph::with_nodes(6);
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+            graph.add_edge(a, b);
+        }
+        let mut cut = min_cut(&graph).unwrap();
+        cut.side.sort_unstable();
+        assert_eq!(cut.weight, 1);
+        assert!(cut.side == vec![0, 1, 2] || cut.side == vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_min_cut_disconnected() {
+        let mut graph = Graph::with_nodes(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(2, 3);
+        assert_eq!(min_cut(&graph).map(|cut| cut.weight), Some(0));
+    }
+
+    #[test]
+    fn test_min_cut_too_small() {
+        assert_eq!(min_cut(&Graph::with_nodes(1)), None);
+    }
+
+    #[test]
+    fn test_topo_sort() {
+        //The steps from 2018 day 7's example.
+        let edges = HashMap::from([
+            ('C', vec!['A', 'F']),
+            ('A', vec!['B', 'D']),
+            ('B', vec!['E']),
+            ('D', vec!['E']),
+            ('F', vec!['E']),
+        ]);
+        let order: String = topo_sort(&edges).unwrap().into_iter().collect();
+        assert_eq!(order, "CABDFE");
+        assert_eq!(topo_sort::<u8>(&HashMap::new()), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_topo_sort_cycle() {
+        let edges = HashMap::from([
+            (1, vec![2]),
+            (2, vec![5]),
+            (5, vec![3, 9]),
+            (3, vec![4]),
+            (4, vec![2]),
+        ]);
+        assert_eq!(topo_sort(&edges), Err(vec![2, 5, 3, 4]));
+        assert_eq!(topo_sort(&HashMap::from([(7, vec![7])])), Err(vec![7]));
+    }
+}
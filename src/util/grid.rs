@@ -0,0 +1,340 @@
+use std::{
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::Result;
+
+use super::{direction::Direction, point::Point};
+
+/// A rectangular grid of cells stored row by row, indexed by [`Point`].
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `fill`.
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// A `width` by `height` grid whose cell at `(x, y)` is copied from the point
+    /// `source(x, y)` of this one.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Point) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// The grid mirrored in its leading diagonal, so rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| Point::from((y, x)))
+    }
+
+    /// The grid turned a quarter turn clockwise, so what was the left column is now
+    /// the top row.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height;
+        self.remap(height, self.width, |x, y| Point::from((y, height - 1 - x)))
+    }
+
+    /// The grid turned a quarter turn anticlockwise.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, width, |x, y| Point::from((width - 1 - y, x)))
+    }
+
+    /// The grid mirrored left to right.
+    #[must_use]
+    pub fn flip_h(&self) -> Self {
+        let width = self.width;
+        self.remap(width, self.height, |x, y| Point::from((width - 1 - x, y)))
+    }
+
+    /// The grid mirrored top to bottom.
+    #[must_use]
+    pub fn flip_v(&self) -> Self {
+        let height = self.height;
+        self.remap(self.width, height, |x, y| Point::from((x, height - 1 - y)))
+    }
+
+    /// All eight ways the grid can be turned and flipped: the four rotations of the
+    /// grid, then the four of its mirror image.
+    pub fn orientations(&self) -> impl Iterator<Item = Self> {
+        [self.clone(), self.flip_h()].into_iter().flat_map(|grid| {
+            std::iter::successors(Some(grid), |grid| Some(grid.rotate_cw())).take(4)
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            return Err(anyhow::anyhow!("row {} is not {width} wide", row + 1));
+        }
+        let height = rows.len();
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Parses a grid of characters, one row per line, turning each into a cell with `cell`.
+    ///
+    /// Errors name the line at fault, including any line that isn't as wide as the first.
+    pub fn parse(input: &str, mut cell: impl FnMut(u8) -> Result<T>) -> Result<Self> {
+        let mut width = None;
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                let expected = *width.get_or_insert(line.len());
+                if line.len() != expected {
+                    return Err(anyhow::anyhow!(
+                        "line {}: {} wide, expected {expected}",
+                        row + 1,
+                        line.len()
+                    ));
+                }
+                line.bytes()
+                    .map(&mut cell)
+                    .collect::<Result<Vec<T>>>()
+                    .map_err(|e| anyhow::anyhow!("line {}: {e}", row + 1))
+            })
+            .collect::<Result<_>>()?;
+        Self::from_rows(rows)
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.position(point).is_some()
+    }
+
+    /// Where `point` is in `cells`, if it is on the grid.
+    fn position(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    const fn point(&self, position: usize) -> Point {
+        Point::new(
+            (position % self.width) as i64,
+            (position / self.width) as i64,
+        )
+    }
+
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.position(point).map(|position| &self.cells[position])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.position(point)
+            .map(|position| &mut self.cells[position])
+    }
+
+    fn steps(
+        &self,
+        point: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + '_ {
+        directions
+            .iter()
+            .map(move |direction| point + direction.offset())
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// The neighbours above, right, below and left of `point` that are on the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.steps(point, &Direction::ORTHOGONAL)
+    }
+
+    /// All eight neighbours of `point`, diagonals included, that are on the grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.steps(point, &Direction::ALL)
+    }
+
+    /// Every point on the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|position| self.point(position))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(position, cell)| (self.point(position), cell))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        //`chunks` won't take a size of 0, but then there are no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells.iter().skip(x).step_by(self.width))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is off the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is off the grid"))
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a block of single digits, such as heights or costs, into their values.
+    pub fn digits(input: &str) -> Result<Self> {
+        Self::parse(input, |c| match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            _ => Err(anyhow::anyhow!("{:?} is not a digit", c as char)),
+        })
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = anyhow::Error;
+
+    /// A grid of the raw characters.
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s, Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc
+def
+";
+
+    #[test]
+    fn test_from_str() {
+        let grid: Grid<u8> = EXAMPLE.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], b'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert!("ab\nc".parse::<Grid<u8>>().is_err());
+        assert!("".parse::<Grid<u8>>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_digits() {
+        let grid = Grid::digits("12\n34").unwrap();
+        assert_eq!(grid[Point::new(1, 1)], 4);
+        let error = Grid::digits("12\n3x").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: 'x' is not a digit");
+        let error = Grid::digits("123\n456\n78").unwrap_err();
+        assert_eq!(error.to_string(), "line 3: 2 wide, expected 3");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        let corner: Vec<_> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors4(Point::new(2, 1)).count(), 3);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let mut grid: Grid<u8> = EXAMPLE.parse().unwrap();
+        grid[Point::new(1, 0)] = b'B';
+        let rows: Vec<&[u8]> = grid.rows().collect();
+        assert_eq!(rows, vec![b"aBc".as_slice(), b"def".as_slice()]);
+        let cols: Vec<Vec<u8>> = grid.cols().map(|col| col.copied().collect()).collect();
+        assert_eq!(cols, vec![b"ad".to_vec(), b"Be".to_vec(), b"cf".to_vec()]);
+        let found = grid.enumerate().find(|&(_, &c)| c == b'e');
+        assert_eq!(found, Some((Point::new(1, 1), &b'e')));
+        assert_eq!(grid.points().last(), Some(Point::new(2, 1)));
+
+        let empty = Grid::new(3, 0, 0u8);
+        assert_eq!(empty.rows().count(), 0);
+        let cols: Vec<usize> = empty.cols().map(Iterator::count).collect();
+        assert_eq!(cols, vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid: Grid<u8> = EXAMPLE.parse().unwrap();
+        let show = |grid: &Grid<u8>| -> Vec<String> {
+            grid.rows()
+                .map(|row| String::from_utf8(row.to_vec()).unwrap())
+                .collect()
+        };
+        assert_eq!(show(&grid.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(show(&grid.rotate_cw()), ["da", "eb", "fc"]);
+        assert_eq!(show(&grid.rotate_ccw()), ["cf", "be", "ad"]);
+        assert_eq!(show(&grid.flip_h()), ["cba", "fed"]);
+        assert_eq!(show(&grid.flip_v()), ["def", "abc"]);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_h().flip_v());
+    }
+
+    #[test]
+    fn test_orientations() {
+        let grid: Grid<u8> = EXAMPLE.parse().unwrap();
+        let orientations: Vec<Grid<u8>> = grid.orientations().collect();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert!(orientations.contains(&grid.transpose()));
+        assert!(orientations.contains(&grid.flip_v()));
+        assert!(orientations.contains(&grid.rotate_ccw()));
+        //A grid with no symmetry looks different every way round.
+        assert!(orientations
+            .iter()
+            .enumerate()
+            .all(|(i, a)| orientations[i + 1..].iter().all(|b| a != b)));
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasherDefault, Hasher},
+    ops::BitXor,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use crypto::{digest::Digest, md5::Md5};
+
+/// The Holiday ASCII String Helper algorithm from 2023 day 15: for each byte, add
+/// it to the current value, multiply by 17 and keep the remainder mod 256.
+#[must_use]
+pub fn holiday_ascii(input: &[u8]) -> u8 {
+    input
+        .iter()
+        .fold(0, |acc: u8, &byte| acc.wrapping_add(byte).wrapping_mul(17))
+}
+
+/// The fast, non-cryptographic hash used inside rustc. Much quicker than the
+/// standard library's default for the small integer keys puzzles use, but offers no
+/// protection against keys chosen to collide.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    fn add(&mut self, word: u64) {
+        self.hash = self
+            .hash
+            .rotate_left(5)
+            .bitxor(word)
+            .wrapping_mul(Self::SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(
+                chunk.try_into().expect("chunks are 8 bytes"),
+            ));
+        }
+        for &byte in chunks.remainder() {
+            self.add(u64::from(byte));
+        }
+    }
+
+    fn write_u8(&mut self, n: u8) {
+        self.add(u64::from(n));
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.add(u64::from(n));
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.add(n);
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.add(n as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+pub type FxHashMap<K, V> = HashMap<K, V, FxBuildHasher>;
+pub type FxHashSet<T> = HashSet<T, FxBuildHasher>;
+
+/// The MD5 digest of `input`.
+#[must_use]
+pub fn md5(input: &[u8]) -> [u8; 16] {
+    let mut hasher = Md5::new();
+    hasher.input(input);
+    let mut digest = [0; 16];
+    hasher.result(&mut digest);
+    digest
+}
+
+/// The MD5 digest of `input` as 32 lowercase hex digits.
+#[must_use]
+pub fn md5_hex(input: &[u8]) -> String {
+    md5(input)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// How many hex digits the digest starts with that are zero.
+#[must_use]
+pub fn leading_zero_nibbles(digest: &[u8; 16]) -> u32 {
+    let bits: u32 = digest
+        .iter()
+        .position(|&byte| byte != 0)
+        .map_or(128, |index| {
+            index as u32 * 8 + digest[index].leading_zeros()
+        });
+    bits / 4
+}
+
+/// The number of nonces a thread claims at a time.
+const BATCH: u64 = 4096;
+
+/// The smallest nonce from `start` on whose digest of `key` followed by the nonce in
+/// decimal satisfies `accept`, as in the 2015 day 4 and 2016 day 5 puzzles.
+///
+/// Every core claims batches of nonces in turn from a shared counter. Once any of
+/// them finds a match, the others finish the batches they hold, which could still
+/// hold a smaller one, but claim no more past it.
+#[must_use]
+pub fn find_nonce(key: &str, start: u64, accept: impl Fn(&[u8; 16]) -> bool + Sync) -> Option<u64> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get() as u64);
+    //Leave room for every thread to overshoot by a batch without the counter wrapping.
+    let limit = u64::MAX - (threads + 1) * BATCH;
+    let next = AtomicU64::new(start);
+    let best = AtomicU64::new(u64::MAX);
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                let mut input = key.as_bytes().to_vec();
+                loop {
+                    let from = next.fetch_add(BATCH, Ordering::Relaxed);
+                    if from > limit || from >= best.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    let found = (from..from + BATCH).find(|nonce| {
+                        input.truncate(key.len());
+                        input.extend_from_slice(nonce.to_string().as_bytes());
+                        accept(&md5(&input))
+                    });
+                    if let Some(nonce) = found {
+                        best.fetch_min(nonce, Ordering::Relaxed);
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    Some(best.into_inner()).filter(|&nonce| nonce != u64::MAX)
+}
+
+/// The smallest nonce from `start` on whose digest of `key` followed by the nonce
+/// starts with `zeros` zeros in hex.
+#[must_use]
+pub fn find_zeros(key: &str, start: u64, zeros: u32) -> Option<u64> {
+    find_nonce(key, start, |digest| leading_zero_nibbles(digest) >= zeros)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holiday_ascii() {
+        assert_eq!(holiday_ascii(b"HASH"), 52);
+        assert_eq!(holiday_ascii(b"rn=1"), 30);
+        assert_eq!(holiday_ascii(b"rn"), 0);
+        assert_eq!(holiday_ascii(b""), 0);
+    }
+
+    #[test]
+    fn test_fx_hash() {
+        let hash = |value: &(u32, &str)| {
+            let mut hasher = FxHasher::default();
+            std::hash::Hash::hash(value, &mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&(7, "seven")), hash(&(7, "seven")));
+        assert_ne!(hash(&(7, "seven")), hash(&(8, "seven")));
+        let squares: FxHashMap<u64, u64> = (0..1000).map(|n| (n, n * n)).collect();
+        assert_eq!(squares.get(&999), Some(&998_001));
+    }
+
+    #[test]
+    fn test_md5() {
+        assert_eq!(md5_hex(b""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(md5_hex(b"abcdef609043")[..5], *"00000");
+    }
+
+    #[test]
+    fn test_leading_zero_nibbles() {
+        let mut digest = [0; 16];
+        assert_eq!(leading_zero_nibbles(&digest), 32);
+        digest[2] = 0x0f;
+        assert_eq!(leading_zero_nibbles(&digest), 5);
+        digest[1] = 0x10;
+        assert_eq!(leading_zero_nibbles(&digest), 2);
+    }
+
+    #[test]
+    fn test_find_nonce() {
+        let three_zeros = |digest: &[u8; 16]| leading_zero_nibbles(digest) >= 3;
+        let nonce = find_nonce("abcdef", 0, three_zeros).unwrap();
+        assert!(md5_hex(format!("abcdef{nonce}").as_bytes()).starts_with("000"));
+        assert!((0..nonce).all(|n| !three_zeros(&md5(format!("abcdef{n}").as_bytes()))));
+        //Searching on from just past it finds the next one.
+        assert!(find_nonce("abcdef", nonce + 1, three_zeros).unwrap() > nonce);
+        assert_eq!(find_zeros("abcdef", 0, 3), Some(nonce));
+    }
+}
@@ -0,0 +1,210 @@
+use std::ops::{Add, AddAssign, Sub};
+
+use anyhow::Result;
+
+/// One of the six ways to step between hexagons in a grid with pointed tops, where
+/// rows of hexagons run east to west.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    /// All six directions, clockwise from east.
+    pub const ALL: [Self; 6] = [
+        Self::East,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+        Self::NorthEast,
+    ];
+
+    #[must_use]
+    pub const fn offset(self) -> Hex {
+        match self {
+            Self::East => Hex::new(1, 0),
+            Self::SouthEast => Hex::new(0, 1),
+            Self::SouthWest => Hex::new(-1, 1),
+            Self::West => Hex::new(-1, 0),
+            Self::NorthWest => Hex::new(0, -1),
+            Self::NorthEast => Hex::new(1, -1),
+        }
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 3) % 6]
+    }
+
+    /// Reads `e`, `se`, `sw`, `w`, `nw` or `ne`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "e" => Some(Self::East),
+            "se" => Some(Self::SouthEast),
+            "sw" => Some(Self::SouthWest),
+            "w" => Some(Self::West),
+            "nw" => Some(Self::NorthWest),
+            "ne" => Some(Self::NorthEast),
+            _ => None,
+        }
+    }
+
+    /// Reads `n`, `ne`, `se`, `s`, `sw` or `nw` on a grid with flat tops, where
+    /// columns run north to south, as the same grid turned a twelfth clockwise.
+    /// Distances come out the same.
+    #[must_use]
+    pub fn from_flat_name(name: &str) -> Option<Self> {
+        match name {
+            "n" => Some(Self::NorthWest),
+            "ne" => Some(Self::NorthEast),
+            "se" => Some(Self::East),
+            "s" => Some(Self::SouthEast),
+            "sw" => Some(Self::SouthWest),
+            "nw" => Some(Self::West),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a run of steps written with no separators, such as `esenee`.
+pub fn parse_moves(input: &str) -> Result<Vec<HexDirection>> {
+    let mut moves = Vec::new();
+    let mut rest = input.trim();
+    while !rest.is_empty() {
+        //North and south never stand alone, so take the second letter with them.
+        let len = if rest.starts_with(['n', 's']) { 2 } else { 1 };
+        let (name, after) = rest.split_at(len.min(rest.len()));
+        let step = HexDirection::from_name(name)
+            .ok_or_else(|| anyhow::anyhow!("unexpected {name:?} in {input:?}"))?;
+        moves.push(step);
+        rest = after;
+    }
+    Ok(moves)
+}
+
+/// A hexagon in axial coordinates. The third cube coordinate, [`Hex::s`], is
+/// implied by the three summing to zero.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    #[must_use]
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    #[must_use]
+    pub const fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// The fewest steps between the two hexagons.
+    #[must_use]
+    pub const fn distance(self, other: Self) -> i64 {
+        let (dq, dr) = ((self.q - other.q).abs(), (self.r - other.r).abs());
+        let ds = (self.s() - other.s()).abs();
+        (dq + dr + ds) / 2
+    }
+
+    #[must_use]
+    pub const fn step(self, direction: HexDirection) -> Self {
+        let offset = direction.offset();
+        Self::new(self.q + offset.q, self.r + offset.r)
+    }
+
+    /// The six hexagons touching this one, clockwise from east.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        HexDirection::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walk(moves: &[HexDirection]) -> Hex {
+        moves.iter().fold(Hex::ORIGIN, |hex, &step| hex.step(step))
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        //The examples from 2020 day 24.
+        let moves = parse_moves("esew").unwrap();
+        assert_eq!(
+            moves,
+            vec![
+                HexDirection::East,
+                HexDirection::SouthEast,
+                HexDirection::West
+            ]
+        );
+        assert_eq!(walk(&moves), HexDirection::SouthEast.offset());
+        assert_eq!(walk(&parse_moves("nwwswee").unwrap()), Hex::ORIGIN);
+        assert!(parse_moves("en").is_err());
+        assert!(parse_moves("ex").is_err());
+    }
+
+    #[test]
+    fn test_distance() {
+        //The examples from 2017 day 11, on a grid with flat tops.
+        let distance = |path: &str| {
+            let moves: Vec<HexDirection> = path
+                .split(',')
+                .map(|name| HexDirection::from_flat_name(name).unwrap())
+                .collect();
+            walk(&moves).distance(Hex::ORIGIN)
+        };
+        assert_eq!(distance("ne,ne,ne"), 3);
+        assert_eq!(distance("ne,ne,sw,sw"), 0);
+        assert_eq!(distance("ne,ne,s,s"), 2);
+        assert_eq!(distance("se,sw,se,sw,sw"), 3);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let hex = Hex::new(2, -1);
+        assert!(hex.neighbors().all(|next| next.distance(hex) == 1));
+        assert_eq!(hex.neighbors().count(), 6);
+        for direction in HexDirection::ALL {
+            assert_eq!(hex.step(direction).step(direction.reverse()), hex);
+        }
+        assert_eq!(Hex::new(3, -1) - Hex::new(1, 1), Hex::new(2, -2));
+        assert_eq!(Hex::new(2, -2).s(), 0);
+    }
+}
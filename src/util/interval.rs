@@ -0,0 +1,403 @@
+/// A half-open range of integers `[start, end)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    #[must_use]
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    #[must_use]
+    pub const fn from_len(start: i64, len: i64) -> Self {
+        Self {
+            start,
+            end: start + len,
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> i64 {
+        self.end - self.start
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    #[must_use]
+    pub const fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let overlap = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    #[must_use]
+    pub const fn shift(&self, offset: i64) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+
+    /// The parts below and from `value`, either of which may be empty.
+    #[must_use]
+    pub fn split_at(&self, value: i64) -> (Self, Self) {
+        let value = value.clamp(self.start, self.end.max(self.start));
+        (Self::new(self.start, value), Self::new(value, self.end))
+    }
+}
+
+/// Sorts `intervals` and joins any that overlap or touch, dropping empty ones.
+#[must_use]
+pub fn merge(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.retain(|interval| !interval.is_empty());
+    intervals.sort_unstable();
+
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
+/// A set of integers stored as the sorted, separate intervals that cover it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    /// Sorted, non-empty and neither overlapping nor touching.
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    #[must_use]
+    pub fn count(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    #[must_use]
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    #[must_use]
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        //The intervals after the one that could hold `value` all start past it.
+        let after = self
+            .intervals
+            .partition_point(|interval| interval.start <= value);
+        after > 0 && self.intervals[after - 1].contains(value)
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        self.intervals = merge(intervals);
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        //Both lists are sorted, so walk them together like a merge.
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        let mut intervals = Vec::new();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            intervals.extend(x.intersection(y));
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Everything in this set that isn't in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut holes = other.intervals.iter().peekable();
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            while let Some(hole) = holes.peek() {
+                if hole.start >= rest.end {
+                    break;
+                }
+                let (before, _) = rest.split_at(hole.start);
+                let (_, after) = rest.split_at(hole.end);
+                if !before.is_empty() {
+                    intervals.push(before);
+                }
+                rest = after;
+                //A hole reaching past this interval may cut into the next one too.
+                if hole.end > interval.end {
+                    break;
+                }
+                holes.next();
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> Self {
+        std::iter::once(interval).collect()
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        Self {
+            intervals: merge(iter.into_iter().collect()),
+        }
+    }
+}
+
+/// One piece of a [`PiecewiseMap`]: every value in `source` is moved by `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shift {
+    pub source: Interval,
+    pub offset: i64,
+}
+
+/// A mapping made of non-overlapping shifted pieces. Values that fall outside
+/// every piece map to themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    pieces: Vec<Shift>,
+}
+
+impl PiecewiseMap {
+    #[must_use]
+    pub fn new(pieces: impl IntoIterator<Item = Shift>) -> Self {
+        let mut pieces: Vec<Shift> = pieces.into_iter().collect();
+        pieces.sort_unstable_by_key(|piece| piece.source);
+        Self { pieces }
+    }
+
+    #[must_use]
+    pub fn apply(&self, value: i64) -> i64 {
+        self.pieces
+            .iter()
+            .find(|piece| piece.source.contains(value))
+            .map_or(value, |piece| value + piece.offset)
+    }
+
+    /// Maps every value in `interval`, splitting it wherever it crosses a piece
+    /// boundary. The result is not merged.
+    #[must_use]
+    pub fn apply_interval(&self, interval: Interval) -> Vec<Interval> {
+        let mut mapped = Vec::new();
+        let mut cursor = interval.start;
+        for piece in &self.pieces {
+            if cursor >= interval.end {
+                break;
+            }
+            let Some(overlap) = piece.source.intersection(&interval) else {
+                continue;
+            };
+            //Anything between the last piece and this one is left untouched.
+            if cursor < overlap.start {
+                mapped.push(Interval::new(cursor, overlap.start));
+            }
+            mapped.push(overlap.shift(piece.offset));
+            cursor = overlap.end;
+        }
+        if cursor < interval.end {
+            mapped.push(Interval::new(cursor, interval.end));
+        }
+        mapped
+    }
+
+    /// Maps a whole set of intervals, merging the results.
+    #[must_use]
+    pub fn apply_intervals(&self, intervals: &[Interval]) -> Vec<Interval> {
+        merge(
+            intervals
+                .iter()
+                .flat_map(|&interval| self.apply_interval(interval))
+                .collect(),
+        )
+    }
+
+    /// The image of every value in `set`.
+    #[must_use]
+    pub fn apply_set(&self, set: &RangeSet) -> RangeSet {
+        set.intervals
+            .iter()
+            .flat_map(|&interval| self.apply_interval(interval))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersection() {
+        let a = Interval::new(0, 10);
+        assert_eq!(
+            a.intersection(&Interval::new(5, 15)),
+            Some(Interval::new(5, 10))
+        );
+        assert_eq!(a.intersection(&Interval::new(10, 15)), None);
+        assert_eq!(
+            a.intersection(&Interval::new(2, 3)),
+            Some(Interval::new(2, 3))
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let merged = merge(vec![
+            Interval::new(8, 12),
+            Interval::new(0, 3),
+            Interval::new(3, 5),
+            Interval::new(20, 20),
+            Interval::new(10, 11),
+        ]);
+        assert_eq!(merged, vec![Interval::new(0, 5), Interval::new(8, 12)]);
+    }
+
+    #[test]
+    fn test_apply() {
+        let map = PiecewiseMap::new([
+            Shift {
+                source: Interval::from_len(98, 2),
+                offset: -48,
+            },
+            Shift {
+                source: Interval::from_len(50, 48),
+                offset: 2,
+            },
+        ]);
+        assert_eq!(map.apply(79), 81);
+        assert_eq!(map.apply(99), 51);
+        assert_eq!(map.apply(13), 13);
+    }
+
+    #[test]
+    fn test_apply_interval_splits() {
+        let map = PiecewiseMap::new([Shift {
+            source: Interval::new(10, 20),
+            offset: 100,
+        }]);
+        assert_eq!(
+            map.apply_interval(Interval::new(5, 25)),
+            vec![
+                Interval::new(5, 10),
+                Interval::new(110, 120),
+                Interval::new(20, 25)
+            ]
+        );
+        assert_eq!(
+            map.apply_interval(Interval::new(12, 15)),
+            vec![Interval::new(112, 115)]
+        );
+        assert_eq!(
+            map.apply_interval(Interval::new(0, 5)),
+            vec![Interval::new(0, 5)]
+        );
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_range_set() {
+        let mut a = set(&[(10, 20), (0, 5), (4, 8)]);
+        assert_eq!(a, set(&[(0, 8), (10, 20)]));
+        assert_eq!(a.count(), 18);
+        assert_eq!((a.min(), a.max()), (Some(0), Some(19)));
+        assert!(a.contains(7) && a.contains(10) && !a.contains(8) && !a.contains(20));
+        a.insert(Interval::new(8, 10));
+        assert_eq!(a.intervals(), &[Interval::new(0, 20)]);
+        assert!(RangeSet::new().is_empty());
+    }
+
+    #[test]
+    fn test_range_set_operations() {
+        let a = set(&[(0, 10), (20, 30), (40, 50)]);
+        let b = set(&[(5, 25), (28, 45), (60, 70)]);
+        assert_eq!(a.union(&b), set(&[(0, 50), (60, 70)]));
+        assert_eq!(
+            a.intersection(&b),
+            set(&[(5, 10), (20, 25), (28, 30), (40, 45)])
+        );
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28), (45, 50)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40), (60, 70)]));
+        assert_eq!(a.difference(&a), RangeSet::new());
+        assert_eq!(a.difference(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let interval = Interval::new(3, 9);
+        assert_eq!(
+            interval.split_at(5),
+            (Interval::new(3, 5), Interval::new(5, 9))
+        );
+        assert!(interval.split_at(0).0.is_empty());
+        assert!(interval.split_at(12).1.is_empty());
+    }
+
+    #[test]
+    fn test_apply_set() {
+        let map = PiecewiseMap::new([Shift {
+            source: Interval::new(10, 20),
+            offset: -10,
+        }]);
+        let mapped = map.apply_set(&set(&[(5, 15), (18, 25)]));
+        assert_eq!(mapped, set(&[(0, 10), (20, 25)]));
+    }
+}
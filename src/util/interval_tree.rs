@@ -0,0 +1,201 @@
+use super::interval::Interval;
+
+/// A fixed collection of intervals, each with a value, that can quickly find all
+/// those holding a point or overlapping another interval, however many there are.
+///
+/// The intervals are kept sorted by start and read as a balanced binary tree, each
+/// slice's middle entry being the root of the slice. Each root also records the
+/// furthest end in its slice, so whole slices ending before the query are skipped.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IntervalTree<V> {
+    entries: Vec<(Interval, V)>,
+    /// The furthest end of any interval in the slice rooted at each entry.
+    max_ends: Vec<i64>,
+}
+
+impl<V> IntervalTree<V> {
+    /// Builds the tree, dropping any empty intervals.
+    #[must_use]
+    pub fn new(entries: impl IntoIterator<Item = (Interval, V)>) -> Self {
+        let mut entries: Vec<(Interval, V)> = entries
+            .into_iter()
+            .filter(|(interval, _)| !interval.is_empty())
+            .collect();
+        entries.sort_unstable_by_key(|&(interval, _)| interval);
+        let mut tree = Self {
+            max_ends: vec![i64::MIN; entries.len()],
+            entries,
+        };
+        tree.build(0, tree.entries.len());
+        tree
+    }
+
+    fn build(&mut self, low: usize, high: usize) -> i64 {
+        if low >= high {
+            return i64::MIN;
+        }
+        let mid = low + (high - low) / 2;
+        let max_end = self.entries[mid]
+            .0
+            .end
+            .max(self.build(low, mid))
+            .max(self.build(mid + 1, high));
+        self.max_ends[mid] = max_end;
+        max_end
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every interval sharing at least one value with `query`, in order of start.
+    #[must_use]
+    pub fn overlapping(&self, query: Interval) -> Vec<&(Interval, V)> {
+        let mut found = Vec::new();
+        if !query.is_empty() {
+            self.visit(0, self.entries.len(), query, &mut found);
+        }
+        found
+    }
+
+    fn visit<'a>(
+        &'a self,
+        low: usize,
+        high: usize,
+        query: Interval,
+        found: &mut Vec<&'a (Interval, V)>,
+    ) {
+        if low >= high {
+            return;
+        }
+        let mid = low + (high - low) / 2;
+        if self.max_ends[mid] <= query.start {
+            return;
+        }
+        self.visit(low, mid, query, found);
+        //Everything from the middle on starts too late once the middle does.
+        if self.entries[mid].0.start < query.end {
+            if self.entries[mid].0.end > query.start {
+                found.push(&self.entries[mid]);
+            }
+            self.visit(mid + 1, high, query, found);
+        }
+    }
+
+    /// Every interval holding `value`, in order of start.
+    #[must_use]
+    pub fn stab(&self, value: i64) -> Vec<&(Interval, V)> {
+        self.overlapping(Interval::new(value, value + 1))
+    }
+
+    /// Whether any interval holds `value`.
+    #[must_use]
+    pub fn covers(&self, value: i64) -> bool {
+        !self.stab(value).is_empty()
+    }
+}
+
+impl<V> FromIterator<(Interval, V)> for IntervalTree<V> {
+    fn from_iter<I: IntoIterator<Item = (Interval, V)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names<'a>(found: &[&(Interval, &'a str)]) -> Vec<&'a str> {
+        found.iter().map(|&&(_, name)| name).collect()
+    }
+
+    #[test]
+    fn test_queries() {
+        let tree: IntervalTree<&str> = [
+            (Interval::new(0, 10), "a"),
+            (Interval::new(5, 6), "b"),
+            (Interval::new(8, 20), "c"),
+            (Interval::new(15, 15), "empty"),
+            (Interval::new(-5, 1), "d"),
+            (Interval::new(12, 30), "e"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(tree.len(), 5);
+        assert_eq!(names(&tree.stab(0)), vec!["d", "a"]);
+        assert_eq!(names(&tree.stab(9)), vec!["a", "c"]);
+        assert_eq!(names(&tree.stab(10)), vec!["c"]);
+        assert_eq!(names(&tree.stab(15)), vec!["c", "e"]);
+        assert!(!tree.covers(30));
+        assert!(tree.covers(-5));
+        assert_eq!(
+            names(&tree.overlapping(Interval::new(5, 9))),
+            vec!["a", "b", "c"]
+        );
+        assert!(tree.overlapping(Interval::new(6, 6)).is_empty());
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let intervals: Vec<(Interval, usize)> = (0..50)
+            .map(|i: i64| {
+                (
+                    Interval::from_len((i * 37) % 101, (i * 13) % 17),
+                    i as usize,
+                )
+            })
+            .collect();
+        let tree = IntervalTree::new(intervals.clone());
+        for value in -1..120 {
+            let mut expected: Vec<usize> = intervals
+                .iter()
+                .filter(|(interval, _)| interval.contains(value))
+                .map(|&(_, i)| i)
+                .collect();
+            let mut actual: Vec<usize> = tree.stab(value).iter().map(|&&(_, i)| i).collect();
+            expected.sort_unstable();
+            actual.sort_unstable();
+            assert_eq!(actual, expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_sensor_coverage() {
+        //2022 day 15: the positions on row 10 that can't hold a beacon.
+        let sensors = [
+            ((2, 18), (-2, 15)),
+            ((9, 16), (10, 16)),
+            ((13, 2), (15, 3)),
+            ((12, 14), (10, 16)),
+            ((10, 20), (10, 16)),
+            ((14, 17), (10, 16)),
+            ((8, 7), (2, 10)),
+            ((2, 0), (2, 10)),
+            ((0, 11), (2, 10)),
+            ((20, 14), (25, 17)),
+            ((17, 20), (21, 22)),
+            ((16, 7), (15, 3)),
+            ((14, 3), (15, 3)),
+            ((20, 1), (15, 3)),
+        ];
+        let row = 10;
+        let tree: IntervalTree<()> = sensors
+            .iter()
+            .map(|&((x, y), (bx, by)): &((i64, i64), (i64, i64))| {
+                let reach = (x - bx).abs() + (y - by).abs() - (y - row).abs();
+                (Interval::new(x - reach, x + reach + 1), ())
+            })
+            .collect();
+        let beacon_free = (-10..40)
+            .filter(|&x| tree.covers(x))
+            .filter(|&x| !sensors.iter().any(|&(_, beacon)| beacon == (x, row)))
+            .count();
+        assert_eq!(beacon_free, 26);
+    }
+}
@@ -0,0 +1,169 @@
+use std::ops::Sub;
+
+/// Extra adapters for any iterator, in the spirit of slices' `windows` and `chunks`
+/// but yielding arrays and working without collecting first.
+pub trait IterExt: Iterator + Sized {
+    /// Every run of `N` consecutive items, overlapping, so `[1, 2, 3]` gives
+    /// `[1, 2]` then `[2, 3]`. Yields nothing if there are fewer than `N` items.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is zero.
+    fn windows_of<const N: usize>(self) -> Windows<Self, N>
+    where
+        Self::Item: Clone,
+    {
+        assert!(N > 0, "windows must hold at least one item");
+        Windows {
+            iter: self,
+            window: None,
+        }
+    }
+
+    /// The items `N` at a time, without overlapping. Any items left over at the end
+    /// are kept back in [`ChunksOf::remainder`] rather than yielded short.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is zero.
+    fn chunks_of<const N: usize>(self) -> ChunksOf<Self, N> {
+        assert!(N > 0, "chunks must hold at least one item");
+        ChunksOf {
+            iter: self,
+            remainder: Vec::new(),
+        }
+    }
+
+    /// The difference between each item and the one before it, so `[1, 4, 9]` gives
+    /// `[3, 5]`.
+    fn differences(self) -> Differences<Self>
+    where
+        Self::Item: Clone + Sub,
+    {
+        Differences {
+            iter: self,
+            previous: None,
+        }
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+/// The iterator returned by [`IterExt::windows_of`].
+#[derive(Debug, Clone)]
+pub struct Windows<I: Iterator, const N: usize> {
+    iter: I,
+    window: Option<[I::Item; N]>,
+}
+
+impl<I: Iterator, const N: usize> Iterator for Windows<I, N>
+where
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(window) = &mut self.window {
+            let item = self.iter.next()?;
+            window.rotate_left(1);
+            window[N - 1] = item;
+            return Some(window.clone());
+        }
+        let first: Vec<I::Item> = self.iter.by_ref().take(N).collect();
+        let window: [I::Item; N] = first.try_into().ok()?;
+        self.window = Some(window.clone());
+        Some(window)
+    }
+}
+
+/// The iterator returned by [`IterExt::chunks_of`].
+#[derive(Debug, Clone)]
+pub struct ChunksOf<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: Vec<I::Item>,
+}
+
+impl<I: Iterator, const N: usize> ChunksOf<I, N> {
+    /// The items that didn't fill a whole chunk at the end. Empty until the
+    /// iterator is exhausted, and when the items divided evenly.
+    #[must_use]
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.remainder
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ChunksOf<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(N).collect();
+        match chunk.try_into() {
+            Ok(chunk) => Some(chunk),
+            Err(short) => {
+                //Don't let a later call on the finished iterator wipe out the tail.
+                if !short.is_empty() {
+                    self.remainder = short;
+                }
+                None
+            }
+        }
+    }
+}
+
+/// The iterator returned by [`IterExt::differences`].
+#[derive(Debug, Clone)]
+pub struct Differences<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Differences<I>
+where
+    I::Item: Clone + Sub,
+{
+    type Item = <I::Item as Sub>::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.previous.is_none() {
+            self.previous = Some(self.iter.next()?);
+        }
+        let next = self.iter.next()?;
+        let previous = self.previous.replace(next.clone())?;
+        Some(next - previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_of() {
+        let windows: Vec<[u32; 3]> = (1..=5).windows_of::<3>().collect();
+        assert_eq!(windows, vec![[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+        assert_eq!((1..=2).windows_of::<3>().count(), 0);
+        let words: Vec<[&str; 2]> = "a b c".split(' ').windows_of().collect();
+        assert_eq!(words, vec![["a", "b"], ["b", "c"]]);
+    }
+
+    #[test]
+    fn test_chunks_of() {
+        let mut chunks = "abcdefgh".chars().chunks_of::<3>();
+        assert_eq!(chunks.next(), Some(['a', 'b', 'c']));
+        assert_eq!(chunks.next(), Some(['d', 'e', 'f']));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), ['g', 'h']);
+
+        let mut even = (0..4).chunks_of::<2>();
+        assert_eq!(even.by_ref().count(), 2);
+        assert!(even.remainder().is_empty());
+    }
+
+    #[test]
+    fn test_differences() {
+        let differences: Vec<i64> = [1_i64, 4, 9, 16, 10].into_iter().differences().collect();
+        assert_eq!(differences, vec![3, 5, 7, -6]);
+        assert_eq!([1].into_iter().differences().count(), 0);
+    }
+}
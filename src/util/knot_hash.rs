@@ -0,0 +1,85 @@
+//! The Knot Hash from 2017, which ties a circular list in knots and reads the result
+//! back as a 128-bit hash.
+
+use std::fmt::Write;
+
+/// The suffix added to every input before hashing.
+const SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
+
+/// The list `0..size` after `rounds` rounds of knotting by `lengths`. The position and
+/// skip size carry on from one round to the next.
+///
+/// # Panics
+///
+/// If any length is longer than the list.
+#[must_use]
+pub fn sparse(size: usize, lengths: &[usize], rounds: usize) -> Vec<usize> {
+    let mut list: Vec<usize> = (0..size).collect();
+    let mut position = 0;
+    for (skip, &length) in (0..).zip(lengths.iter().cycle().take(lengths.len() * rounds)) {
+        assert!(length <= size, "can't reverse {length} of {size} items");
+        //Turn the list so the section starts at the front, and the reverse never wraps.
+        list.rotate_left(position);
+        list[..length].reverse();
+        list.rotate_right(position);
+        position = (position + length + skip) % size;
+    }
+    list
+}
+
+/// The 16-byte Knot Hash of `input`.
+#[must_use]
+pub fn hash(input: &str) -> [u8; 16] {
+    let lengths: Vec<usize> = input.bytes().map(usize::from).chain(SUFFIX).collect();
+    let sparse = sparse(256, &lengths, 64);
+    let mut dense = [0; 16];
+    for (byte, block) in dense.iter_mut().zip(sparse.chunks(16)) {
+        //Every value is below 256, so the XOR of a block is too.
+        *byte = block.iter().fold(0, |acc, &value| acc ^ value) as u8;
+    }
+    dense
+}
+
+/// The Knot Hash of `input` as 32 lowercase hex digits.
+#[must_use]
+pub fn hex(input: &str) -> String {
+    hash(input).iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+/// The Knot Hash of `input` as 128 bits, the first byte's high bit at the top.
+#[must_use]
+pub fn bits(input: &str) -> u128 {
+    u128::from_be_bytes(hash(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse() {
+        assert_eq!(sparse(5, &[3, 4, 1, 5], 1), vec![3, 4, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(hex(""), "a2582a3a0e66e6e86e3812dcb672a272");
+        assert_eq!(hex("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
+        assert_eq!(hex("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d");
+        assert_eq!(hex("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
+    }
+
+    #[test]
+    fn test_bits() {
+        //The disk grid from 2017 day 14, where each row is the hash of the key and
+        //  the row number.
+        assert_eq!(bits("flqrgnkx-0") >> 120, 0b1101_0100);
+        let used: u32 = (0..128)
+            .map(|row| bits(&format!("flqrgnkx-{row}")).count_ones())
+            .sum();
+        assert_eq!(used, 8108);
+    }
+}
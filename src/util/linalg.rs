@@ -0,0 +1,85 @@
+use super::rational::Ratio;
+
+/// Solves a square system of linear equations exactly, given as rows of
+/// coefficients each followed by the right hand side.
+///
+/// Returns `None` unless there is exactly one solution, or if the working would
+/// overflow.
+///
+/// # Panics
+///
+/// If a row isn't one longer than the number of rows.
+#[must_use]
+pub fn solve(mut rows: Vec<Vec<Ratio>>) -> Option<Vec<Ratio>> {
+    let n = rows.len();
+    assert!(
+        rows.iter().all(|row| row.len() == n + 1),
+        "{n} equations need {} columns",
+        n + 1
+    );
+    //Gauss-Jordan elimination: clear each pivot's column from every other row, so
+    //  the answers are left down the right hand side.
+    for pivot in 0..n {
+        let swap = (pivot..n).find(|&row| !rows[row][pivot].is_zero())?;
+        rows.swap(pivot, swap);
+        let scale = rows[pivot][pivot];
+        for value in &mut rows[pivot] {
+            *value = value.checked_div(scale)?;
+        }
+        let pivot_row = rows[pivot].clone();
+        for (_, row) in rows.iter_mut().enumerate().filter(|&(row, _)| row != pivot) {
+            let factor = row[pivot];
+            if factor.is_zero() {
+                continue;
+            }
+            for (value, &above) in row[pivot..].iter_mut().zip(&pivot_row[pivot..]) {
+                *value = value.checked_sub(factor.checked_mul(above)?)?;
+            }
+        }
+    }
+    Some(rows.into_iter().map(|row| row[n]).collect())
+}
+
+/// Like [`solve`], for a system with integer coefficients.
+#[must_use]
+pub fn solve_integers(rows: &[Vec<i128>]) -> Option<Vec<Ratio>> {
+    solve(
+        rows.iter()
+            .map(|row| row.iter().copied().map(Ratio::integer).collect())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        //x + 2y = 5, 3x - y = 1
+        assert_eq!(
+            solve_integers(&[vec![1, 2, 5], vec![3, -1, 1]]),
+            Some(vec![Ratio::ONE, Ratio::integer(2)])
+        );
+        assert_eq!(solve_integers(&[vec![1, 2, 5], vec![2, 4, 1]]), None);
+        //Needs a row swap to find a pivot.
+        assert_eq!(
+            solve_integers(&[vec![0, 2, 1, 7], vec![1, 0, 1, 4], vec![2, 1, 0, 4]]),
+            Some(vec![Ratio::ONE, Ratio::integer(2), Ratio::integer(3)])
+        );
+        assert_eq!(
+            solve_integers(&[vec![2, 0, 1], vec![0, 3, 1]]),
+            Some(vec![Ratio::new(1, 2), Ratio::new(1, 3)])
+        );
+    }
+
+    #[test]
+    fn test_claw_machine() {
+        //Presses of buttons A and B that reach the prize, from 2024 day 13.
+        let presses = solve_integers(&[vec![94, 22, 8400], vec![34, 67, 5400]]).unwrap();
+        assert_eq!(presses, vec![Ratio::integer(80), Ratio::integer(40)]);
+        //This machine's prize can only be reached part way through a press.
+        let presses = solve_integers(&[vec![26, 67, 12748], vec![66, 21, 12176]]).unwrap();
+        assert!(presses.iter().any(|presses| presses.to_integer().is_none()));
+    }
+}
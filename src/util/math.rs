@@ -0,0 +1,184 @@
+#[must_use]
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[must_use]
+pub const fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// The least common multiple, or `None` if it doesn't fit in a `u64`.
+#[must_use]
+pub const fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The greatest common divisor of all of `values`, or 0 if there are none.
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// The least common multiple of all of `values`, or 1 if there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(1, lcm)
+}
+
+/// The greatest common divisor `g` of `a` and `b` along with `x` and `y` such that
+/// `a * x + b * y == g`, as `(g, x, y)`.
+#[must_use]
+pub const fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` with the Chinese
+/// remainder theorem, returning the combined `(residue, modulus)` with the residue
+/// in `0..modulus`.
+///
+/// The moduli must be positive but needn't be coprime. Returns `None` if the
+/// congruences contradict each other.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            let (g, p, _) = extended_gcd(m1, m2);
+            let difference = r2 - r1;
+            if difference % g != 0 {
+                return None;
+            }
+            //Find k with r1 + m1 * k ≡ r2 (mod m2), working wide to avoid overflow.
+            let step = i128::from(m2 / g);
+            let k = (i128::from(difference / g) * i128::from(p)).rem_euclid(step);
+            let modulus = i128::from(m1) * step;
+            let residue = (i128::from(r1) + i128::from(m1) * k).rem_euclid(modulus);
+            Some((i64::try_from(residue).ok()?, i64::try_from(modulus).ok()?))
+        })
+}
+
+/// `base` to the power `exponent`, modulo `modulus`, by repeated squaring.
+#[must_use]
+pub const fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and `modulus` are
+/// coprime.
+#[must_use]
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    let (a, m) = (i128::from(a), i128::from(modulus));
+    //Run Euclid wide so moduli up to `u64::MAX` fit.
+    let (mut old_r, mut r) = (a.rem_euclid(m), m);
+    let (mut old_x, mut x) = (1_i128, 0_i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+    }
+    (old_r == 1).then(|| old_x.rem_euclid(m) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(7, 0), 7);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(21, 6), 42);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(checked_lcm(21, 6), Some(42));
+        assert_eq!(checked_lcm(1 << 63, 3), None);
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2, 3, 4]), 12);
+        assert_eq!(lcm_all([]), 1);
+        assert_eq!(gcd_all([]), 0);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (17, 5), (-12, 18), (0, 7), (7, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        //The buses from 2020 day 13's example: bus `id` leaves `offset` minutes later.
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let congruences = buses.map(|(id, offset): (i64, i64)| (-offset, id));
+        assert_eq!(crt(congruences), Some((1_068_781, 3_162_341)));
+        //Moduli with common factors still combine if they agree.
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(7, 0, 13), 1);
+        assert_eq!(mod_pow(7, 5, 1), 0);
+        //Fermat's little theorem, with a modulus too big to square in 64 bits.
+        let prime = 119_315_717_514_047;
+        assert_eq!(mod_pow(2020, prime - 1, prime), 1);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        let prime = 119_315_717_514_047;
+        let inverse = mod_inverse(2020, prime).unwrap();
+        assert_eq!(u128::from(inverse) * 2020 % u128::from(prime), 1);
+    }
+}
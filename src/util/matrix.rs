@@ -0,0 +1,224 @@
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use anyhow::Result;
+
+use super::modint::ModInt;
+
+/// Numbers that can fill a [`Matrix`]. Each value can give the zero and one of its
+/// own kind, so [`ModInt`] keeps its modulus.
+pub trait Element: Copy + Add<Output = Self> + Mul<Output = Self> {
+    #[must_use]
+    fn zero_like(self) -> Self;
+
+    #[must_use]
+    fn one_like(self) -> Self;
+}
+
+macro_rules! impl_element {
+    ($($int:ty),*) => {
+        $(impl Element for $int {
+            fn zero_like(self) -> Self {
+                0
+            }
+
+            fn one_like(self) -> Self {
+                1
+            }
+        })*
+    };
+}
+
+impl_element!(u32, u64, u128, usize, i32, i64, i128);
+
+impl Element for ModInt {
+    fn zero_like(self) -> Self {
+        self.with(0)
+    }
+
+    fn one_like(self) -> Self {
+        self.with(1)
+    }
+}
+
+/// A square matrix, for stepping a linear recurrence: multiplying the state by the
+/// matrix moves it on one step, so its `n`th power moves it on `n` steps at once.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Matrix<T> {
+    cells: Vec<T>,
+    size: usize,
+}
+
+impl<T: Element> Matrix<T> {
+    /// A `size` by `size` matrix with every cell set to `fill`.
+    #[must_use]
+    pub fn new(size: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; size * size],
+            size,
+        }
+    }
+
+    /// The matrix that changes nothing, with zeros and ones like `like`.
+    #[must_use]
+    pub fn identity(size: usize, like: T) -> Self {
+        let mut identity = Self::new(size, like.zero_like());
+        for i in 0..size {
+            identity[(i, i)] = like.one_like();
+        }
+        identity
+    }
+
+    /// Builds a matrix from its rows, which must all be as long as there are rows.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let size = rows.len();
+        if let Some(row) = rows.iter().find(|row| row.len() != size) {
+            return Err(anyhow::anyhow!(
+                "a row of {} in a matrix of {size} rows isn't square",
+                row.len()
+            ));
+        }
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            size,
+        })
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.size.max(1))
+    }
+
+    /// This matrix times the column `vector`.
+    ///
+    /// # Panics
+    ///
+    /// If `vector` isn't as long as the matrix is wide.
+    #[must_use]
+    pub fn apply(&self, vector: &[T]) -> Vec<T> {
+        assert_eq!(vector.len(), self.size, "vector doesn't fit the matrix");
+        self.rows()
+            .map(|row| dot(row, vector.iter().copied()))
+            .collect()
+    }
+
+    /// This matrix multiplied by itself `exponent` times, by repeated squaring.
+    #[must_use]
+    pub fn pow(&self, mut exponent: u64) -> Self {
+        let Some(&like) = self.cells.first() else {
+            return self.clone();
+        };
+        let mut result = Self::identity(self.size, like);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+}
+
+/// The sum of the products of matching values, which must not both be empty.
+fn dot<T: Element>(row: &[T], column: impl Iterator<Item = T>) -> T {
+    let mut products = row.iter().zip(column).map(|(&a, b)| a * b);
+    let first = products.next().expect("can't multiply empty matrices");
+    products.fold(first, |sum, product| sum + product)
+}
+
+impl<T: Element> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: Self) -> Matrix<T> {
+        assert_eq!(self.size, other.size, "matrices are different sizes");
+        let size = self.size;
+        let cells = (0..size * size)
+            .map(|i| {
+                let (row, col) = (i / size, i % size);
+                dot(
+                    &self.cells[row * size..(row + 1) * size],
+                    other.cells[col..].iter().step_by(size).copied(),
+                )
+            })
+            .collect();
+        Matrix { cells, size }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// The cell at `(row, col)`.
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.size && col < self.size,
+            "({row}, {col}) is off the matrix"
+        );
+        &self.cells[row * self.size + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.size && col < self.size,
+            "({row}, {col}) is off the matrix"
+        );
+        &mut self.cells[row * self.size + col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fibonacci() {
+        let step = Matrix::from_rows(vec![vec![1_u64, 1], vec![1, 0]]).unwrap();
+        assert_eq!(step.pow(0), Matrix::identity(2, 0));
+        assert_eq!(step.pow(10)[(0, 1)], 55);
+        assert_eq!(step.pow(90)[(0, 1)], 2_880_067_194_370_816_120);
+        assert!(Matrix::from_rows(vec![vec![1_u64, 1], vec![1]]).is_err());
+    }
+
+    #[test]
+    fn test_modular() {
+        let modulus = 1_000_000_007;
+        let m = |value| ModInt::new(value, modulus);
+        let step = Matrix::from_rows(vec![vec![m(1), m(1)], vec![m(1), m(0)]]).unwrap();
+        let mut fibonacci = (m(0), m(1));
+        for _ in 0..100_000 {
+            fibonacci = (fibonacci.1, fibonacci.0 + fibonacci.1);
+        }
+        assert_eq!(step.pow(100_000)[(0, 1)], fibonacci.0);
+        //Squaring a huge power is the same as doubling the exponent.
+        let power = step.pow(1 << 59);
+        assert_eq!(&power * &power, step.pow(1 << 60));
+    }
+
+    #[test]
+    fn test_lanternfish() {
+        //2021 day 6: each day, fish with a timer of 0 reset to 6 and spawn one at 8.
+        let mut step = Matrix::new(9, 0_u64);
+        for timer in 0..8 {
+            step[(timer, timer + 1)] = 1;
+        }
+        step[(6, 0)] = 1;
+        step[(8, 0)] = 1;
+        let mut fish = vec![0; 9];
+        for timer in [3, 4, 3, 1, 2] {
+            fish[timer] += 1;
+        }
+        let count = |days| step.pow(days).apply(&fish).iter().sum::<u64>();
+        assert_eq!(count(18), 26);
+        assert_eq!(count(80), 5934);
+        assert_eq!(count(256), 26_984_457_539);
+    }
+}
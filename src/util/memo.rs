@@ -0,0 +1,110 @@
+use std::hash::Hash;
+
+use super::hash::FxHashMap;
+
+/// A cache of the answers a recursive function has already worked out.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: FxHashMap<K, V>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: FxHashMap::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// The answer for `key`, worked out with `compute` the first time it is asked
+    /// for. `compute` is handed the memo so it can recurse through it.
+    pub fn get(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(known) = self.cache.get(&key) {
+            return known.clone();
+        }
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    /// Runs the recursive function `f` on `key`, caching every answer along the way.
+    ///
+    /// `f` recurses by calling the function it is handed rather than itself, which
+    /// lets a closure be memoized without naming its own type.
+    pub fn call<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(known) = self.cache.get(&key) {
+            return known.clone();
+        }
+        let value = f(&mut |next| self.call(next, f), key.clone());
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+/// Runs the recursive function `f` on `key` with a fresh [`Memo`].
+pub fn memoize<K, V, F>(key: K, f: F) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    Memo::new().call(key, &f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memoize() {
+        let fibonacci = |fib: &mut dyn FnMut(u64) -> u64, n: u64| {
+            if n < 2 {
+                n
+            } else {
+                fib(n - 1) + fib(n - 2)
+            }
+        };
+        assert_eq!(memoize(90, fibonacci), 2_880_067_194_370_816_120);
+    }
+
+    #[test]
+    fn test_get() {
+        //Ways to climb `n` stairs a step or two at a time, counting the calls made.
+        fn climb(memo: &mut Memo<u32, u64>, calls: &mut u32, n: u32) -> u64 {
+            memo.get(n, |memo| {
+                *calls += 1;
+                match n {
+                    0 | 1 => 1,
+                    _ => climb(memo, calls, n - 1) + climb(memo, calls, n - 2),
+                }
+            })
+        }
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        assert_eq!(climb(&mut memo, &mut calls, 40), 165_580_141);
+        assert_eq!((calls, memo.len()), (41, 41));
+        assert_eq!(climb(&mut memo, &mut calls, 30), 1_346_269);
+        assert_eq!(calls, 41);
+    }
+}
@@ -0,0 +1,40 @@
+pub mod aabb;
+pub mod automaton;
+pub mod bit_grid;
+pub mod combinatorics;
+pub mod compress;
+pub mod counter;
+pub mod cycle;
+pub mod direction;
+pub mod disjoint_set;
+pub mod fill;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod hash;
+pub mod hex;
+pub mod interval;
+pub mod interval_tree;
+pub mod iter;
+pub mod knot_hash;
+pub mod linalg;
+pub mod math;
+pub mod matrix;
+pub mod memo;
+pub mod modint;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod point3;
+pub mod polynomial;
+pub mod prefix_sum;
+pub mod priority_queue;
+pub mod rational;
+pub mod rotation;
+pub mod search;
+pub mod sparse_grid;
+pub mod strings;
+pub mod trie;
+pub mod wide;
+#[cfg(feature = "winnow")]
+pub mod winnow_parse;
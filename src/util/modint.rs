@@ -0,0 +1,192 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::math::{mod_inverse, mod_pow};
+
+/// An integer modulo some `modulus`, chosen at runtime, that wraps on every
+/// operation.
+///
+/// Values with different moduli can't be combined: doing so panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModInt {
+    /// `value` reduced modulo `modulus`, which must not be zero.
+    #[must_use]
+    pub const fn new(value: i64, modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be positive");
+        let value = (value as i128).rem_euclid(modulus as i128) as u64;
+        Self { value, modulus }
+    }
+
+    /// The representative in `0..modulus`.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+
+    #[must_use]
+    pub const fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    #[must_use]
+    pub const fn pow(self, exponent: u64) -> Self {
+        Self {
+            value: mod_pow(self.value, exponent, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+
+    /// The value that multiplies with this one to give 1, if there is one.
+    #[must_use]
+    pub fn inverse(self) -> Option<Self> {
+        Some(Self {
+            value: mod_inverse(self.value, self.modulus)?,
+            modulus: self.modulus,
+        })
+    }
+
+    /// The same modulus with a different value, for mixing in plain integers.
+    #[must_use]
+    pub const fn with(self, value: i64) -> Self {
+        Self::new(value, self.modulus)
+    }
+
+    fn check(self, other: Self) -> u128 {
+        assert_eq!(self.modulus, other.modulus, "mismatched moduli");
+        u128::from(self.modulus)
+    }
+}
+
+impl fmt::Display for ModInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Add for ModInt {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let modulus = self.check(other);
+        let value = (u128::from(self.value) + u128::from(other.value)) % modulus;
+        Self {
+            value: value as u64,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Sub for ModInt {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for ModInt {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let modulus = self.check(other);
+        let value = u128::from(self.value) * u128::from(other.value) % modulus;
+        Self {
+            value: value as u64,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Div for ModInt {
+    type Output = Self;
+
+    /// Multiplies by the inverse of `other`, panicking if it has none.
+    fn div(self, other: Self) -> Self {
+        Mul::mul(self, other.inverse().expect("divisor has no inverse"))
+    }
+}
+
+impl Neg for ModInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            value: (self.modulus - self.value) % self.modulus,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl AddAssign for ModInt {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for ModInt {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for ModInt {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let seven = ModInt::new(7, 10);
+        assert_eq!(ModInt::new(-3, 10), seven);
+        assert_eq!((seven + seven).value(), 4);
+        assert_eq!((seven - seven.with(9)).value(), 8);
+        assert_eq!((seven * seven).value(), 9);
+        assert_eq!((-seven).value(), 3);
+        assert_eq!(seven.pow(4).value(), 1);
+        assert_eq!(seven.inverse(), Some(seven.with(3)));
+        assert_eq!(seven.with(4).inverse(), None);
+        assert_eq!((seven.with(9) / seven).value(), 7);
+        assert_eq!(seven.to_string(), "7");
+    }
+
+    #[test]
+    fn test_shuffle() {
+        //Shuffles as linear maps `position -> a * position + b` on a deck of 10,
+        //from 2019 day 22: deal with increment 7, then cut -2.
+        let deck = 10;
+        let (a, b) = (ModInt::new(7, deck), ModInt::new(2, deck));
+        let position = |card: i64| a * a.with(card) + b;
+        let order: Vec<u64> = {
+            let mut order = vec![0; 10];
+            for card in 0..10 {
+                order[position(card).value() as usize] = card as u64;
+            }
+            order
+        };
+        assert_eq!(order, vec![4, 7, 0, 3, 6, 9, 2, 5, 8, 1]);
+        //Undoing it finds which card ends up where.
+        let card_at = |position: u64| (a.with(position as i64) - b) / a;
+        assert!((0..10).all(|p| card_at(p).value() == order[p as usize]));
+        //Shuffling twice composes the maps: a * (a * x + b) + b.
+        let twice = |card: i64| a.pow(2) * a.with(card) + a * b + b;
+        assert!((0..10).all(|card| twice(card) == position(position(card).value() as i64)));
+    }
+
+    #[test]
+    #[should_panic(expected = "mismatched moduli")]
+    fn test_mismatched_moduli() {
+        let _ = ModInt::new(1, 10) + ModInt::new(1, 11);
+    }
+}
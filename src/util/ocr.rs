@@ -0,0 +1,214 @@
+use anyhow::Result;
+
+use super::{grid::Grid, point::Point};
+
+/// A dot-matrix font: how big its letters are, how far apart they start, and what
+/// each looks like drawn with `#` and `.`, row after row.
+struct Font {
+    width: usize,
+    height: usize,
+    stride: usize,
+    glyphs: &'static [(char, &'static str)],
+}
+
+/// The small capitals most puzzles draw, four pixels wide and six tall.
+const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    stride: 5,
+    glyphs: &[
+        ('A', ".##.#..##..######..##..#"),
+        ('B', "###.#..####.#..##..####."),
+        ('C', ".##.#..##...#...#..#.##."),
+        ('E', "#####...###.#...#...####"),
+        ('F', "#####...###.#...#...#..."),
+        ('G', ".##.#..##...#.###..#.###"),
+        ('H', "#..##..######..##..##..#"),
+        ('I', ".###..#...#...#...#..###"),
+        ('J', "..##...#...#...##..#.##."),
+        ('K', "#..##.#.##..#.#.#.#.#..#"),
+        ('L', "#...#...#...#...#...####"),
+        ('O', ".##.#..##..##..##..#.##."),
+        ('P', "###.#..##..####.#...#..."),
+        ('R', "###.#..##..####.#.#.#..#"),
+        ('S', ".####...#....##....####."),
+        ('U', "#..##..##..##..##..#.##."),
+        ('Y', "#...#....#.#..#...#...#."),
+        ('Z', "####...#..#..#..#...####"),
+    ],
+};
+
+/// The large capitals from 2018 day 10, six pixels wide and ten tall.
+const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    stride: 8,
+    glyphs: &[
+        (
+            'A',
+            "..##...#..#.#....##....##....########....##....##....##....#",
+        ),
+        (
+            'B',
+            "#####.#....##....##....######.#....##....##....##....######.",
+        ),
+        (
+            'C',
+            ".####.#....##.....#.....#.....#.....#.....#.....#....#.####.",
+        ),
+        (
+            'E',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....######",
+        ),
+        (
+            'F',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'G',
+            ".####.#....##.....#.....#.....#..####....##....##...##.###.#",
+        ),
+        (
+            'H',
+            "#....##....##....##....########....##....##....##....##....#",
+        ),
+        (
+            'J',
+            "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###..",
+        ),
+        (
+            'K',
+            "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#",
+        ),
+        (
+            'L',
+            "#.....#.....#.....#.....#.....#.....#.....#.....#.....######",
+        ),
+        (
+            'N',
+            "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#",
+        ),
+        (
+            'P',
+            "#####.#....##....##....######.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'R',
+            "#####.#....##....##....######.#..#..#...#.#...#.#....##....#",
+        ),
+        (
+            'X',
+            "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#",
+        ),
+        (
+            'Z',
+            "######.....#.....#....#....#....#....#....#.....#.....######",
+        ),
+    ],
+};
+
+/// Reads the capital letters drawn by the lit pixels of `screen`, in whichever
+/// font is the right height. Blank space around the letters is ignored.
+pub fn read(screen: &Grid<bool>) -> Result<String> {
+    let lit: Vec<Point> = screen
+        .enumerate()
+        .filter(|&(_, &on)| on)
+        .map(|(point, _)| point)
+        .collect();
+    let (Some(left), Some(right)) = (
+        lit.iter().map(|point| point.x).min(),
+        lit.iter().map(|point| point.x).max(),
+    ) else {
+        return Err(anyhow::anyhow!("nothing is drawn"));
+    };
+    let top = lit.iter().map(|point| point.y).min().unwrap_or_default();
+    let bottom = lit.iter().map(|point| point.y).max().unwrap_or_default();
+    let height = (bottom - top + 1) as usize;
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|font| font.height == height)
+        .ok_or_else(|| anyhow::anyhow!("no font is {height} pixels tall"))?;
+    (left..=right)
+        .step_by(font.stride)
+        .map(|start| {
+            let glyph: String = (top..=bottom)
+                .flat_map(|y| (start..start + font.width as i64).map(move |x| Point::new(x, y)))
+                .map(|point| {
+                    if screen.get(point) == Some(&true) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            font.glyphs
+                .iter()
+                .find(|(_, pixels)| *pixels == glyph)
+                .map(|&(letter, _)| letter)
+                .ok_or_else(|| anyhow::anyhow!("unknown letter at column {start}"))
+        })
+        .collect()
+}
+
+/// Draws `screen` with `#` for lit pixels and `.` for dark ones, a line per row.
+#[must_use]
+pub fn render(screen: &Grid<bool>) -> String {
+    screen
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|&on| if on { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(picture: &str) -> Grid<bool> {
+        Grid::parse(picture, |c| Ok(c == b'#')).unwrap()
+    }
+
+    #[test]
+    fn test_read_small() {
+        //The message from 2016 day 8's example screen, widened to real letters.
+        let picture = "\
+..........................
+.#..#.####.#....#.....##..
+.#..#.#....#....#....#..#.
+.####.###..#....#....#..#.
+.#..#.#....#....#....#..#.
+.#..#.#....#....#....#..#.
+.#..#.####.####.####..##..
+..........................";
+        assert_eq!(read(&screen(picture)).unwrap(), "HELLO");
+        assert_eq!(render(&screen(picture)), picture);
+    }
+
+    #[test]
+    fn test_read_large() {
+        let picture = "\
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..#.....
+######..#.....
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..######";
+        assert_eq!(read(&screen(picture)).unwrap(), "HL");
+        let error = read(&screen(&picture.replace("..#.", "..##"))).unwrap_err();
+        assert_eq!(error.to_string(), "unknown letter at column 8");
+    }
+
+    #[test]
+    fn test_unreadable() {
+        assert!(read(&screen("....\n....")).is_err());
+        assert!(read(&screen("#.#\n.#.")).is_err());
+    }
+}
@@ -0,0 +1,373 @@
+//! nom combinators shared by the puzzle parsers.
+
+use std::{fmt::Display, ops::RangeFrom, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1, space0},
+    combinator::{map, map_opt, map_res, opt, recognize},
+    error::{Error, FromExternalError, ParseError},
+    sequence::{delimited, pair, tuple},
+    AsChar, Compare, IResult, InputIter, InputTake, InputTakeAtPosition, Parser, Slice,
+};
+
+/// A combinator that takes a parser `inner` and produces a parser that also consumes both leading and
+/// trailing whitespace, returning the output of `inner`. Works on bytes as well as text.
+pub fn ws<I, F, O, E>(inner: F) -> impl FnMut(I) -> IResult<I, O, E>
+where
+    I: InputTakeAtPosition,
+    <I as InputTakeAtPosition>::Item: AsChar + Clone,
+    F: Parser<I, O, E>,
+    E: ParseError<I>,
+{
+    delimited(space0, inner, space0)
+}
+
+/// A header like `Card 12:` naming a numbered item, followed by the item itself.
+/// Returns the number and what `inner` parsed.
+pub fn labeled<'a, I, F, O, E>(
+    label: &'a str,
+    inner: F,
+) -> impl FnMut(I) -> IResult<I, (u32, O), E> + use<'a, I, F, O, E>
+where
+    I: Clone
+        + InputTake
+        + InputIter
+        + InputTakeAtPosition
+        + Slice<RangeFrom<usize>>
+        + Compare<&'a str>,
+    <I as InputIter>::Item: AsChar,
+    <I as InputTakeAtPosition>::Item: AsChar + Clone,
+    F: Parser<I, O, E>,
+    E: ParseError<I>,
+{
+    map(
+        tuple((ws(tag(label)), uint, ws(char(':')), inner)),
+        |(_, id, _, value)| (id, value),
+    )
+}
+
+/// A line ending, either Unix or Windows style.
+pub fn newline<I, E>(input: I) -> IResult<I, I, E>
+where
+    I: Clone + InputTake + Compare<&'static str>,
+    E: ParseError<I>,
+{
+    alt((tag("\r\n"), tag("\n")))(input)
+}
+
+/// An unsigned number read straight from its ASCII digits, failing if it doesn't fit.
+/// Unlike [`unsigned`] this never checks the text is valid UTF-8, so it is the
+/// faster choice on `&[u8]` input.
+pub fn uint<I, T, E>(input: I) -> IResult<I, T, E>
+where
+    I: Clone + InputIter + InputTakeAtPosition,
+    <I as InputIter>::Item: AsChar,
+    <I as InputTakeAtPosition>::Item: AsChar,
+    T: TryFrom<u64>,
+    E: ParseError<I>,
+{
+    map_opt(digit1, |digits: I| {
+        let value = digits.iter_elements().try_fold(0_u64, |value, digit| {
+            value
+                .checked_mul(10)?
+                .checked_add(u64::from(digit.as_char().to_digit(10)?))
+        })?;
+        T::try_from(value).ok()
+    })(input)
+}
+
+/// An unsigned number of any type that can be parsed from its digits, failing if it
+/// doesn't fit.
+pub fn unsigned<'a, T, E>(input: &'a str) -> IResult<&'a str, T, E>
+where
+    T: FromStr,
+    E: ParseError<&'a str> + FromExternalError<&'a str, T::Err>,
+{
+    map_res(digit1, str::parse)(input)
+}
+
+/// An optionally negative number of any type that can be parsed from its digits,
+/// failing if it doesn't fit.
+pub fn signed<'a, T, E>(input: &'a str) -> IResult<&'a str, T, E>
+where
+    T: FromStr,
+    E: ParseError<&'a str> + FromExternalError<&'a str, T::Err>,
+{
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+pub fn parse_u32(input: &str) -> IResult<&str, u32> {
+    unsigned(input)
+}
+
+pub fn parse_u64(input: &str) -> IResult<&str, u64> {
+    unsigned(input)
+}
+
+pub fn parse_usize(input: &str) -> IResult<&str, usize> {
+    unsigned(input)
+}
+
+pub fn parse_i32(input: &str) -> IResult<&str, i32> {
+    signed(input)
+}
+
+pub fn parse_i64(input: &str) -> IResult<&str, i64> {
+    signed(input)
+}
+
+pub fn parse_i128(input: &str) -> IResult<&str, i128> {
+    signed(input)
+}
+
+/// Runs `parser` over the whole of `input`, allowing only whitespace to be left over.
+/// A failure names the line and column it happened at and shows that line.
+pub fn complete<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> Result<O> {
+    match parser.parse(input) {
+        Ok((rest, output)) if rest.trim().is_empty() => Ok(output),
+        Ok((rest, _)) => Err(located(input, rest.len(), "unexpected input")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(located(
+            input,
+            e.input.len(),
+            &format!("failed to parse ({})", e.code.description()),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(anyhow!("input ended early")),
+    }
+}
+
+/// [`complete`] for a parser that reads the bytes of `input`.
+pub fn complete_bytes<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a [u8], O, Error<&'a [u8]>>,
+) -> Result<O> {
+    match parser.parse(input.as_bytes()) {
+        Ok((rest, output)) if rest.trim_ascii().is_empty() => Ok(output),
+        Ok((rest, _)) => Err(located(input, rest.len(), "unexpected input")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(located(
+            input,
+            e.input.len(),
+            &format!("failed to parse ({})", e.code.description()),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(anyhow!("input ended early")),
+    }
+}
+
+/// An error for the last `remaining` bytes of `input`, which couldn't be parsed.
+pub(crate) fn located(input: &str, remaining: usize, message: &str) -> anyhow::Error {
+    let mut offset = input.len() - remaining;
+    //A byte parser could stop partway through a character.
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line_number = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    let line = input[line_start..].lines().next().unwrap_or_default();
+    anyhow!(
+        "{message} at line {line_number}, column {column}:\n{line}\n{:>column$}",
+        "^"
+    )
+}
+
+/// The lines a [`lenient`] parse left out.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Skipped {
+    pub count: usize,
+    /// The first few skipped lines and their line numbers, to show what was wrong.
+    pub samples: Vec<(usize, String)>,
+}
+
+impl Skipped {
+    /// How many skipped lines are kept as samples.
+    pub const SAMPLES: usize = 3;
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+impl Display for Skipped {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "skipped {} malformed line(s)", self.count)?;
+        for (number, line) in &self.samples {
+            write!(f, "\n  line {number}: {line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses each line of `input` with `parse`, leaving out the lines it fails on
+/// instead of giving up, for inputs that have been edited by hand. Blank lines are
+/// ignored.
+pub fn lenient<T>(input: &str, mut parse: impl FnMut(&str) -> Result<T>) -> (Vec<T>, Skipped) {
+    let mut parsed = Vec::new();
+    let mut skipped = Skipped::default();
+    for (line, number) in input.lines().zip(1..) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse(line) {
+            Ok(value) => parsed.push(value),
+            Err(_) => {
+                skipped.count += 1;
+                if skipped.samples.len() < Skipped::SAMPLES {
+                    skipped.samples.push((number, line.to_string()));
+                }
+            }
+        }
+    }
+    (parsed, skipped)
+}
+
+/// Every number in `text`, separated by any mix of whitespace and commas.
+pub fn numbers<T>(text: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|e| anyhow!("{token:?} is not a number: {e}"))
+        })
+        .collect()
+}
+
+/// The [`numbers`] on each line of `input`, skipping blank lines.
+pub fn number_lines<T>(input: &str) -> Result<Vec<Vec<T>>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(numbers)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::multi::separated_list1;
+
+    use super::*;
+
+    #[test]
+    fn test_ws() {
+        let mut parser = ws::<_, _, _, Error<&str>>(tag("Game"));
+        assert_eq!(parser("  Game 1"), Ok(("1", "Game")));
+        assert_eq!(parser("Game"), Ok(("", "Game")));
+        //Only spaces and tabs, so lines are kept apart.
+        assert!(parser("\nGame").is_err());
+    }
+
+    #[test]
+    fn test_labeled() {
+        let mut monkey = labeled::<_, _, _, Error<&str>>("Monkey", tag("..."));
+        assert_eq!(monkey("Monkey 3:..."), Ok(("", (3, "..."))));
+        let mut card = labeled("Card", separated_list1(tag(" "), parse_u32));
+        assert_eq!(card("  Card   12:  7 8"), Ok(("", (12, vec![7, 8]))));
+        assert!(card("Game 1: 7").is_err());
+        assert!(card("Card x: 7").is_err());
+    }
+
+    #[test]
+    fn test_newline() {
+        let mut lines = separated_list1(newline::<_, Error<&str>>, parse_u32);
+        assert_eq!(lines("1\n2\r\n3"), Ok(("", vec![1, 2, 3])));
+        assert!(newline::<_, Error<&str>>("\r").is_err());
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(parse_u32("123abc"), Ok(("abc", 123)));
+        assert_eq!(parse_u64("18446744073709551615"), Ok(("", u64::MAX)));
+        assert!(parse_u32("4294967296").is_err());
+        assert!(parse_usize("-1").is_err());
+        assert!(parse_usize("").is_err());
+        assert_eq!(pair(parse_usize, ws(parse_u64))("7  8 "), Ok(("", (7, 8))));
+        assert_eq!(unsigned::<u8, Error<&str>>("255"), Ok(("", 255)));
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(parse_i64("-12 3"), Ok((" 3", -12)));
+        assert_eq!(parse_i32("7"), Ok(("", 7)));
+        assert!(parse_i64("-").is_err());
+        assert!(parse_i32("+1").is_err());
+        assert!(parse_i32("-2147483649").is_err());
+        assert_eq!(
+            parse_i128("-170141183460469231731687303715884105728"),
+            Ok(("", i128::MIN))
+        );
+        //Hailstones list negative velocities.
+        let mut velocity = separated_list1(ws(tag(",")), parse_i64);
+        assert_eq!(velocity("-2,  1, -2"), Ok(("", vec![-2, 1, -2])));
+    }
+
+    #[test]
+    fn test_number_lists() {
+        assert_eq!(
+            numbers::<i64>("4 -2,0,  -15\n7").unwrap(),
+            vec![4, -2, 0, -15, 7]
+        );
+        assert_eq!(numbers::<u32>(" ").unwrap(), Vec::<u32>::new());
+        let error = numbers::<u32>("1, x, 3").unwrap_err().to_string();
+        assert!(error.starts_with("\"x\" is not a number"), "{error}");
+        assert_eq!(
+            number_lines::<i64>("0 3 6\n\n1,3\r\n").unwrap(),
+            vec![vec![0, 3, 6], vec![1, 3]]
+        );
+    }
+
+    #[test]
+    fn test_complete() {
+        let mut lines = separated_list1(newline, parse_u32);
+        assert_eq!(complete("1\n2\n3\n", &mut lines).unwrap(), vec![1, 2, 3]);
+        let error = complete("1\n2\n3x\n4", &mut lines).unwrap_err().to_string();
+        assert_eq!(error, "unexpected input at line 3, column 2:\n3x\n ^");
+        let error = complete("  7", parse_u32).unwrap_err().to_string();
+        assert!(
+            error.starts_with("failed to parse (Digit) at line 1, column 1"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        let number = uint::<_, u32, Error<&[u8]>>;
+        assert_eq!(number(b"42 red"), Ok((&b" red"[..], 42)));
+        assert!(number(b"4294967296").is_err());
+        assert!(number(b"x").is_err());
+        assert_eq!(uint::<_, u8, Error<&str>>("255"), Ok(("", 255)));
+        let mut card = labeled("Card", ws(uint::<_, u64, Error<&[u8]>>));
+        assert_eq!(card(&b"Card 3:  17\n"[..]), Ok((&b"\n"[..], (3, 17))));
+        let mut lines = separated_list1(newline, uint::<_, u32, _>);
+        assert_eq!(complete_bytes("1\r\n2\n", &mut lines).unwrap(), vec![1, 2]);
+        let error = complete_bytes("1\n2é", &mut lines).unwrap_err().to_string();
+        assert_eq!(error, "unexpected input at line 2, column 2:\n2é\n ^");
+    }
+
+    #[test]
+    fn test_lenient() {
+        let (numbers, skipped) = lenient("1\nx\n\n3\ny\nz\nw\n5", |line| Ok(line.parse::<u32>()?));
+        assert_eq!(numbers, vec![1, 3, 5]);
+        assert_eq!(skipped.count, 4);
+        assert_eq!(
+            skipped.to_string(),
+            "skipped 4 malformed line(s)\n  line 2: x\n  line 5: y\n  line 6: z"
+        );
+        let (_, skipped) = lenient("1\n2", |line| Ok(line.parse::<u32>()?));
+        assert!(skipped.is_empty());
+    }
+}
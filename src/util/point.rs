@@ -0,0 +1,153 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a 2D grid: `x` counts columns to the right and `y` counts rows down.
+///
+/// The same type serves as the offset between two positions.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The distance travelling only along rows and columns.
+    #[must_use]
+    pub const fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The distance when diagonal steps count the same as straight ones.
+    #[must_use]
+    pub const fn chebyshev(self, other: Self) -> i64 {
+        let (dx, dy) = ((self.x - other.x).abs(), (self.y - other.y).abs());
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    /// The single step, possibly diagonal, that heads the same way as this offset.
+    #[must_use]
+    pub const fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The `(x, y)` grid index `offset` away from `index`, unless it would be negative.
+    #[must_use]
+    pub fn checked_offset(index: (usize, usize), offset: Self) -> Option<(usize, usize)> {
+        (Self::from(index) + offset).try_into().ok()
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, scale: i64) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// From an `(x, y)` grid index.
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+/// To an `(x, y)` grid index, if neither coordinate is negative.
+impl TryFrom<Point> for (usize, usize) {
+    type Error = anyhow::Error;
+
+    fn try_from(point: Point) -> anyhow::Result<Self> {
+        match (usize::try_from(point.x), usize::try_from(point.y)) {
+            (Ok(x), Ok(y)) => Ok((x, y)),
+            _ => Err(anyhow::anyhow!("{point:?} is not a grid index")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point::new(3, -2);
+        assert_eq!(point + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(point - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(point * 3, Point::new(9, -6));
+        assert_eq!(-point, Point::new(-3, 2));
+        point += Point::new(-3, 2);
+        assert_eq!(point, Point::ORIGIN);
+        point -= Point::new(1, 0);
+        assert_eq!(point, Point::new(-1, 0));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, 5), Point::new(-2, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((a - b).signum(), Point::new(1, 1));
+        assert_eq!(Point::new(0, -7).signum(), Point::new(0, -1));
+    }
+
+    #[test]
+    fn test_index() {
+        assert_eq!(Point::from((4, 2)), Point::new(4, 2));
+        assert_eq!(
+            <(usize, usize)>::try_from(Point::new(4, 2)).unwrap(),
+            (4, 2)
+        );
+        assert!(<(usize, usize)>::try_from(Point::new(4, -2)).is_err());
+        assert_eq!(
+            Point::checked_offset((1, 1), Point::new(-1, 2)),
+            Some((0, 3))
+        );
+        assert_eq!(Point::checked_offset((1, 1), Point::new(-2, 0)), None);
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset in 3D space.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub const fn dot(self, other: Self) -> i64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[must_use]
+    pub const fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// The distance travelling only along the axes.
+    #[must_use]
+    pub const fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// A quarter turn about the x axis, taking y towards z.
+    #[must_use]
+    pub const fn rotate_x(self) -> Self {
+        Self::new(self.x, -self.z, self.y)
+    }
+
+    /// A quarter turn about the y axis, taking z towards x.
+    #[must_use]
+    pub const fn rotate_y(self) -> Self {
+        Self::new(self.z, self.y, -self.x)
+    }
+
+    /// A quarter turn about the z axis, taking x towards y.
+    #[must_use]
+    pub const fn rotate_z(self) -> Self {
+        Self::new(-self.y, self.x, self.z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+
+    fn mul(self, scale: i64) -> Self {
+        Self::new(self.x * scale, self.y * scale, self.z * scale)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl From<[i64; 3]> for Point3 {
+    fn from([x, y, z]: [i64; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<Point3> for [i64; 3] {
+    fn from(point: Point3) -> Self {
+        [point.x, point.y, point.z]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::new(4, 5, -6);
+        assert_eq!(a + b, Point3::new(5, 3, -3));
+        assert_eq!(a - b, Point3::new(-3, -7, 9));
+        assert_eq!(a * 2, Point3::new(2, -4, 6));
+        assert_eq!(-a, Point3::new(-1, 2, -3));
+        assert_eq!(a.manhattan(b), 3 + 7 + 9);
+        assert_eq!(Point3::from([1, 2, 3]), Point3::new(1, 2, 3));
+        assert_eq!(<[i64; 3]>::from(b), [4, 5, -6]);
+    }
+
+    #[test]
+    fn test_products() {
+        let (x, y, z) = (
+            Point3::new(1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, 1),
+        );
+        assert_eq!(x.cross(y), z);
+        assert_eq!(y.cross(x), -z);
+        assert_eq!(Point3::new(1, 2, 3).dot(Point3::new(4, -5, 6)), 12);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let (x, y, z) = (
+            Point3::new(1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, 1),
+        );
+        assert_eq!(y.rotate_x(), z);
+        assert_eq!(z.rotate_y(), x);
+        assert_eq!(x.rotate_z(), y);
+        let point = Point3::new(3, -1, 4);
+        let full_turn = point.rotate_x().rotate_x().rotate_x().rotate_x();
+        assert_eq!(full_turn, point);
+    }
+}
@@ -0,0 +1,73 @@
+use super::rational::Ratio;
+
+/// The value at `x` of the lowest-degree polynomial through every `(x, y)` point,
+/// worked out exactly by Lagrange interpolation.
+///
+/// Returns `None` if two points share an `x`, or if the working would overflow.
+#[must_use]
+pub fn lagrange(points: &[(i64, i64)], x: i64) -> Option<Ratio> {
+    let mut total = Ratio::ZERO;
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        //The basis polynomial that is 1 at `xi` and 0 at every other point.
+        let mut basis = Ratio::ONE;
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i != j {
+                let factor = Ratio::checked_new(
+                    i128::from(x) - i128::from(xj),
+                    i128::from(xi) - i128::from(xj),
+                )?;
+                basis = basis.checked_mul(factor)?;
+            }
+        }
+        total = total.checked_add(basis.checked_mul(Ratio::from(yi))?)?;
+    }
+    Some(total)
+}
+
+/// The value at `x` of the polynomial whose values at 0, 1, 2, ... are `samples`,
+/// for answers that grow polynomially with a step count.
+///
+/// Returns `None` if that isn't a whole number that fits, which means the samples
+/// didn't come from a polynomial with integer values.
+#[must_use]
+pub fn extrapolate(samples: &[i64], x: i64) -> Option<i64> {
+    let points: Vec<(i64, i64)> = (0..).zip(samples.iter().copied()).collect();
+    lagrange(&points, x)?
+        .to_integer()
+        .and_then(|value| i64::try_from(value).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lagrange() {
+        //Through (1, 1), (2, 4) and (4, 16), so y = x^2.
+        let points = [(1, 1), (2, 4), (4, 16)];
+        assert_eq!(lagrange(&points, 3), Some(Ratio::integer(9)));
+        assert_eq!(lagrange(&points, -5), Some(Ratio::integer(25)));
+        //A line through (0, 0) and (2, 1) is halfway at x = 1.
+        assert_eq!(lagrange(&[(0, 0), (2, 1)], 1), Some(Ratio::new(1, 2)));
+        assert_eq!(lagrange(&[(1, 1), (1, 2)], 0), None);
+        assert_eq!(lagrange(&[], 7), Some(Ratio::ZERO));
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let quadratic = |x: i64| 3 * x * x - 2 * x + 7;
+        assert_eq!(
+            extrapolate(&[0, 1, 2].map(quadratic), 10),
+            Some(quadratic(10))
+        );
+        let cubic = |x: i64| x * x * x - 4 * x;
+        assert_eq!(
+            extrapolate(&[0, 1, 2, 3].map(cubic), 1000),
+            Some(cubic(1000))
+        );
+        //2023 day 9's histories are polynomials too.
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], 6), Some(68));
+        assert_eq!(extrapolate(&[0, 1], 1 << 62), Some(1 << 62));
+        assert_eq!(extrapolate(&[0, 2], i64::MAX), None);
+    }
+}
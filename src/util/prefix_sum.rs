@@ -0,0 +1,121 @@
+use std::ops::{Add, Sub};
+
+use super::{grid::Grid, point::Point};
+
+/// The running totals of a grid, which give the sum of any rectangle of it with
+/// four lookups.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PrefixSumGrid<T> {
+    /// The sum of every cell above and left of each point, one row and column
+    /// bigger than the grid so the top and left edges need no special case.
+    totals: Grid<T>,
+}
+
+impl<T> PrefixSumGrid<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    #[must_use]
+    pub fn new(grid: &Grid<T>) -> Self {
+        let mut totals = Grid::new(grid.width() + 1, grid.height() + 1, T::default());
+        for (point, &cell) in grid.enumerate() {
+            let (x, y) = (point.x + 1, point.y + 1);
+            totals[Point::new(x, y)] =
+                cell + totals[Point::new(x - 1, y)] + totals[Point::new(x, y - 1)]
+                    - totals[Point::new(x - 1, y - 1)];
+        }
+        Self { totals }
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.totals.width() - 1
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.totals.height() - 1
+    }
+
+    /// The sum of the cells from `top_left` to `bottom_right`, both included.
+    ///
+    /// # Panics
+    ///
+    /// If the rectangle isn't entirely on the grid, or its corners are the wrong
+    /// way round.
+    #[must_use]
+    pub fn sum(&self, top_left: Point, bottom_right: Point) -> T {
+        assert!(
+            top_left.x <= bottom_right.x + 1 && top_left.y <= bottom_right.y + 1,
+            "{top_left:?} isn't above and left of {bottom_right:?}"
+        );
+        let total = |x: i64, y: i64| {
+            *self
+                .totals
+                .get(Point::new(x, y))
+                .unwrap_or_else(|| panic!("{top_left:?} to {bottom_right:?} is off the grid"))
+        };
+        let (left, top) = (top_left.x, top_left.y);
+        let (right, bottom) = (bottom_right.x + 1, bottom_right.y + 1);
+        total(right, bottom) - total(left, bottom) - total(right, top) + total(left, top)
+    }
+
+    /// The sum of the `size` by `size` square whose top left corner is `corner`.
+    #[must_use]
+    pub fn square(&self, corner: Point, size: usize) -> T {
+        let far = corner + Point::new(size as i64 - 1, size as i64 - 1);
+        self.sum(corner, far)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The power of the fuel cell at `(x, y)` in 2018 day 11, counting from 1.
+    fn power(x: i64, y: i64, serial: i64) -> i64 {
+        let rack = x + 10;
+        ((rack * y + serial) * rack / 100) % 10 - 5
+    }
+
+    /// The top-left corner (counting from 1) and power of the best 3x3 square.
+    fn best_square(serial: i64) -> (i64, i64, i64) {
+        let cells = (1..=300)
+            .map(|y| (1..=300).map(|x| power(x, y, serial)).collect())
+            .collect();
+        let sums = PrefixSumGrid::new(&Grid::from_rows(cells).unwrap());
+        (0..298)
+            .flat_map(|y| (0..298).map(move |x| Point::new(x, y)))
+            .map(|corner| (corner.x + 1, corner.y + 1, sums.square(corner, 3)))
+            .max_by_key(|&(_, _, total)| total)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_sum() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        let sums = PrefixSumGrid::new(&grid);
+        assert_eq!((sums.width(), sums.height()), (3, 3));
+        assert_eq!(sums.sum(Point::new(0, 0), Point::new(2, 2)), 45);
+        assert_eq!(sums.sum(Point::new(1, 1), Point::new(2, 2)), 28);
+        assert_eq!(sums.sum(Point::new(2, 0), Point::new(2, 1)), 9);
+        assert_eq!(sums.sum(Point::new(1, 1), Point::new(1, 1)), 5);
+        assert_eq!(sums.sum(Point::new(1, 1), Point::new(0, 0)), 0);
+        assert_eq!(sums.square(Point::new(0, 1), 2), 24);
+    }
+
+    #[test]
+    #[should_panic(expected = "off the grid")]
+    fn test_sum_off_grid() {
+        let sums = PrefixSumGrid::new(&Grid::new(2, 2, 1));
+        let _ = sums.sum(Point::new(0, 0), Point::new(2, 0));
+    }
+
+    #[test]
+    fn test_fuel_cells() {
+        assert_eq!(power(3, 5, 8), 4);
+        assert_eq!(power(122, 79, 57), -5);
+        assert_eq!(best_square(18), (33, 45, 29));
+        assert_eq!(best_square(42), (21, 61, 30));
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+/// A min-heap of states by cost where pushing a state again at a lower cost
+/// replaces it, as searches like Dijkstra's need.
+///
+/// Replaced entries are left in the heap and skipped when they come up, which is
+/// cheaper than finding and moving them. States popped can be pushed again at a
+/// lower cost, and will then come up a second time.
+#[derive(Debug, Clone)]
+pub struct PriorityQueue<S, C> {
+    /// Entries by cost, then by the order their states were first pushed. States are
+    /// kept by number so the heap needn't be able to order them.
+    heap: BinaryHeap<Reverse<(C, usize)>>,
+    indices: HashMap<S, usize>,
+    states: Vec<S>,
+    /// The lowest cost each state has been pushed with.
+    costs: Vec<C>,
+    /// Whether each state's lowest cost is still waiting in the heap.
+    queued: Vec<bool>,
+    len: usize,
+}
+
+impl<S, C> Default for PriorityQueue<S, C> {
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            indices: HashMap::new(),
+            states: Vec::new(),
+            costs: Vec::new(),
+            queued: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> PriorityQueue<S, C> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of states waiting to be popped.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The lowest cost `state` has been pushed with, whether or not it has been popped.
+    #[must_use]
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.indices.get(state).map(|&index| self.costs[index])
+    }
+
+    /// Queues `state` at `cost`, unless it has already been pushed at a cost no
+    /// higher. Returns whether it was queued.
+    pub fn push(&mut self, state: S, cost: C) -> bool {
+        let index = match self.indices.entry(state) {
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if cost >= self.costs[index] {
+                    return false;
+                }
+                self.costs[index] = cost;
+                index
+            }
+            Entry::Vacant(entry) => {
+                self.states.push(entry.key().clone());
+                self.costs.push(cost);
+                self.queued.push(false);
+                *entry.insert(self.states.len() - 1)
+            }
+        };
+        if !self.queued[index] {
+            self.queued[index] = true;
+            self.len += 1;
+        }
+        self.heap.push(Reverse((cost, index)));
+        true
+    }
+
+    /// Takes the cheapest state waiting, with its cost.
+    pub fn pop(&mut self) -> Option<(S, C)> {
+        while let Some(Reverse((cost, index))) = self.heap.pop() {
+            //Skip entries since replaced by a cheaper push, and copies of one
+            //  already popped.
+            if !self.queued[index] || cost > self.costs[index] {
+                continue;
+            }
+            self.queued[index] = false;
+            self.len -= 1;
+            return Some((self.states[index].clone(), cost));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let mut queue = PriorityQueue::new();
+        assert!(queue.push("c", 3));
+        assert!(queue.push("a", 1));
+        assert!(queue.push("b", 1));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some(("a", 1)));
+        assert_eq!(queue.pop(), Some(("b", 1)));
+        assert_eq!(queue.pop(), Some(("c", 3)));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut queue = PriorityQueue::new();
+        queue.push('x', 10);
+        queue.push('y', 5);
+        assert!(queue.push('x', 2));
+        assert!(!queue.push('x', 7));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.cost(&'x'), Some(2));
+        assert_eq!(queue.pop(), Some(('x', 2)));
+        assert_eq!(queue.pop(), Some(('y', 5)));
+        //The stale entry for x at 10 is skipped.
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn test_reopen() {
+        let mut queue = PriorityQueue::new();
+        queue.push(1, 4);
+        assert_eq!(queue.pop(), Some((1, 4)));
+        assert!(!queue.push(1, 4));
+        assert!(queue.is_empty());
+        assert!(queue.push(1, 3));
+        assert_eq!(queue.pop(), Some((1, 3)));
+        assert_eq!(queue.cost(&1), Some(3));
+        assert_eq!(queue.cost(&2), None);
+    }
+}
@@ -0,0 +1,434 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// An exact fraction, always kept in lowest terms with a positive denominator so
+/// equal values compare equal.
+///
+/// The `checked_` methods return `None` if a result won't fit in an `i128`; the
+/// operators panic instead, rather than give a wrong answer.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Ratio {
+    numer: i128,
+    denom: i128,
+}
+
+//Works on magnitudes, as `i128::MIN` has none that fits in an `i128`.
+const fn gcd(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// A signed 256-bit integer, big enough for the sum of two products of `i128`s.
+#[derive(Debug, Clone, Copy)]
+struct Wide {
+    negative: bool,
+    high: u128,
+    low: u128,
+}
+
+impl Wide {
+    fn product(a: i128, b: i128) -> Self {
+        const HALF: u32 = 64;
+        let (x, y) = (a.unsigned_abs(), b.unsigned_abs());
+        let mask = u128::from(u64::MAX);
+        let (x1, x0, y1, y0) = (x >> HALF, x & mask, y >> HALF, y & mask);
+        //Each partial product of 64-bit halves fits, and so do their sums below.
+        let (middle, middle_carry) = (x1 * y0).overflowing_add(x0 * y1);
+        let (low, low_carry) = (x0 * y0).overflowing_add(middle << HALF);
+        let high =
+            x1 * y1 + (middle >> HALF) + (u128::from(middle_carry) << HALF) + u128::from(low_carry);
+        Self {
+            negative: (a < 0) != (b < 0) && (high, low) != (0, 0),
+            high,
+            low,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        let (larger, smaller) = if (self.high, self.low) >= (other.high, other.low) {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        //The magnitudes are under 2^255, so neither of these wraps.
+        let (high, low) = if self.negative == other.negative {
+            let (low, carry) = larger.low.overflowing_add(smaller.low);
+            (larger.high + smaller.high + u128::from(carry), low)
+        } else {
+            let (low, borrow) = larger.low.overflowing_sub(smaller.low);
+            (larger.high - smaller.high - u128::from(borrow), low)
+        };
+        Self {
+            negative: larger.negative && (high, low) != (0, 0),
+            high,
+            low,
+        }
+    }
+
+    /// The magnitude divided by `divisor`, as the quotient if it fits in a `u128`,
+    /// and the remainder.
+    fn div_rem(self, divisor: u128) -> (Option<u128>, u128) {
+        let (mut quotient, mut remainder, mut fits) = (0_u128, 0_u128, true);
+        for bit in (0..256).rev() {
+            let word = if bit >= 128 { self.high } else { self.low };
+            //`remainder` stays below `divisor`, which is at most 2^127.
+            remainder = remainder << 1 | (word >> (bit % 128)) & 1;
+            let set = remainder >= divisor;
+            if set {
+                remainder -= divisor;
+            }
+            fits &= quotient.leading_zeros() > 0;
+            quotient = quotient << 1 | u128::from(set);
+        }
+        (fits.then_some(quotient), remainder)
+    }
+}
+
+/// Gives `magnitude` the sign asked for, if that fits in an `i128`.
+fn signed(negative: bool, magnitude: u128) -> Option<i128> {
+    if negative {
+        0_i128.checked_sub_unsigned(magnitude)
+    } else {
+        i128::try_from(magnitude).ok()
+    }
+}
+
+impl Ratio {
+    pub const ZERO: Self = Self::integer(0);
+    pub const ONE: Self = Self::integer(1);
+
+    /// `numer / denom` in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// If `denom` is zero.
+    #[must_use]
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "{numer}/0 has a zero denominator");
+        checked(Self::checked_new(numer, denom))
+    }
+
+    /// `numer / denom` in lowest terms, or `None` if `denom` is zero or the
+    /// fraction can't be written with a positive denominator.
+    #[must_use]
+    pub fn checked_new(numer: i128, denom: i128) -> Option<Self> {
+        if denom == 0 {
+            return None;
+        }
+        let divisor = gcd(numer, denom);
+        Some(Self {
+            numer: signed((numer < 0) != (denom < 0), numer.unsigned_abs() / divisor)?,
+            denom: i128::try_from(denom.unsigned_abs() / divisor).ok()?,
+        })
+    }
+
+    #[must_use]
+    pub const fn integer(value: i128) -> Self {
+        Self {
+            numer: value,
+            denom: 1,
+        }
+    }
+
+    #[must_use]
+    pub const fn numer(self) -> i128 {
+        self.numer
+    }
+
+    #[must_use]
+    pub const fn denom(self) -> i128 {
+        self.denom
+    }
+
+    /// The value as an integer, if it is one.
+    #[must_use]
+    pub const fn to_integer(self) -> Option<i128> {
+        if self.denom == 1 {
+            Some(self.numer)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.numer == 0
+    }
+
+    /// `None` only if the sum itself won't fit: the cross products are worked out
+    /// in 256 bits, and cancelled down before they have to fit in an `i128`.
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        //Only scale up by as much as the denominators don't already share, and
+        //  then only what the sum shares with that can still cancel, which leaves
+        //  it in lowest terms (Knuth, TAOCP 4.5.1).
+        let shared = i128::try_from(gcd(self.denom, other.denom)).ok()?;
+        let (left, right) = (other.denom / shared, self.denom / shared);
+        let sum = Wide::product(self.numer, left).add(Wide::product(other.numer, right));
+        let (_, rest) = sum.div_rem(shared.unsigned_abs());
+        let cancel = gcd(i128::try_from(rest).ok()?, shared);
+        Some(Self {
+            numer: signed(sum.negative, sum.div_rem(cancel).0?)?,
+            denom: right.checked_mul(other.denom / i128::try_from(cancel).ok()?)?,
+        })
+    }
+
+    #[must_use]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom,
+        })
+    }
+
+    #[must_use]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    #[must_use]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        //Cancel across the two fractions first, so the products stay small.
+        let a = i128::try_from(gcd(self.numer, other.denom).max(1)).ok()?;
+        let b = i128::try_from(gcd(other.numer, self.denom).max(1)).ok()?;
+        Self::checked_new(
+            (self.numer / a).checked_mul(other.numer / b)?,
+            (self.denom / b).checked_mul(other.denom / a)?,
+        )
+    }
+
+    /// `None` if `other` is zero, as well as on overflow.
+    #[must_use]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(Self::checked_new(other.denom, other.numer)?)
+    }
+
+    /// Compares without overflowing, unlike cross-multiplying would.
+    fn compare(self, other: Self) -> Ordering {
+        if let (Some(left), Some(right)) = (
+            self.numer.checked_mul(other.denom),
+            other.numer.checked_mul(self.denom),
+        ) {
+            return left.cmp(&right);
+        }
+        //Compare the whole parts, then the reciprocals of what's left, which is
+        //  the continued fraction of each taken a term at a time.
+        let (whole, other_whole) = (
+            self.numer.div_euclid(self.denom),
+            other.numer.div_euclid(other.denom),
+        );
+        if whole != other_whole {
+            return whole.cmp(&other_whole);
+        }
+        let (rest, other_rest) = (
+            self.numer.rem_euclid(self.denom),
+            other.numer.rem_euclid(other.denom),
+        );
+        match (rest, other_rest) {
+            (0, 0) => Ordering::Equal,
+            (0, _) => Ordering::Less,
+            (_, 0) => Ordering::Greater,
+            //The larger fraction has the smaller reciprocal. Both are still in
+            //  lowest terms, and each step shrinks them like Euclid's algorithm.
+            _ => Self {
+                numer: other.denom,
+                denom: other_rest,
+            }
+            .compare(Self {
+                numer: self.denom,
+                denom: rest,
+            }),
+        }
+    }
+}
+
+fn checked<T>(value: Option<T>) -> T {
+    value.expect("rational arithmetic overflowed")
+}
+
+impl From<i128> for Ratio {
+    fn from(value: i128) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl From<i64> for Ratio {
+    fn from(value: i64) -> Self {
+        Self::integer(i128::from(value))
+    }
+}
+
+impl Add for Ratio {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        checked(self.checked_add(other))
+    }
+}
+
+impl Neg for Ratio {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        checked(self.checked_neg())
+    }
+}
+
+impl Sub for Ratio {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        checked(self.checked_sub(other))
+    }
+}
+
+impl Mul for Ratio {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        checked(self.checked_mul(other))
+    }
+}
+
+impl Div for Ratio {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If `other` is zero.
+    fn div(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "division by zero");
+        checked(self.checked_div(other))
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(*other)
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denom == 1 {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Ratio::new(2, 4);
+        assert_eq!(half, Ratio::new(-1, -2));
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(Ratio::new(3, -6), -half);
+        assert_eq!(half + Ratio::new(1, 3), Ratio::new(5, 6));
+        assert_eq!(half - Ratio::ONE, Ratio::new(-1, 2));
+        assert_eq!(half * Ratio::new(4, 3), Ratio::new(2, 3));
+        assert_eq!(half / Ratio::new(1, 4), Ratio::integer(2));
+        assert!(Ratio::new(-1, 3) < Ratio::new(-1, 4));
+        assert_eq!((half + half).to_integer(), Some(1));
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(Ratio::new(-7, 2).to_string(), "-7/2");
+        assert_eq!(Ratio::from(4_i64).to_string(), "4");
+    }
+
+    #[test]
+    fn test_checked() {
+        let big = Ratio::integer(i128::MAX);
+        assert_eq!(big.checked_add(Ratio::ONE), None);
+        assert_eq!(big.checked_mul(Ratio::integer(2)), None);
+        assert_eq!(Ratio::integer(i128::MIN).checked_neg(), None);
+        assert_eq!(Ratio::ONE.checked_div(Ratio::ZERO), None);
+        assert_eq!(Ratio::checked_new(1, 0), None);
+        let third = big.checked_mul(Ratio::new(1, 3)).unwrap();
+        assert_eq!(third, Ratio::new(i128::MAX, 3));
+        //`-2 * i128::MAX / 3` is in lowest terms, so its numerator can't fit.
+        assert_eq!(third.checked_sub(big), None);
+        //The cross products overflow, but the results fit once they cancel.
+        let quarter = Ratio::new(i128::MAX, 4);
+        assert_eq!(quarter.checked_add(quarter), Some(Ratio::new(i128::MAX, 2)));
+        let (c, d) = (
+            Ratio::new((1 << 126) + 1, 2),
+            Ratio::new(3 * (1 << 125) + 1, 3),
+        );
+        assert_eq!(c.checked_sub(d), Some(Ratio::new(1, 6)));
+        assert_eq!(big.checked_add(-big), Some(Ratio::ZERO));
+        let min = Ratio::integer(i128::MIN);
+        assert_eq!(min.checked_add(Ratio::new(1, 2)), None);
+        assert_eq!(
+            Ratio::new(i128::MIN + 1, 2).checked_add(Ratio::new(-1, 2)),
+            Some(Ratio::integer(i128::MIN / 2))
+        );
+        assert_eq!(
+            min.checked_add(Ratio::ONE),
+            Some(Ratio::integer(i128::MIN + 1))
+        );
+        //`i128::MIN` has no negation, but these still have positive denominators.
+        assert_eq!(Ratio::checked_new(0, i128::MIN), Some(Ratio::ZERO));
+        assert_eq!(Ratio::checked_new(i128::MIN, i128::MIN), Some(Ratio::ONE));
+        assert_eq!(
+            Ratio::checked_new(i128::MIN, 2),
+            Some(Ratio::integer(i128::MIN / 2))
+        );
+        assert_eq!(Ratio::checked_new(1, i128::MIN), None);
+        assert_eq!(Ratio::checked_new(i128::MIN, -1), None);
+        //Cross-multiplying these would overflow.
+        let (a, b) = (Ratio::new(i128::MAX, 3), Ratio::new(i128::MAX - 2, 3));
+        assert!(a > b);
+        assert!(Ratio::new(i128::MAX, i128::MAX - 1) < Ratio::new(i128::MAX - 1, i128::MAX - 2));
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_wide() {
+        //(2^127 - 1)^2 = 2^254 - 2^128 + 1
+        let square = Wide::product(i128::MAX, i128::MIN + 1);
+        assert_eq!(
+            (square.negative, square.high, square.low),
+            (true, (1 << 126) - 1, 1)
+        );
+        assert_eq!(
+            square.div_rem(i128::MAX.unsigned_abs()),
+            (Some(i128::MAX.unsigned_abs()), 0)
+        );
+        for (a, b, c, d) in [
+            (7, -3, 5, 4),
+            (-12, 9, 12, 9),
+            (0, 5, -6, -7),
+            (1 << 60, 1 << 60, -3, 1 << 62),
+        ] {
+            let sum = Wide::product(a, b).add(Wide::product(c, d));
+            let expected: i128 = a * b + c * d;
+            let (quotient, remainder) = sum.div_rem(5);
+            assert_eq!(
+                signed(sum.negative, quotient.unwrap() * 5 + remainder),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn test_overflow() {
+        let _ = Ratio::integer(i128::MAX) + Ratio::ONE;
+    }
+}
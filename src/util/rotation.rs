@@ -0,0 +1,156 @@
+use super::point3::Point3;
+
+/// A rotation of space that maps each axis onto an axis: one of the 24 ways to
+/// turn a cube so it sits squarely again.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Rotation([[i64; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Self = Self([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// All 24 rotations, starting with the identity.
+    #[must_use]
+    pub fn all() -> Vec<Self> {
+        //Every signed permutation matrix, keeping those that don't mirror space.
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        PERMUTATIONS
+            .iter()
+            .flat_map(|&axes| {
+                (0..8).map(move |signs: usize| {
+                    let mut matrix = [[0; 3]; 3];
+                    for (row, &axis) in axes.iter().enumerate() {
+                        matrix[row][axis] = if signs >> row & 1 == 0 { 1 } else { -1 };
+                    }
+                    Self(matrix)
+                })
+            })
+            .filter(|rotation| rotation.determinant() == 1)
+            .collect()
+    }
+
+    const fn determinant(&self) -> i64 {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.0;
+        a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g)
+    }
+
+    #[must_use]
+    pub fn apply(&self, point: Point3) -> Point3 {
+        self.0.map(|row| Point3::from(row).dot(point)).into()
+    }
+
+    /// This rotation followed by `other`.
+    #[must_use]
+    pub fn then(&self, other: &Self) -> Self {
+        Self(std::array::from_fn(|row| {
+            std::array::from_fn(|col| (0..3).map(|k| other.0[row][k] * self.0[k][col]).sum())
+        }))
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        //Rotation matrices are orthogonal, so the transpose undoes them.
+        Self(std::array::from_fn(|row| {
+            std::array::from_fn(|col| self.0[col][row])
+        }))
+    }
+}
+
+/// A rotation followed by a translation: how to carry coordinates measured from
+/// one point of view over to another.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Transform {
+    pub rotation: Rotation,
+    pub translation: Point3,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self {
+        rotation: Rotation::IDENTITY,
+        translation: Point3::ORIGIN,
+    };
+
+    #[must_use]
+    pub fn apply(&self, point: Point3) -> Point3 {
+        self.rotation.apply(point) + self.translation
+    }
+
+    /// This transform followed by `other`.
+    #[must_use]
+    pub fn then(&self, other: &Self) -> Self {
+        Self {
+            rotation: self.rotation.then(&other.rotation),
+            translation: other.apply(self.translation),
+        }
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            rotation,
+            translation: -rotation.apply(self.translation),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+        //Each one turns the point somewhere different.
+        let mut images: Vec<_> = rotations
+            .iter()
+            .map(|r| r.apply(Point3::new(1, 2, 3)))
+            .collect();
+        images.sort_unstable();
+        images.dedup();
+        assert_eq!(images.len(), 24);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let quarter_turn_about_z = Rotation([[0, -1, 0], [1, 0, 0], [0, 0, 1]]);
+        assert_eq!(
+            quarter_turn_about_z.apply(Point3::new(1, 2, 3)),
+            Point3::new(1, 2, 3).rotate_z()
+        );
+        let half_turn = quarter_turn_about_z.then(&quarter_turn_about_z);
+        assert_eq!(
+            half_turn.apply(Point3::new(1, 2, 3)),
+            Point3::new(-1, -2, 3)
+        );
+        assert_eq!(
+            quarter_turn_about_z.then(&quarter_turn_about_z.inverse()),
+            Rotation::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let rotations = Rotation::all();
+        let a = Transform {
+            rotation: rotations[5],
+            translation: Point3::new(10, -4, 7),
+        };
+        let b = Transform {
+            rotation: rotations[17],
+            translation: Point3::new(-3, 8, 1),
+        };
+        let point = Point3::new(5, 6, -2);
+        assert_eq!(a.then(&b).apply(point), b.apply(a.apply(point)));
+        assert_eq!(a.inverse().apply(a.apply(point)), point);
+        assert_eq!(a.then(&a.inverse()), Transform::IDENTITY);
+    }
+}
@@ -0,0 +1,438 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use super::priority_queue::PriorityQueue;
+
+/// The cheapest way a search found to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    /// Every state reached, numbered in the order they were first seen.
+    states: Vec<S>,
+    /// The state each one was best reached from, or `None` for the start.
+    previous: Vec<Option<usize>>,
+    end: usize,
+}
+
+impl<S: Clone, C> Found<S, C> {
+    /// The goal state that was reached.
+    #[must_use]
+    pub fn goal(&self) -> &S {
+        &self.states[self.end]
+    }
+
+    /// Every state on the way from the start to the goal, both included.
+    #[must_use]
+    pub fn path(&self) -> Vec<S> {
+        let mut path: Vec<S> = std::iter::successors(Some(self.end), |&index| self.previous[index])
+            .map(|index| self.states[index].clone())
+            .collect();
+        path.reverse();
+        path
+    }
+}
+
+/// Finds the cheapest way from `start` to any state satisfying `goal`, where
+/// `neighbors` lists the states one step on from a state with what each step costs.
+///
+/// Costs must not be negative. Returns `None` if no goal can be reached.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, neighbors, goal, |_| C::default())
+}
+
+/// Like [`dijkstra`], but tries first the states that `heuristic` guesses are
+/// closest to a goal.
+///
+/// The heuristic must never guess more than the true remaining cost, or the way
+/// found might not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+    heuristic: impl FnMut(&S) -> C,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, neighbors, goal, heuristic)
+}
+
+/// Expands states in order of their cost so far plus `heuristic`'s guess at the
+/// rest, so a zero heuristic gives Dijkstra's algorithm and any other gives A*.
+fn best_first<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    //States are queued by number so their order in the queue is by guess alone.
+    let mut queue = PriorityQueue::new();
+    queue.push(0, heuristic(&start));
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut costs = vec![C::default()];
+    let mut previous = vec![None];
+    while let Some((index, _)) = queue.pop() {
+        let cost = costs[index];
+        if goal(&states[index]) {
+            return Some(Found {
+                cost,
+                states,
+                previous,
+                end: index,
+            });
+        }
+        for (next, step) in neighbors(&states[index]) {
+            let next_cost = cost + step;
+            let next_index = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let known = *entry.get();
+                    if next_cost >= costs[known] {
+                        continue;
+                    }
+                    costs[known] = next_cost;
+                    previous[known] = Some(index);
+                    known
+                }
+                Entry::Vacant(entry) => {
+                    states.push(entry.key().clone());
+                    costs.push(next_cost);
+                    previous.push(Some(index));
+                    *entry.insert(states.len() - 1)
+                }
+            };
+            queue.push(next_index, next_cost + heuristic(&states[next_index]));
+        }
+    }
+    None
+}
+
+/// Every state reachable from a start, in the order a search visited them, and how
+/// it got to each.
+#[derive(Debug, Clone)]
+pub struct Traversal<S> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    /// The number of steps the search took to each state.
+    distances: Vec<usize>,
+    previous: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Traversal<S> {
+    fn new(start: S) -> Self {
+        Self {
+            indices: HashMap::from([(start.clone(), 0)]),
+            states: vec![start],
+            distances: vec![0],
+            previous: vec![None],
+        }
+    }
+
+    /// Records `next` as reached from the state numbered `from`, unless it has been
+    /// reached already. Returns its number if it is new.
+    fn reach(&mut self, next: S, from: usize) -> Option<usize> {
+        let Entry::Vacant(entry) = self.indices.entry(next) else {
+            return None;
+        };
+        self.states.push(entry.key().clone());
+        self.distances.push(self.distances[from] + 1);
+        self.previous.push(Some(from));
+        Some(*entry.insert(self.states.len() - 1))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, state: &S) -> bool {
+        self.indices.contains_key(state)
+    }
+
+    /// Every state reached, in the order the search first came to them.
+    pub fn visited(&self) -> impl Iterator<Item = &S> {
+        self.states.iter()
+    }
+
+    /// Every state reached with the number of steps the search took to get there.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, usize)> {
+        self.states.iter().zip(self.distances.iter().copied())
+    }
+
+    /// The number of steps the search took to reach `state`, which for a
+    /// breadth-first search is the fewest possible.
+    #[must_use]
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.indices.get(state).map(|&index| self.distances[index])
+    }
+
+    /// The way the search came to `state`, from the start to `state` itself.
+    #[must_use]
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        let end = *self.indices.get(state)?;
+        let mut path: Vec<S> = std::iter::successors(Some(end), |&index| self.previous[index])
+            .map(|index| self.states[index].clone())
+            .collect();
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Visits every state reachable from `start`, nearest first.
+pub fn bfs<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> Traversal<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut traversal = Traversal::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        for next in neighbors(&traversal.states[index]) {
+            queue.extend(traversal.reach(next, index));
+        }
+    }
+    traversal
+}
+
+/// Visits every state reachable from `start`, following each branch as far as it
+/// goes before backing up.
+pub fn dfs<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> Traversal<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut traversal = Traversal::new(start);
+    //Each state on the way down, with the neighbours still to try.
+    let mut stack = vec![(0, neighbors(&traversal.states[0]).into_iter())];
+    while let Some((index, untried)) = stack.last_mut() {
+        let index = *index;
+        match untried.next() {
+            Some(next) => {
+                if let Some(next) = traversal.reach(next, index) {
+                    let untried = neighbors(&traversal.states[next]).into_iter();
+                    stack.push((next, untried));
+                }
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    traversal
+}
+
+/// Integers that [`bisect`] can search.
+pub trait Bisectable: Copy + Ord {
+    /// The average of `self` and `other`, rounded down, without overflowing.
+    #[must_use]
+    fn midpoint_floor(self, other: Self) -> Self;
+
+    /// The next value up.
+    #[must_use]
+    fn successor(self) -> Self;
+}
+
+macro_rules! impl_bisectable {
+    ($($int:ty),*) => {
+        $(impl Bisectable for $int {
+            fn midpoint_floor(self, other: Self) -> Self {
+                //The shared bits, plus half of the bits only one has.
+                (self & other) + ((self ^ other) >> 1)
+            }
+
+            fn successor(self) -> Self {
+                self + 1
+            }
+        })*
+    };
+}
+
+impl_bisectable!(i32, i64, i128, u32, u64, u128, usize);
+
+/// The smallest value from `lo` to `hi`, both included, that satisfies `predicate`,
+/// which must be false up to some point and true from then on.
+///
+/// Returns `None` if even `hi` doesn't satisfy it.
+pub fn bisect<T: Bisectable>(
+    mut lo: T,
+    mut hi: T,
+    mut predicate: impl FnMut(T) -> bool,
+) -> Option<T> {
+    if lo > hi || !predicate(hi) {
+        return None;
+    }
+    while lo < hi {
+        let mid = lo.midpoint_floor(hi);
+        if predicate(mid) {
+            hi = mid;
+        } else {
+            lo = mid.successor();
+        }
+    }
+    Some(lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{grid::Grid, point::Point};
+
+    /// A small road map: (from, to, distance), travelled either way.
+    const ROADS: [(char, char, u32); 7] = [
+        ('a', 'b', 7),
+        ('a', 'c', 9),
+        ('a', 'f', 14),
+        ('b', 'c', 10),
+        ('c', 'f', 2),
+        ('c', 'd', 11),
+        ('e', 'f', 9),
+    ];
+
+    fn roads(from: char) -> Vec<(char, u32)> {
+        ROADS
+            .iter()
+            .filter_map(|&(a, b, distance)| match from {
+                _ if a == from => Some((b, distance)),
+                _ if b == from => Some((a, distance)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let found = dijkstra('a', |&town| roads(town), |&town| town == 'e').unwrap();
+        assert_eq!(found.cost, 20);
+        assert_eq!(found.path(), vec!['a', 'c', 'f', 'e']);
+        assert_eq!(*found.goal(), 'e');
+        let start = dijkstra('a', |&town| roads(town), |&town| town == 'a').unwrap();
+        assert_eq!((start.cost, start.path()), (0, vec!['a']));
+        assert!(dijkstra('a', |&town| roads(town), |&town| town == 'z').is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_any_goal() {
+        //Counting up by 3s and 5s, the cheapest way past 11 costs a step per move.
+        let found = dijkstra(0, |&n: &u32| [(n + 3, 1), (n + 5, 1)], |&n| n > 11).unwrap();
+        assert_eq!(found.cost, 3);
+        assert_eq!(found.path().len(), 4);
+    }
+
+    const MAZE: &str = "S..#......
+.#.#.####.
+.#...#....
+.#####.##.
+...#...#E.
+.#.#.#.##.
+.#...#....";
+
+    #[test]
+    fn test_astar() {
+        let maze: Grid<u8> = MAZE.parse().unwrap();
+        let find = |c| maze.enumerate().find(|&(_, &cell)| cell == c).unwrap().0;
+        let (start, end) = (find(b'S'), find(b'E'));
+        let steps = |&point: &Point| {
+            maze.neighbors4(point)
+                .filter(|&next| maze[next] != b'#')
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let slow = dijkstra(start, steps, |&point| point == end).unwrap();
+        let fast = astar(
+            start,
+            steps,
+            |&point| point == end,
+            |&point| point.manhattan(end),
+        )
+        .unwrap();
+        assert_eq!(slow.cost, 18);
+        assert_eq!(fast.cost, slow.cost);
+        let path = fast.path();
+        assert_eq!((path[0], path[path.len() - 1]), (start, end));
+        assert!(path.windows(2).all(|pair| pair[0].manhattan(pair[1]) == 1));
+        let walled_in = astar(start, |_| Vec::new(), |&point| point == end, |_| 0);
+        assert!(walled_in.is_none());
+    }
+
+    /// A binary tree numbered like a heap, cut off below 15.
+    fn children(&node: &u32) -> Vec<u32> {
+        [2 * node, 2 * node + 1]
+            .into_iter()
+            .filter(|&child| child < 16)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let traversal = bfs(1, children);
+        assert_eq!(traversal.len(), 15);
+        let order: Vec<u32> = traversal.visited().copied().collect();
+        assert_eq!(order, (1..16).collect::<Vec<_>>());
+        assert_eq!(traversal.distance(&11), Some(3));
+        assert_eq!(traversal.path(&11), Some(vec![1, 2, 5, 11]));
+        assert_eq!(traversal.path(&16), None);
+        let furthest = traversal.distances().filter(|&(_, d)| d == 3).count();
+        assert_eq!(furthest, 8);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let traversal = dfs(1, children);
+        let order: Vec<u32> = traversal.visited().copied().collect();
+        assert_eq!(
+            order,
+            vec![1, 2, 4, 8, 9, 5, 10, 11, 3, 6, 12, 13, 7, 14, 15]
+        );
+        assert_eq!(traversal.path(&13), Some(vec![1, 3, 6, 13]));
+        //Going round a cycle, depth-first takes the long way.
+        let ring = |&n: &u32| [(n + 1) % 6, (n + 5) % 6];
+        assert_eq!(dfs(0, ring).distance(&5), Some(5));
+        assert_eq!(bfs(0, ring).distance(&5), Some(1));
+    }
+
+    #[test]
+    fn test_bisect() {
+        assert_eq!(bisect(0, 100, |n| n * n >= 50), Some(8));
+        assert_eq!(bisect(0, 100, |n| n > 100), None);
+        assert_eq!(bisect(5, 1, |_| true), None);
+        assert_eq!(bisect(3, 3, |n| n == 3), Some(3));
+        let mut checks = 0;
+        bisect(0_u64, 1 << 40, |n| {
+            checks += 1;
+            n >= 12_345
+        });
+        assert!(checks <= 42);
+    }
+
+    #[test]
+    fn test_bisect_extremes() {
+        assert_eq!(bisect(i64::MIN, i64::MAX, |n| n >= -7), Some(-7));
+        assert_eq!(bisect(i64::MIN, i64::MAX, |_| true), Some(i64::MIN));
+        assert_eq!(bisect(0, u64::MAX, |n| n == u64::MAX), Some(u64::MAX));
+    }
+}
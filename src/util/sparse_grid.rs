@@ -0,0 +1,239 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    ops::Index,
+};
+
+use super::{direction::Direction, point::Point};
+
+/// A grid that only stores the cells that are set, for maps too big or too open-ended
+/// to hold every cell.
+///
+/// Keeps track of the smallest rectangle holding every cell as they come and go.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    /// The top-left and bottom-right corners of the cells, inclusive.
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The top-left and bottom-right corners of the smallest rectangle holding every
+    /// cell, or `None` if there are none.
+    #[must_use]
+    pub const fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// The number of columns between the leftmost and rightmost cells, inclusive.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    /// The number of rows between the top and bottom cells, inclusive.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Sets the cell at `point`, returning what was there before.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.grow(point);
+        self.cells.insert(point, value)
+    }
+
+    /// Clears the cell at `point`, returning what was there.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+        //Only a cell on the edge can pull the bounds in.
+        if let Some((min, max)) = self.bounds {
+            if point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y {
+                self.bounds = Self::measure(self.cells.keys().copied());
+            }
+        }
+        Some(value)
+    }
+
+    pub fn entry(&mut self, point: Point) -> Entry<'_, Point, T> {
+        //Entries are always filled in unless removed again, so count the point now.
+        self.grow(point);
+        self.cells.entry(point)
+    }
+
+    fn grow(&mut self, point: Point) {
+        let corners = self.bounds.into_iter().flat_map(|(min, max)| [min, max]);
+        self.bounds = Self::measure(corners.chain([point]));
+    }
+
+    /// The corners of the smallest rectangle holding all of `points`.
+    fn measure(points: impl Iterator<Item = Point>) -> Option<(Point, Point)> {
+        points.fold(None, |bounds, point| {
+            Some(match bounds {
+                None => (point, point),
+                Some((min, max)) => (
+                    Point::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point::new(max.x.max(point.x), max.y.max(point.y)),
+                ),
+            })
+        })
+    }
+
+    fn steps(
+        &self,
+        point: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + '_ {
+        directions
+            .iter()
+            .map(move |direction| point + direction.offset())
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// The neighbours above, right, below and left of `point` that are set.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.steps(point, &Direction::ORTHOGONAL)
+    }
+
+    /// All eight neighbours of `point`, diagonals included, that are set.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.steps(point, &Direction::ALL)
+    }
+
+    /// Every point that is set, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Every cell with its point, in no particular order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, cell)| (point, cell))
+    }
+
+    /// Draws the bounds row by row, showing each point with `draw`.
+    pub fn render(&self, mut draw: impl FnMut(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        let mut picture = String::new();
+        for y in min.y..=max.y {
+            picture.extend((min.x..=max.x).map(|x| draw(self.get(Point::new(x, y)))));
+            picture.push('\n');
+        }
+        picture
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is not set"))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point::new(500, 0), '+');
+        grid.insert(Point::new(-3, 9), '#');
+        grid.insert(Point::new(498, 4), '#');
+        assert_eq!(grid.bounds(), Some((Point::new(-3, 0), Point::new(500, 9))));
+        assert_eq!((grid.width(), grid.height()), (504, 10));
+        assert_eq!(grid.remove(Point::new(-3, 9)), Some('#'));
+        assert_eq!(grid.remove(Point::new(-3, 9)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some((Point::new(498, 0), Point::new(500, 4)))
+        );
+        *grid.entry(Point::new(499, 7)).or_insert('o') = 'x';
+        assert_eq!(grid[Point::new(499, 7)], 'x');
+        assert_eq!(grid.height(), 8);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: SparseGrid<()> = [(0, 0), (1, 0), (1, 1), (5, 5)]
+            .into_iter()
+            .map(|(x, y)| (Point::new(x, y), ()))
+            .collect();
+        assert_eq!(grid.len(), 4);
+        let beside: Vec<_> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(beside, vec![Point::new(1, 0)]);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8(Point::new(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let grid: SparseGrid<char> = [(Point::new(-1, 2), 'a'), (Point::new(1, 3), 'b')]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            grid.render(|cell| cell.copied().unwrap_or('.')),
+            "a..\n..b\n"
+        );
+        assert_eq!(SparseGrid::<char>::new().render(|_| '.'), "");
+    }
+}
@@ -0,0 +1,129 @@
+/// The fewest single-character insertions, deletions and substitutions that turn
+/// `a` into `b`.
+#[must_use]
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    //The distances from the part of `a` read so far to each prefix of `b`.
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitute.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// The number of positions where `a` and `b` differ, or `None` if they aren't the
+/// same length.
+#[must_use]
+pub fn hamming(a: &str, b: &str) -> Option<usize> {
+    (a.chars().count() == b.chars().count())
+        .then(|| a.chars().zip(b.chars()).filter(|(ca, cb)| ca != cb).count())
+}
+
+/// The characters that `a` and `b` have in the same positions, in order.
+#[must_use]
+pub fn common_chars(a: &str, b: &str) -> String {
+    a.chars()
+        .zip(b.chars())
+        .filter(|(ca, cb)| ca == cb)
+        .map(|(c, _)| c)
+        .collect()
+}
+
+/// The longest run of characters found unbroken in both `a` and `b`, the first in
+/// `a` if there is a tie.
+#[must_use]
+pub fn longest_common_substring(a: &str, b: &str) -> String {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    //The length of the common run ending at each position of `b`, for the
+    //  previous and current positions of `a`.
+    let mut previous = vec![0; b.len() + 1];
+    let mut current = vec![0; b.len() + 1];
+    let (mut best_len, mut best_end) = (0, 0);
+    for (i, &ca) in a.iter().enumerate() {
+        for (j, &cb) in b.iter().enumerate() {
+            current[j + 1] = if ca == cb { previous[j] + 1 } else { 0 };
+            if current[j + 1] > best_len {
+                (best_len, best_end) = (current[j + 1], i + 1);
+            }
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    a[best_end - best_len..best_end].iter().collect()
+}
+
+/// The longest sequence of characters found in both `a` and `b` in the same order,
+/// though not necessarily together.
+#[must_use]
+pub fn longest_common_subsequence(a: &str, b: &str) -> String {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    //lengths[i][j] is the length of the answer for the first `i` of `a` and the
+    //  first `j` of `b`.
+    let mut lengths = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, &ca) in a.iter().enumerate() {
+        for (j, &cb) in b.iter().enumerate() {
+            lengths[i + 1][j + 1] = if ca == cb {
+                lengths[i][j] + 1
+            } else {
+                lengths[i][j + 1].max(lengths[i + 1][j])
+            };
+        }
+    }
+    //Walk back from the end, keeping the characters that were matched.
+    let mut common = Vec::new();
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            common.push(a[i - 1]);
+            (i, j) = (i - 1, j - 1);
+        } else if lengths[i - 1][j] >= lengths[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    common.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_boxes() {
+        //2018 day 2: the two box IDs differing by exactly one character.
+        let ids = [
+            "abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz",
+        ];
+        let (a, b) = ids
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| ids[i + 1..].iter().map(move |b| (a, b)))
+            .find(|(a, b)| hamming(a, b) == Some(1))
+            .unwrap();
+        assert_eq!(common_chars(a, b), "fgij");
+        assert_eq!(hamming("abc", "abcd"), None);
+    }
+
+    #[test]
+    fn test_common() {
+        assert_eq!(longest_common_substring("xabcdey", "zzbcdezz"), "bcde");
+        assert_eq!(longest_common_substring("abc", "xyz"), "");
+        assert_eq!(longest_common_subsequence("ABCBDAB", "BDCABA").len(), 4);
+        assert_eq!(longest_common_subsequence("AGGTAB", "GXTXAYB"), "GTAB");
+        assert_eq!(longest_common_subsequence("", "abc"), "");
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Node<V> {
+    children: HashMap<u8, usize>,
+    value: Option<V>,
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Self {
+            children: HashMap::new(),
+            value: None,
+        }
+    }
+}
+
+/// A set of words, each with a value, that can find every one of them starting at
+/// a given place in some text in a single pass.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Trie<V> {
+    /// The root is always first. Each node is reached by the bytes along its path.
+    nodes: Vec<Node<V>>,
+    len: usize,
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Trie<V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::new()],
+            len: 0,
+        }
+    }
+
+    /// The number of words.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds `word` with `value`, returning the value it had before if it was already
+    /// there.
+    pub fn insert(&mut self, word: &str, value: V) -> Option<V> {
+        let mut node = 0;
+        for &byte in word.as_bytes() {
+            node = match self.nodes[node].children.get(&byte) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(Node::new());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(byte, child);
+                    child
+                }
+            };
+        }
+        let previous = self.nodes[node].value.replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    #[must_use]
+    pub fn get(&self, word: &str) -> Option<&V> {
+        let mut node = 0;
+        for byte in word.as_bytes() {
+            node = *self.nodes[node].children.get(byte)?;
+        }
+        self.nodes[node].value.as_ref()
+    }
+
+    /// Every word that `text` has starting at byte `start`, shortest first, as its
+    /// length in bytes and its value.
+    pub fn matches_at<'a, 't>(
+        &'a self,
+        text: &'t str,
+        start: usize,
+    ) -> impl Iterator<Item = (usize, &'a V)> + use<'a, 't, V> {
+        let bytes = text.as_bytes().get(start..).unwrap_or_default();
+        //The empty word matches before reading anything.
+        let empty = self.nodes[0].value.as_ref().map(|value| (0, value));
+        let mut node = Some(0);
+        let rest = bytes.iter().enumerate().map_while(move |(i, byte)| {
+            let child = *self.nodes[node?].children.get(byte)?;
+            node = Some(child);
+            Some(self.nodes[child].value.as_ref().map(|value| (i + 1, value)))
+        });
+        empty.into_iter().chain(rest.flatten())
+    }
+
+    /// The longest word that `text` has starting at byte `start`.
+    #[must_use]
+    pub fn longest_match(&self, text: &str, start: usize) -> Option<(usize, &V)> {
+        self.matches_at(text, start).last()
+    }
+}
+
+impl<'a, V> FromIterator<(&'a str, V)> for Trie<V> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, V)>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        for (word, value) in iter {
+            trie.insert(word, value);
+        }
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut trie = Trie::new();
+        assert_eq!(trie.insert("car", 1), None);
+        assert_eq!(trie.insert("cart", 2), None);
+        assert_eq!(trie.insert("car", 3), Some(1));
+        assert_eq!(trie.len(), 2);
+        assert_eq!(trie.get("car"), Some(&3));
+        assert_eq!(trie.get("ca"), None);
+        assert_eq!(trie.get("cartoon"), None);
+    }
+
+    #[test]
+    fn test_matches_at() {
+        let trie: Trie<u32> = [("r", 1), ("rb", 2), ("rbg", 3), ("g", 4)]
+            .into_iter()
+            .collect();
+        let text = "grbgx";
+        let found: Vec<_> = trie.matches_at(text, 1).collect();
+        assert_eq!(found, vec![(1, &1), (2, &2), (3, &3)]);
+        assert_eq!(trie.longest_match(text, 0), Some((1, &4)));
+        assert_eq!(trie.longest_match(text, 4), None);
+        assert_eq!(trie.matches_at(text, 9).count(), 0);
+    }
+
+    #[test]
+    fn test_towels() {
+        //2024 day 19: the number of ways to make each design from the towels.
+        let towels: Trie<()> = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
+            .into_iter()
+            .map(|towel| (towel, ()))
+            .collect();
+        let arrangements = |design: &str| {
+            let mut ways = vec![0_u64; design.len() + 1];
+            ways[0] = 1;
+            for start in 0..design.len() {
+                for (len, ()) in towels.matches_at(design, start) {
+                    ways[start + len] += ways[start];
+                }
+            }
+            ways[design.len()]
+        };
+        assert_eq!(arrangements("brwrr"), 2);
+        assert_eq!(arrangements("gbbr"), 4);
+        assert_eq!(arrangements("rrbgbr"), 6);
+        assert_eq!(arrangements("ubwu"), 0);
+        assert_eq!(arrangements("bbrgwb"), 0);
+    }
+}
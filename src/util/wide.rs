@@ -0,0 +1,134 @@
+//! Helpers for answers too big for the usual integer types, so a huge input gives an
+//! error or `None` instead of quietly wrapping round.
+
+use std::{any::type_name, fmt::Display, str::FromStr};
+
+use anyhow::Result;
+
+/// Parses a whole number, ignoring surrounding whitespace, naming the type it
+/// didn't fit in when it fails.
+pub fn parse_int<T>(text: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.trim()
+        .parse()
+        .map_err(|e| anyhow::anyhow!("{text:?} is not a valid {}: {e}", type_name::<T>()))
+}
+
+pub fn parse_u128(text: &str) -> Result<u128> {
+    parse_int(text)
+}
+
+pub fn parse_i128(text: &str) -> Result<i128> {
+    parse_int(text)
+}
+
+/// Integers that can be totted up without overflowing unnoticed.
+pub trait Accumulate: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    #[must_use]
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    #[must_use]
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    #[must_use]
+    fn saturating_add(self, other: Self) -> Self;
+
+    #[must_use]
+    fn saturating_mul(self, other: Self) -> Self;
+}
+
+macro_rules! impl_accumulate {
+    ($($int:ty),*) => {
+        $(impl Accumulate for $int {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$int>::checked_add(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$int>::checked_mul(self, other)
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                <$int>::saturating_add(self, other)
+            }
+
+            fn saturating_mul(self, other: Self) -> Self {
+                <$int>::saturating_mul(self, other)
+            }
+        })*
+    };
+}
+
+impl_accumulate!(u32, u64, u128, usize, i32, i64, i128);
+
+/// Sums and products that notice overflow, for any iterator of integers.
+pub trait AccumulateExt: Iterator + Sized
+where
+    Self::Item: Accumulate,
+{
+    /// The sum, or `None` if it overflows.
+    fn checked_sum(mut self) -> Option<Self::Item> {
+        self.try_fold(Self::Item::ZERO, Accumulate::checked_add)
+    }
+
+    /// The product, or `None` if it overflows.
+    fn checked_product(mut self) -> Option<Self::Item> {
+        self.try_fold(Self::Item::ONE, Accumulate::checked_mul)
+    }
+
+    /// The sum, stuck at the type's limit if it would go past it.
+    fn saturating_sum(self) -> Self::Item {
+        self.fold(Self::Item::ZERO, Accumulate::saturating_add)
+    }
+
+    /// The product, stuck at the type's limit if it would go past it.
+    fn saturating_product(self) -> Self::Item {
+        self.fold(Self::Item::ONE, Accumulate::saturating_mul)
+    }
+}
+
+impl<I: Iterator> AccumulateExt for I where I::Item: Accumulate {}
+
+/// The sum of `values` in a `u128`, which can't overflow for fewer than 2^64 of them.
+pub fn wide_sum(values: impl IntoIterator<Item = u64>) -> u128 {
+    values.into_iter().map(u128::from).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_u128(" 340282366920938463463374607431768211455\n").unwrap(),
+            u128::MAX
+        );
+        assert_eq!(parse_i128("-12").unwrap(), -12);
+        let error = parse_u128("-12").unwrap_err().to_string();
+        assert!(error.starts_with("\"-12\" is not a valid u128"), "{error}");
+        assert!(parse_int::<u32>("4294967296").is_err());
+    }
+
+    #[test]
+    fn test_accumulate() {
+        let big = [u64::MAX / 2, u64::MAX / 2, 2];
+        assert_eq!(big.into_iter().checked_sum(), None);
+        assert_eq!(big.into_iter().saturating_sum(), u64::MAX);
+        assert_eq!([1_u64, 2, 3].into_iter().checked_sum(), Some(6));
+        assert_eq!(wide_sum(big), u128::from(u64::MAX) + 1);
+        assert_eq!([1 << 20, 1 << 20_u32].into_iter().checked_product(), None);
+        assert_eq!([-3_i64, 4].into_iter().checked_product(), Some(-12));
+        assert_eq!([i32::MIN, -1].into_iter().saturating_product(), i32::MAX);
+        assert_eq!(std::iter::empty::<i128>().checked_product(), Some(1));
+    }
+}
@@ -0,0 +1,88 @@
+//! winnow versions of the shared helpers in [`parse`](super::parse), for comparing
+//! the two parser stacks. Only built with the `winnow` feature.
+
+use anyhow::Result;
+use winnow::{
+    ascii::{dec_uint, space0},
+    combinator::delimited,
+    error::{ContextError, ErrMode, ParserError},
+    stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial},
+    token::literal,
+    Parser,
+};
+
+use super::parse::located;
+
+/// `inner` with the spaces and tabs around it skipped, like [`parse::ws`](super::parse::ws).
+pub fn ws<I, O, E, P>(inner: P) -> impl Parser<I, O, E>
+where
+    I: Stream + StreamIsPartial,
+    <I as Stream>::Token: AsChar + Clone,
+    P: Parser<I, O, E>,
+    E: ParserError<I>,
+{
+    delimited(space0, inner, space0)
+}
+
+/// A header like `Card 12:` naming a numbered item, followed by the item itself,
+/// like [`parse::labeled`](super::parse::labeled).
+pub fn labeled<'a, I, O, E, P>(
+    label: &'a str,
+    inner: P,
+) -> impl Parser<I, (u32, O), E> + use<'a, I, O, E, P>
+where
+    I: Stream + StreamIsPartial + Compare<&'a str> + Compare<char>,
+    <I as Stream>::Token: AsChar + Clone,
+    <I as Stream>::Slice: AsBStr,
+    P: Parser<I, O, E>,
+    E: ParserError<I>,
+{
+    (ws(literal(label)), dec_uint, ws(':'), inner).map(|(_, id, _, value)| (id, value))
+}
+
+/// Runs `parser` over the bytes of `input`, allowing only whitespace to be left
+/// over, with errors pointing at a line and column like
+/// [`parse::complete_bytes`](super::parse::complete_bytes).
+pub fn complete_bytes<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a [u8], O, ErrMode<ContextError>>,
+) -> Result<O> {
+    let mut rest = input.as_bytes();
+    //A failed parser leaves `rest` where it went wrong.
+    match parser.parse_next(&mut rest) {
+        Ok(output) if rest.trim_ascii().is_empty() => Ok(output),
+        Ok(_) => Err(located(input, rest.len(), "unexpected input")),
+        Err(_) => Err(located(input, rest.len(), "failed to parse")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use winnow::{ascii::line_ending, combinator::separated};
+
+    use super::*;
+
+    #[test]
+    fn test_labeled() {
+        let mut card = labeled("Card", ws(dec_uint::<_, u64, ContextError>));
+        assert_eq!(
+            card.parse_peek(&b"Card 3:  17\n"[..]),
+            Ok((&b"\n"[..], (3, 17)))
+        );
+        assert!(card.parse_peek(&b"Card x: 17"[..]).is_err());
+    }
+
+    #[test]
+    fn test_complete_bytes() {
+        let lines = separated(1.., dec_uint::<_, u32, _>, line_ending);
+        assert_eq!(
+            complete_bytes::<Vec<u32>>("1\r\n2\n", lines).unwrap(),
+            vec![1, 2]
+        );
+        let lines = separated(1.., dec_uint::<_, u32, _>, line_ending);
+        let error = complete_bytes::<Vec<u32>>("1\n2é", lines)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "unexpected input at line 2, column 2:\n2é\n ^");
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{Context, Result};
+
+use crate::{input, runner::Record};
+
+/// How often the watched files are checked for changes.
+pub const INTERVAL: Duration = Duration::from_millis(500);
+
+/// The files whose changes re-run `day` of `year`: its source and its input.
+#[must_use]
+pub fn watched_paths(year: u16, day: u8) -> Vec<PathBuf> {
+    vec![
+        PathBuf::from("src")
+            .join(format!("year{year}"))
+            .join(format!("day{day}.rs")),
+        input::path(year, day),
+    ]
+}
+
+/// What one part gave on a run.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Snapshot {
+    pub part: u8,
+    /// The answer, or the error that stopped it.
+    pub answer: Result<String, String>,
+    pub total: Duration,
+}
+
+/// Reads the parts from the output of `aoc run --format json`.
+pub fn parse_results(json: &str) -> Result<Vec<Snapshot>> {
+    let records: Vec<Record> = serde_json::from_str(json).context("reading the results")?;
+    Ok(records
+        .into_iter()
+        .map(|record| Snapshot {
+            part: record.solution.part,
+            answer: match (record.error, record.answer) {
+                (Some(error), _) => Err(error),
+                (None, answer) => answer.ok_or_else(|| "no answer".to_string()),
+            },
+            total: Duration::from_nanos(record.total_ns.unwrap_or_default()),
+        })
+        .collect())
+}
+
+/// A line for each part of `after` saying how its answer and time compare with
+/// `before`.
+#[must_use]
+pub fn changes(before: &[Snapshot], after: &[Snapshot]) -> Vec<String> {
+    after
+        .iter()
+        .map(|now| {
+            let then = before.iter().find(|then| then.part == now.part);
+            let answer = match (then.map(|then| &then.answer), &now.answer) {
+                (_, Err(error)) => format!("FAILED: {error}"),
+                (Some(Ok(old)), Ok(new)) if old == new => format!("{new} (same)"),
+                (Some(Ok(old)), Ok(new)) => format!("{old} -> {new}"),
+                (_, Ok(new)) => new.clone(),
+            };
+            let time = match then {
+                Some(then) if then.answer.is_ok() && now.answer.is_ok() => {
+                    format!("{:.2?} -> {:.2?}", then.total, now.total)
+                }
+                _ => format!("{:.2?}", now.total),
+            };
+            format!("Part {}: {answer}, {time}", now.part)
+        })
+        .collect()
+}
+
+/// Runs `day` of `year` through `cargo run`, so changes to its source are built
+/// first. Build and run errors go straight to the terminal.
+fn run(year: u16, day: u8) -> Result<Vec<Snapshot>> {
+    let output = Command::new("cargo")
+        .args([
+            "run", "--quiet", "--bin", "aoc", "--", "run", "--format", "json",
+        ])
+        .args(["--year", &year.to_string(), "--day", &day.to_string()])
+        .stderr(Stdio::inherit())
+        .output()
+        .context("running cargo")?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!("cargo run failed: {}", output.status));
+    }
+    parse_results(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| {
+            std::fs::metadata(path)
+                .and_then(|meta| meta.modified())
+                .ok()
+        })
+        .collect()
+}
+
+/// Runs `day` of `year`, then runs it again whenever its source or input changes,
+/// printing how the answers and times moved. A failed build is reported and the
+/// watch carries on, comparing the next good run with the last one.
+pub fn watch(year: u16, day: u8) -> ! {
+    let paths = watched_paths(year, day);
+    for path in &paths {
+        println!("Watching {}", path.display());
+    }
+    let mut seen = modified(&paths);
+    let mut results = Vec::new();
+    loop {
+        match run(year, day) {
+            Ok(latest) => {
+                for line in changes(&results, &latest) {
+                    println!("{line}");
+                }
+                results = latest;
+            }
+            Err(e) => eprintln!("{e:#}"),
+        }
+        println!();
+        while modified(&paths) == seen {
+            thread::sleep(INTERVAL);
+        }
+        seen = modified(&paths);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{self, Outcome, Run};
+
+    fn snapshot(part: u8, answer: Result<&str, &str>, micros: u64) -> Snapshot {
+        Snapshot {
+            part,
+            answer: answer.map(String::from).map_err(String::from),
+            total: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_watched_paths() {
+        assert_eq!(
+            watched_paths(2023, 2),
+            vec![
+                PathBuf::from("src/year2023/day2.rs"),
+                PathBuf::from("input/2023/day2.txt")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_results() {
+        let outcomes = [
+            Outcome {
+                solution: runner::find(2023, 9, 1).unwrap(),
+                run: Ok(Run {
+                    answer: "a \"quoted\"\nanswer".to_string(),
+                    generator: Duration::from_micros(3),
+                    solver: Duration::from_micros(4),
+                }),
+            },
+            Outcome {
+                solution: runner::find(2023, 9, 2).unwrap(),
+                run: Err(anyhow::anyhow!("no input")),
+            },
+        ];
+        assert_eq!(
+            parse_results(&runner::json(&outcomes)).unwrap(),
+            vec![
+                snapshot(1, Ok("a \"quoted\"\nanswer"), 7),
+                snapshot(2, Err("no input"), 0)
+            ]
+        );
+        assert!(parse_results("error: could not compile").is_err());
+    }
+
+    #[test]
+    fn test_changes() {
+        let before = [snapshot(1, Ok("114"), 1500), snapshot(2, Ok("2"), 20)];
+        let after = [snapshot(1, Ok("114"), 900), snapshot(2, Ok("5"), 30)];
+        assert_eq!(
+            changes(&before, &after),
+            vec![
+                "Part 1: 114 (same), 1.50ms -> 900.00µs",
+                "Part 2: 2 -> 5, 20.00µs -> 30.00µs"
+            ]
+        );
+        assert_eq!(changes(&[], &after[..1]), vec!["Part 1: 114, 900.00µs"]);
+        assert_eq!(
+            changes(&before, &[snapshot(1, Err("no answer found"), 0)]),
+            vec!["Part 1: FAILED: no answer found, 0.00ns"]
+        );
+    }
+}
@@ -0,0 +1,496 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, i64 as number, line_ending, multispace0, space1, u8 as small},
+    combinator::{map_res, opt, verify},
+    multi::{many1, separated_list1},
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+/// One of the sixteen operations of the 2018 wrist device. The name says whether
+/// each input is a register (`r`) or an immediate value (`i`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl Opcode {
+    pub const ALL: [Self; 16] = [
+        Self::Addr,
+        Self::Addi,
+        Self::Mulr,
+        Self::Muli,
+        Self::Banr,
+        Self::Bani,
+        Self::Borr,
+        Self::Bori,
+        Self::Setr,
+        Self::Seti,
+        Self::Gtir,
+        Self::Gtri,
+        Self::Gtrr,
+        Self::Eqir,
+        Self::Eqri,
+        Self::Eqrr,
+    ];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Addr => "addr",
+            Self::Addi => "addi",
+            Self::Mulr => "mulr",
+            Self::Muli => "muli",
+            Self::Banr => "banr",
+            Self::Bani => "bani",
+            Self::Borr => "borr",
+            Self::Bori => "bori",
+            Self::Setr => "setr",
+            Self::Seti => "seti",
+            Self::Gtir => "gtir",
+            Self::Gtri => "gtri",
+            Self::Gtrr => "gtrr",
+            Self::Eqir => "eqir",
+            Self::Eqri => "eqri",
+            Self::Eqrr => "eqrr",
+        }
+    }
+
+    /// Applies the operation to `registers`, or returns `None` if it names a
+    /// register that doesn't exist or its result overflows.
+    pub fn execute(self, [a, b, c]: [i64; 3], registers: &mut [i64]) -> Option<()> {
+        let register = |value: i64| {
+            usize::try_from(value)
+                .ok()
+                .and_then(|index| registers.get(index).copied())
+        };
+        let value = match self {
+            Self::Addr => register(a)?.checked_add(register(b)?)?,
+            Self::Addi => register(a)?.checked_add(b)?,
+            Self::Mulr => register(a)?.checked_mul(register(b)?)?,
+            Self::Muli => register(a)?.checked_mul(b)?,
+            Self::Banr => register(a)? & register(b)?,
+            Self::Bani => register(a)? & b,
+            Self::Borr => register(a)? | register(b)?,
+            Self::Bori => register(a)? | b,
+            Self::Setr => register(a)?,
+            Self::Seti => a,
+            Self::Gtir => i64::from(a > register(b)?),
+            Self::Gtri => i64::from(register(a)? > b),
+            Self::Gtrr => i64::from(register(a)? > register(b)?),
+            Self::Eqir => i64::from(a == register(b)?),
+            Self::Eqri => i64::from(register(a)? == b),
+            Self::Eqrr => i64::from(register(a)? == register(b)?),
+        };
+        *usize::try_from(c).ok().and_then(|c| registers.get_mut(c))? = value;
+        Some(())
+    }
+}
+
+impl std::str::FromStr for Opcode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|opcode| opcode.name() == s)
+            .ok_or_else(|| anyhow::anyhow!("unknown opcode {s:?}"))
+    }
+}
+
+/// An observation from 2018 day 16 of an unknown opcode number at work on the
+/// four registers of the device.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Sample {
+    pub before: [i64; 4],
+    pub number: u8,
+    pub arguments: [i64; 3],
+    pub after: [i64; 4],
+}
+
+impl Sample {
+    /// Every opcode that behaves as observed.
+    pub fn candidates(&self) -> impl Iterator<Item = Opcode> + '_ {
+        Opcode::ALL.into_iter().filter(|opcode| {
+            let mut registers = self.before;
+            opcode.execute(self.arguments, &mut registers).is_some() && registers == self.after
+        })
+    }
+}
+
+/// Works out which opcode each number stands for from the samples, if they pin
+/// every one down.
+#[must_use]
+pub fn resolve_opcodes(samples: &[Sample]) -> Option<[Opcode; 16]> {
+    //Each number starts out able to be any opcode, and every sample narrows it down.
+    let mut possible = [u16::MAX; 16];
+    for sample in samples {
+        let mask = sample
+            .candidates()
+            .fold(0, |mask, opcode| mask | 1 << opcode as u16);
+        *possible.get_mut(usize::from(sample.number))? &= mask;
+    }
+    let mut resolved = [None; 16];
+    while resolved.contains(&None) {
+        //Pin down a number with only one opcode left, and rule that opcode out elsewhere.
+        let (number, mask) = possible
+            .iter()
+            .enumerate()
+            .find(|&(number, mask)| resolved[number].is_none() && mask.count_ones() == 1)?;
+        let mask = *mask;
+        resolved[number] = Some(Opcode::ALL[mask.trailing_zeros() as usize]);
+        for other in &mut possible {
+            *other &= !mask;
+        }
+    }
+    Some(resolved.map(Option::unwrap))
+}
+
+/// An instruction written with the opcode's number rather than its name.
+pub type NumberedInstruction = (u8, [i64; 3]);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub arguments: [i64; 3],
+}
+
+/// A program for the device, possibly binding the instruction pointer to a register.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Program {
+    pub ip_register: Option<usize>,
+    pub instructions: Vec<Instruction>,
+}
+
+/// A loop found by [`Program::divisor_sum_loop`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DivisorSumLoop {
+    /// The first instruction of the loop body, where the target is ready.
+    pub address: usize,
+    /// The register holding the number whose divisors are summed.
+    pub target: usize,
+}
+
+impl Program {
+    /// Finds the 2018 day 19 loop that sums the divisors of a number by trying every
+    /// pair of factors: a `mulr` of the two counters compared with `eqrr` to the target.
+    #[must_use]
+    pub fn divisor_sum_loop(&self) -> Option<DivisorSumLoop> {
+        self.instructions
+            .windows(2)
+            .enumerate()
+            .find_map(|(address, pair)| {
+                let [product, compare] = pair else {
+                    return None;
+                };
+                let [_, _, p] = product.arguments;
+                let [x, y, z] = compare.arguments;
+                let target = match (product.opcode, compare.opcode) {
+                    (Opcode::Mulr, Opcode::Eqrr) if z == p && x == p => y,
+                    (Opcode::Mulr, Opcode::Eqrr) if z == p && y == p => x,
+                    _ => return None,
+                };
+                Some(DivisorSumLoop {
+                    address,
+                    target: usize::try_from(target).ok()?,
+                })
+            })
+    }
+}
+
+/// The device running a program on six registers.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Device<'a> {
+    program: &'a Program,
+    pub registers: [i64; 6],
+    ip: usize,
+}
+
+impl<'a> Device<'a> {
+    #[must_use]
+    pub const fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            registers: [0; 6],
+            ip: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn ip(&self) -> usize {
+        self.ip
+    }
+
+    #[must_use]
+    pub fn is_halted(&self) -> bool {
+        self.ip >= self.program.instructions.len()
+    }
+
+    /// Executes the instruction at the instruction pointer, writing the pointer to
+    /// its bound register first and reading it back afterwards.
+    pub fn step(&mut self) -> Result<()> {
+        let Some(instruction) = self.program.instructions.get(self.ip) else {
+            return Err(anyhow::anyhow!("halted"));
+        };
+        if let Some(register) = self.program.ip_register {
+            self.registers[register] = self.ip as i64;
+        }
+        instruction
+            .opcode
+            .execute(instruction.arguments, &mut self.registers)
+            .ok_or_else(|| anyhow::anyhow!("bad register or overflow at {}", self.ip))?;
+        if let Some(register) = self.program.ip_register {
+            //A pointer written below zero halts the device just like one past the end.
+            self.ip = usize::try_from(self.registers[register]).unwrap_or(usize::MAX);
+        }
+        self.ip = self.ip.saturating_add(1);
+        Ok(())
+    }
+
+    /// Runs until the program halts or `stop` returns true for the instruction
+    /// pointer and registers before an instruction, returning whether it stopped early.
+    pub fn run_until(&mut self, mut stop: impl FnMut(usize, &[i64; 6]) -> bool) -> Result<bool> {
+        while !self.is_halted() {
+            if stop(self.ip, &self.registers) {
+                return Ok(true);
+            }
+            self.step()?;
+        }
+        Ok(false)
+    }
+
+    pub fn run(&mut self) -> Result<()> {
+        self.run_until(|_, _| false).map(|_| ())
+    }
+
+    /// Runs the program, short-circuiting the divisor-sum loop if it has one by
+    /// summing the divisors directly as soon as the target is ready. Returns what
+    /// ends up in register 0.
+    pub fn run_summing_divisors(&mut self) -> Result<i64> {
+        let Some(shortcut) = self.program.divisor_sum_loop() else {
+            self.run()?;
+            return Ok(self.registers[0]);
+        };
+        if !self.run_until(|ip, _| ip == shortcut.address)? {
+            return Ok(self.registers[0]);
+        }
+        Ok(sum_of_divisors(self.registers[shortcut.target]))
+    }
+}
+
+#[must_use]
+pub fn sum_of_divisors(n: i64) -> i64 {
+    (1..)
+        .take_while(|d| d * d <= n)
+        .filter(|d| n % d == 0)
+        .map(|d| if d * d == n { d } else { d + n / d })
+        .sum()
+}
+
+fn registers_parser(input: &str) -> IResult<&str, [i64; 4]> {
+    map_res(
+        delimited(tag("["), separated_list1(tag(", "), number), tag("]")),
+        <[i64; 4]>::try_from,
+    )(input)
+}
+
+fn arguments_parser(input: &str) -> IResult<&str, [i64; 3]> {
+    let (input, (a, _, b, _, c)) = tuple((number, space1, number, space1, number))(input)?;
+    Ok((input, [a, b, c]))
+}
+
+fn sample_parser(input: &str) -> IResult<&str, Sample> {
+    let (input, before) = delimited(tag("Before: "), registers_parser, line_ending)(input)?;
+    let (input, (number, _, arguments, _)) =
+        tuple((small, space1, arguments_parser, line_ending))(input)?;
+    let (input, after) = preceded(tag("After:  "), registers_parser)(input)?;
+    Ok((
+        input,
+        Sample {
+            before,
+            number,
+            arguments,
+            after,
+        },
+    ))
+}
+
+fn numbered_parser(input: &str) -> IResult<&str, NumberedInstruction> {
+    let (input, (number, _, arguments)) = tuple((small, space1, arguments_parser))(input)?;
+    Ok((input, (number, arguments)))
+}
+
+/// Parses the 2018 day 16 samples and the program written in opcode numbers after them.
+pub fn parse_samples(input: &str) -> Result<(Vec<Sample>, Vec<NumberedInstruction>)> {
+    let samples = many1(terminated(sample_parser, multispace0));
+    let program = separated_list1(line_ending, numbered_parser);
+    let (samples, program) = complete(input, tuple((samples, opt(program))))?;
+    Ok((samples, program.unwrap_or_default()))
+}
+
+fn instruction_parser(input: &str) -> IResult<&str, Instruction> {
+    let (input, (opcode, _, arguments)) =
+        tuple((map_res(alpha1, str::parse), space1, arguments_parser))(input)?;
+    Ok((input, Instruction { opcode, arguments }))
+}
+
+fn program_parser(input: &str) -> IResult<&str, Program> {
+    //The pointer can only be bound to one of the six registers.
+    let register = verify(small, |&register| register < 6);
+    let ip_parser = delimited(tag("#ip "), register, line_ending);
+    let (input, ip_register) = opt(ip_parser)(input)?;
+    let (input, instructions) = separated_list1(line_ending, instruction_parser)(input)?;
+    Ok((
+        input,
+        Program {
+            ip_register: ip_register.map(usize::from),
+            instructions,
+        },
+    ))
+}
+
+/// Parses a program written with opcode names, as in 2018 days 19 and 21.
+pub fn parse_program(input: &str) -> Result<Program> {
+    complete(input, program_parser)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples() {
+        //2018 day 16.
+        let (samples, program) =
+            parse_samples("Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n\n\n\n7 0 1 2\n")
+                .unwrap();
+        assert_eq!(program, vec![(7, [0, 1, 2])]);
+        let candidates: Vec<_> = samples[0].candidates().collect();
+        assert_eq!(candidates, vec![Opcode::Addi, Opcode::Mulr, Opcode::Seti]);
+    }
+
+    #[test]
+    fn test_resolve_opcodes() {
+        //Every opcode seen on a few different machine states, numbered in reverse.
+        let states = [
+            ([5, 3, 3, 9], [1, 2, 3]),
+            ([2, 7, 1, 0], [2, 0, 1]),
+            ([0, 6, 6, 2], [3, 1, 0]),
+            ([3, 2, 1, 1], [2, 1, 2]),
+            ([0, 5, 2, 1], [1, 2, 0]),
+        ];
+        let samples: Vec<_> = Opcode::ALL
+            .into_iter()
+            .zip((0..16).rev())
+            .flat_map(|(opcode, number)| {
+                states.into_iter().map(move |(before, arguments)| {
+                    let mut after = before;
+                    opcode.execute(arguments, &mut after).unwrap();
+                    Sample {
+                        before,
+                        number,
+                        arguments,
+                        after,
+                    }
+                })
+            })
+            .collect();
+        let mut expected = Opcode::ALL;
+        expected.reverse();
+        assert_eq!(resolve_opcodes(&samples), Some(expected));
+        assert_eq!(resolve_opcodes(&samples[..1]), None);
+    }
+
+    #[test]
+    fn test_execute() {
+        let mut registers = [0, 7, 3, 0];
+        Opcode::Borr.execute([1, 2, 3], &mut registers).unwrap();
+        assert_eq!(registers, [0, 7, 3, 7]);
+        Opcode::Gtir.execute([8, 1, 0], &mut registers).unwrap();
+        assert_eq!(registers, [1, 7, 3, 7]);
+        assert_eq!(Opcode::Addr.execute([0, 4, 0], &mut registers), None);
+        assert_eq!(Opcode::Muli.execute([1, i64::MAX, 0], &mut registers), None);
+    }
+
+    const EXAMPLE: &str = "#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5";
+
+    #[test]
+    fn test_run() {
+        //2018 day 19.
+        let program = parse_program(EXAMPLE).unwrap();
+        assert_eq!(program.ip_register, Some(0));
+        assert!(parse_program(&EXAMPLE.replace("#ip 0", "#ip 6")).is_err());
+        let mut device = Device::new(&program);
+        device.run().unwrap();
+        assert_eq!(device.registers, [6, 5, 6, 0, 0, 9]);
+    }
+
+    /// Sums the divisors of the number in register 4 the slow way, the way the
+    /// day 19 programs do.
+    const DIVISORS: &str = "#ip 5
+seti 30 0 4
+seti 1 0 1
+seti 1 0 2
+mulr 1 2 3
+eqrr 3 4 3
+addr 3 5 5
+addi 5 1 5
+addr 1 0 0
+addi 2 1 2
+gtrr 2 4 3
+addr 5 3 5
+seti 2 0 5
+addi 1 1 1
+gtrr 1 4 3
+addr 3 5 5
+seti 1 0 5";
+
+    #[test]
+    fn test_divisor_sum_loop() {
+        let program = parse_program(DIVISORS).unwrap();
+        let shortcut = program.divisor_sum_loop();
+        assert_eq!(
+            shortcut,
+            Some(DivisorSumLoop {
+                address: 3,
+                target: 4
+            })
+        );
+        let mut slow = Device::new(&program);
+        slow.run().unwrap();
+        assert_eq!(slow.registers[0], 72);
+        assert_eq!(Device::new(&program).run_summing_divisors().unwrap(), 72);
+    }
+
+    #[test]
+    fn test_sum_of_divisors() {
+        assert_eq!(sum_of_divisors(1), 1);
+        assert_eq!(sum_of_divisors(16), 31);
+        assert_eq!(sum_of_divisors(10_551_236), 18_514_860);
+    }
+}
@@ -0,0 +1,49 @@
+/// The floor each instruction takes Santa to, starting from the ground floor.
+fn floors(input: &str) -> impl Iterator<Item = i32> + '_ {
+    input
+        .bytes()
+        .filter_map(|c| match c {
+            b'(' => Some(1),
+            b')' => Some(-1),
+            _ => None,
+        })
+        .scan(0, |floor, step| {
+            *floor += step;
+            Some(*floor)
+        })
+}
+
+#[must_use]
+pub fn part1(input: &str) -> i32 {
+    floors(input).last().unwrap_or_default()
+}
+
+/// The position, counting from 1, of the instruction that first takes Santa into
+/// the basement.
+#[must_use]
+pub fn part2(input: &str) -> Option<usize> {
+    floors(input)
+        .position(|floor| floor < 0)
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("(())"), 0);
+        assert_eq!(part1("((("), 3);
+        assert_eq!(part1("))((((("), 3);
+        assert_eq!(part1(")())())"), -3);
+        assert_eq!(part1(""), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(")"), Some(1));
+        assert_eq!(part2("()())"), Some(5));
+        assert_eq!(part2("(("), None);
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use nom::{
+    character::complete::{char, line_ending, u32 as number},
+    combinator::map,
+    multi::separated_list0,
+    sequence::{terminated, tuple},
+    IResult,
+};
+
+use crate::{prelude::*, util::parse::complete};
+
+/// A present's dimensions, smallest first.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Present([u32; 3]);
+
+impl Present {
+    #[must_use]
+    pub fn new(mut dimensions: [u32; 3]) -> Self {
+        dimensions.sort_unstable();
+        Self(dimensions)
+    }
+
+    /// The surface area plus the area of the smallest side as slack, or `None` if
+    /// that doesn't fit in a `u32`.
+    #[must_use]
+    pub fn paper(&self) -> Option<u32> {
+        let [a, b, c] = self.0;
+        let smallest = a.checked_mul(b)?;
+        let sides = [smallest, b.checked_mul(c)?, c.checked_mul(a)?]
+            .into_iter()
+            .checked_sum()?;
+        sides.checked_mul(2)?.checked_add(smallest)
+    }
+
+    /// The smallest perimeter around the present plus its volume for the bow, or
+    /// `None` if that doesn't fit in a `u32`.
+    #[must_use]
+    pub fn ribbon(&self) -> Option<u32> {
+        let [a, b, c] = self.0;
+        let perimeter = a.checked_add(b)?.checked_mul(2)?;
+        perimeter.checked_add([a, b, c].into_iter().checked_product()?)
+    }
+}
+
+fn present_parser(input: &str) -> IResult<&str, Present> {
+    map(
+        tuple((
+            terminated(number, char('x')),
+            terminated(number, char('x')),
+            number,
+        )),
+        |(l, w, h)| Present::new([l, w, h]),
+    )(input)
+}
+
+/// The dimensions of every present in `input`, or an error at the first line that
+/// isn't `LxWxH` with each side fitting in a `u32`.
+pub fn presents_parser(input: &str) -> Result<Vec<Present>> {
+    complete(input, separated_list0(line_ending, present_parser))
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<Present> {
+    match presents_parser(input) {
+        Ok(presents) => presents,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[Present]) -> Option<u32> {
+    input
+        .iter()
+        .try_fold(0_u32, |total, present| total.checked_add(present.paper()?))
+}
+
+#[must_use]
+pub fn part2(input: &[Present]) -> Option<u32> {
+    input
+        .iter()
+        .try_fold(0_u32, |total, present| total.checked_add(present.ribbon()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2x3x4
+1x1x10
+";
+
+    #[test]
+    fn test_present_parser() {
+        assert_eq!(present_parser("4x2x3"), Ok(("", Present([2, 3, 4]))));
+        assert!(present_parser("4x2").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(58 + 43));
+        assert_eq!(part1(&input_generator("65536x65536x1")), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(34 + 14));
+        assert_eq!(part2(&input_generator("2048x2048x1024")), None);
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::util::{direction::Direction, point::Point};
+
+/// Every move in `input`, or an error naming the first character that isn't one.
+pub fn moves_parser(input: &str) -> Result<Vec<Direction>> {
+    input.trim().chars().map(Direction::try_from).collect()
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<Direction> {
+    match moves_parser(input) {
+        Ok(moves) => moves,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+/// Every house visited by `deliverers` taking turns to follow the moves, all
+/// starting from the same house.
+fn visited(moves: &[Direction], deliverers: usize) -> HashSet<Point> {
+    let mut positions = vec![Point::ORIGIN; deliverers];
+    let mut visited = HashSet::from([Point::ORIGIN]);
+    for (turn, direction) in moves.iter().enumerate() {
+        let position = &mut positions[turn % deliverers];
+        *position += direction.offset();
+        visited.insert(*position);
+    }
+    visited
+}
+
+#[must_use]
+pub fn part1(input: &[Direction]) -> usize {
+    visited(input, 1).len()
+}
+
+#[must_use]
+pub fn part2(input: &[Direction]) -> usize {
+    visited(input, 2).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(">")), 2);
+        assert_eq!(part1(&input_generator("^>v<")), 4);
+        assert_eq!(part1(&input_generator("^v^v^v^v^v")), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator("^v")), 3);
+        assert_eq!(part2(&input_generator("^>v<")), 3);
+        assert_eq!(part2(&input_generator("^v^v^v^v^v")), 11);
+    }
+}
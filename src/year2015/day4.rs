@@ -0,0 +1,28 @@
+use crate::util::hash::find_zeros;
+
+/// The lowest number that, appended to the secret key, gives an MD5 hash starting
+/// with `zeros` zeros in hex.
+fn mine(key: &str, zeros: u32) -> Option<u64> {
+    find_zeros(key.trim(), 1, zeros)
+}
+
+#[must_use]
+pub fn part1(input: &str) -> Option<u64> {
+    mine(input, 5)
+}
+
+#[must_use]
+pub fn part2(input: &str) -> Option<u64> {
+    mine(input, 6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("abcdef\n"), Some(609_043));
+        assert_eq!(part1("pqrstuv"), Some(1_048_970));
+    }
+}
@@ -0,0 +1,63 @@
+/// The original rules: three vowels, a doubled letter, and none of the naughty pairs.
+#[must_use]
+pub fn is_nice(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let vowels = bytes.iter().filter(|c| b"aeiou".contains(c)).count();
+    let doubled = bytes.windows(2).any(|pair| pair[0] == pair[1]);
+    let naughty = bytes
+        .windows(2)
+        .any(|pair| [b"ab", b"cd", b"pq", b"xy"].iter().any(|bad| pair == *bad));
+    vowels >= 3 && doubled && !naughty
+}
+
+/// The better rules: a pair that appears twice without overlapping, and a letter
+/// that repeats with one letter between.
+#[must_use]
+pub fn is_nicer(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let repeated_pair = (0..bytes.len().saturating_sub(1)).any(|i| {
+        bytes[i + 2..]
+            .windows(2)
+            .any(|pair| pair == &bytes[i..i + 2])
+    });
+    let sandwich = bytes.windows(3).any(|triple| triple[0] == triple[2]);
+    repeated_pair && sandwich
+}
+
+#[must_use]
+pub fn part1(input: &str) -> usize {
+    input.lines().filter(|line| is_nice(line)).count()
+}
+
+#[must_use]
+pub fn part2(input: &str) -> usize {
+    input.lines().filter(|line| is_nicer(line)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_nice() {
+        assert!(is_nice("ugknbfddgicrmopn"));
+        assert!(is_nice("aaa"));
+        assert!(!is_nice("jchzalrnumimnmhp"));
+        assert!(!is_nice("haegwjzuvuyypxyu"));
+        assert!(!is_nice("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn test_is_nicer() {
+        assert!(is_nicer("qjhvhtzxzqqjkmpb"));
+        assert!(is_nicer("xxyxx"));
+        assert!(!is_nicer("uurcxstgmygtbstg"));
+        assert!(!is_nicer("ieodomkazucvgmuy"));
+        assert!(!is_nicer("aaa"));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("ugknbfddgicrmopn\naaa\njchzalrnumimnmhp"), 2);
+    }
+}
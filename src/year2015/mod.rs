@@ -0,0 +1,24 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+
+use crate::{runner::Solution, solution};
+
+/// Every solution for 2015.
+#[must_use]
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2015, 1, 1, day1::part1),
+        solution!(2015, 1, 2, day1::part2),
+        solution!(2015, 2, 1, day2::input_generator => day2::part1),
+        solution!(2015, 2, 2, day2::input_generator => day2::part2),
+        solution!(2015, 3, 1, day3::input_generator => day3::part1),
+        solution!(2015, 3, 2, day3::input_generator => day3::part2),
+        solution!(2015, 4, 1, day4::part1),
+        solution!(2015, 4, 2, day4::part2),
+        solution!(2015, 5, 1, day5::part1),
+        solution!(2015, 5, 2, day5::part2),
+    ]
+}
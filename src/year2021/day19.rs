@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, i64 as number, line_ending, multispace1, u32 as index},
+    combinator::verify,
+    multi::separated_list1,
+    sequence::{delimited, terminated, tuple},
+    IResult,
+};
+
+use crate::util::{
+    counter::Counter,
+    parse::complete,
+    point3::Point3,
+    rotation::{Rotation, Transform},
+};
+
+type Beacon = Point3;
+
+/// How many beacons two scanners must both see to be sure they overlap.
+const OVERLAP: usize = 12;
+
+/// The furthest a beacon can be from its scanner along any axis. The puzzle's are
+/// within 1000, and this keeps the squared distances between them well inside an
+/// `i64`.
+pub const MAX_COORDINATE: u64 = 1 << 20;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Scanner {
+    beacons: Vec<Beacon>,
+    /// The squared distance between every pair of beacons, which doesn't depend on
+    /// where the scanner is or which way it faces.
+    fingerprint: HashSet<i64>,
+}
+
+impl Scanner {
+    #[must_use]
+    pub fn new(beacons: Vec<Beacon>) -> Self {
+        let fingerprint = beacons
+            .iter()
+            .tuple_combinations()
+            .map(|(&a, &b)| {
+                let offset = a - b;
+                offset.dot(offset)
+            })
+            .collect();
+        Self {
+            beacons,
+            fingerprint,
+        }
+    }
+
+    /// Whether the two scanners share enough distances to possibly share
+    /// [`OVERLAP`] beacons. Cheap enough to rule out most pairs before aligning.
+    fn might_overlap(&self, other: &Self) -> bool {
+        let pairs = OVERLAP * (OVERLAP - 1) / 2;
+        self.fingerprint.intersection(&other.fingerprint).count() >= pairs
+    }
+
+    /// The transform taking `other`'s coordinates into this scanner's, if they see
+    /// at least [`OVERLAP`] beacons in common.
+    fn align(&self, other: &Self) -> Option<Transform> {
+        Rotation::all().into_iter().find_map(|rotation| {
+            //Every pairing of beacons votes for the offset that would line them up.
+            let mut votes = Counter::new();
+            for theirs in &other.beacons {
+                let rotated = rotation.apply(*theirs);
+                for ours in &self.beacons {
+                    votes.add(*ours - rotated);
+                }
+            }
+            votes
+                .into_iter()
+                .find(|&(_, count)| count >= OVERLAP)
+                .map(|(translation, _)| Transform {
+                    rotation,
+                    translation,
+                })
+        })
+    }
+}
+
+/// Where each scanner turned out to be, and every beacon, all relative to the
+/// first scanner.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Map {
+    pub scanners: Vec<Beacon>,
+    pub beacons: HashSet<Beacon>,
+}
+
+/// Aligns every scanner with the first, one overlapping neighbour at a time. Returns
+/// `None` if some scanner can't be linked up.
+#[must_use]
+pub fn assemble(scanners: &[Scanner]) -> Option<Map> {
+    let mut transforms: Vec<Option<Transform>> = vec![None; scanners.len()];
+    *transforms.first_mut()? = Some(Transform::IDENTITY);
+    let mut frontier = vec![0];
+    while let Some(aligned) = frontier.pop() {
+        for other in 0..scanners.len() {
+            if transforms[other].is_some() || !scanners[aligned].might_overlap(&scanners[other]) {
+                continue;
+            }
+            if let Some(transform) = scanners[aligned].align(&scanners[other]) {
+                let to_first = transforms[aligned]?;
+                transforms[other] = Some(transform.then(&to_first));
+                frontier.push(other);
+            }
+        }
+    }
+    let transforms: Vec<Transform> = transforms.into_iter().collect::<Option<_>>()?;
+    let beacons = scanners
+        .iter()
+        .zip(&transforms)
+        .flat_map(|(scanner, transform)| scanner.beacons.iter().map(|&b| transform.apply(b)))
+        .collect();
+    Some(Map {
+        scanners: transforms
+            .iter()
+            .map(|transform| transform.translation)
+            .collect(),
+        beacons,
+    })
+}
+
+fn beacon_parser(input: &str) -> IResult<&str, Beacon> {
+    let coordinate = || verify(number, |n: &i64| n.unsigned_abs() <= MAX_COORDINATE);
+    let (input, (x, _, y, _, z)) = tuple((
+        coordinate(),
+        char(','),
+        coordinate(),
+        char(','),
+        coordinate(),
+    ))(input)?;
+    Ok((input, Point3::new(x, y, z)))
+}
+
+fn scanner_parser(input: &str) -> IResult<&str, Scanner> {
+    let header = delimited(tag("--- scanner "), index, tag(" ---"));
+    let (input, beacons) = tuple((
+        terminated(header, line_ending),
+        separated_list1(line_ending, beacon_parser),
+    ))(input)?;
+    Ok((input, Scanner::new(beacons.1)))
+}
+
+/// Every scanner's report in `input`, or an error at the first line that is
+/// neither a `--- scanner N ---` heading nor a beacon's `x,y,z`, or at a beacon
+/// further than [`MAX_COORDINATE`] along some axis.
+pub fn scanners_parser(input: &str) -> Result<Vec<Scanner>> {
+    complete(input, separated_list1(multispace1, scanner_parser))
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<Scanner> {
+    match scanners_parser(input) {
+        Ok(scanners) => scanners,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[Scanner]) -> Option<usize> {
+    assemble(input).map(|map| map.beacons.len())
+}
+
+#[must_use]
+pub fn part2(input: &[Scanner]) -> Option<i64> {
+    let map = assemble(input)?;
+    map.scanners
+        .iter()
+        .tuple_combinations()
+        .map(|(&a, &b)| a.manhattan(b))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A field of beacons seen by scanners in a row along the x axis, each turned a
+    /// different way, written out as the puzzle would list them.
+    fn survey() -> (String, Vec<Beacon>, usize) {
+        let mut seed: i64 = 19;
+        let mut random = |range: i64| {
+            seed = (seed * 1_103_515_245 + 12_345) % 2_147_483_648;
+            seed % (2 * range + 1) - range
+        };
+        let positions: Vec<Beacon> = (0..5)
+            .map(|i| Point3::new(i * 1100, random(150), random(150)))
+            .collect();
+        let beacons: Vec<Beacon> = (0..400)
+            .map(|_| Point3::new(random(2700) + 2200, random(900), random(900)))
+            .collect();
+        let rotations = Rotation::all();
+        let mut seen = HashSet::new();
+        let input = positions
+            .iter()
+            .enumerate()
+            .map(|(index, position)| {
+                let facing = rotations[index * 5 % 24].inverse();
+                let lines: Vec<String> = beacons
+                    .iter()
+                    .filter(|&&beacon| {
+                        let offset = beacon - *position;
+                        [offset.x, offset.y, offset.z]
+                            .iter()
+                            .all(|d| d.abs() <= 1000)
+                    })
+                    .map(|beacon| {
+                        seen.insert(*beacon);
+                        let Point3 { x, y, z } = facing.apply(*beacon - *position);
+                        format!("{x},{y},{z}")
+                    })
+                    .collect();
+                format!("--- scanner {index} ---\n{}\n", lines.join("\n"))
+            })
+            .join("\n");
+        (input, positions, seen.len())
+    }
+
+    #[test]
+    fn test_scanner_parser() {
+        let scanners =
+            input_generator("--- scanner 0 ---\n0,2,0\n4,1,0\n\n--- scanner 1 ---\n-1,-1,1\n");
+        assert_eq!(scanners.len(), 2);
+        assert_eq!(
+            scanners[0].beacons,
+            vec![Point3::new(0, 2, 0), Point3::new(4, 1, 0)]
+        );
+        assert_eq!(scanners[0].fingerprint, HashSet::from([17]));
+        assert!(scanners_parser("--- scanner 0 ---\n0,0,4000000000").is_err());
+    }
+
+    #[test]
+    fn test_assemble() {
+        let (input, positions, beacons) = survey();
+        let map = assemble(&input_generator(&input)).unwrap();
+        assert_eq!(map.beacons.len(), beacons);
+        let first = positions[0];
+        let relative: Vec<Beacon> = positions.iter().map(|&p| p - first).collect();
+        assert_eq!(map.scanners, relative);
+    }
+
+    #[test]
+    fn test_parts() {
+        let (input, positions, beacons) = survey();
+        let scanners = input_generator(&input);
+        assert_eq!(part1(&scanners), Some(beacons));
+        let farthest = positions[4].manhattan(positions[0]);
+        assert_eq!(part2(&scanners), Some(farthest));
+        assert_eq!(part1(&scanners[..1]), Some(scanners[0].beacons.len()));
+    }
+}
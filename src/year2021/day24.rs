@@ -0,0 +1,396 @@
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{i64 as number, line_ending, one_of, space1},
+    combinator::{map, value},
+    multi::separated_list0,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+/// One of the ALU's four variables, `w`, `x`, `y` and `z`, as an index.
+type Variable = usize;
+
+const W: Variable = 0;
+const X: Variable = 1;
+const Y: Variable = 2;
+const Z: Variable = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operand {
+    Variable(Variable),
+    Value(i64),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Inp(Variable),
+    Binary(Operation, Variable, Operand),
+}
+
+/// Runs the ALU on `input`, returning the final variables, or `None` if it divides
+/// by zero, takes a bad modulus, overflows or runs out of input.
+#[must_use]
+pub fn run(program: &[Instruction], input: &[i64]) -> Option<[i64; 4]> {
+    let mut variables = [0; 4];
+    let mut input = input.iter();
+    for &instruction in program {
+        match instruction {
+            Instruction::Inp(target) => variables[target] = *input.next()?,
+            Instruction::Binary(operation, target, operand) => {
+                let a = variables[target];
+                let b = match operand {
+                    Operand::Variable(source) => variables[source],
+                    Operand::Value(value) => value,
+                };
+                variables[target] = match operation {
+                    Operation::Add => a.checked_add(b)?,
+                    Operation::Mul => a.checked_mul(b)?,
+                    Operation::Div => a.checked_div(b)?,
+                    Operation::Mod if a < 0 || b <= 0 => return None,
+                    Operation::Mod => a % b,
+                    Operation::Eql => i64::from(a == b),
+                };
+            }
+        }
+    }
+    Some(variables)
+}
+
+/// The three constants that set each of MONAD's fourteen digit blocks apart.
+///
+/// Every block treats `z` as a stack of base 26 digits. It peeks at the top,
+/// popping it if `pops`, and compares it plus `check` with the input digit. Only if
+/// they differ does it push the digit plus `offset`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Block {
+    pub pops: bool,
+    pub check: i64,
+    pub offset: i64,
+}
+
+impl Block {
+    /// Reads the constants from a block's instructions, which start with its `inp w`.
+    fn analyze(instructions: &[Instruction]) -> Result<Self> {
+        use Instruction::Binary;
+        use Operand::{Value, Variable as Var};
+        let constant = |pattern: &dyn Fn(&Instruction) -> Option<i64>, what: &str| {
+            instructions
+                .iter()
+                .find_map(pattern)
+                .ok_or_else(|| anyhow::anyhow!("block has no {what}"))
+        };
+        let divisor = constant(
+            &|i| match i {
+                Binary(Operation::Div, Z, Value(d)) => Some(*d),
+                _ => None,
+            },
+            "div z",
+        )?;
+        let check = constant(
+            &|i| match i {
+                Binary(Operation::Add, X, Value(c)) => Some(*c),
+                _ => None,
+            },
+            "add x",
+        )?;
+        //The offset is added to y straight after the digit is.
+        let offset = instructions
+            .windows(2)
+            .find_map(|pair| match pair {
+                [Binary(Operation::Add, Y, Var(W)), Binary(Operation::Add, Y, Value(o))] => {
+                    Some(*o)
+                }
+                _ => None,
+            })
+            .ok_or_else(|| anyhow::anyhow!("block has no offset"))?;
+        let pops = match divisor {
+            1 => false,
+            26 => true,
+            _ => return Err(anyhow::anyhow!("unexpected div z {divisor}")),
+        };
+        //A push block that could ever match its digit would only sometimes push.
+        if !pops && check < 10 {
+            return Err(anyhow::anyhow!("push block can match with check {check}"));
+        }
+        Ok(Self {
+            pops,
+            check,
+            offset,
+        })
+    }
+}
+
+/// Splits the program at each `inp` and reads off every block's constants.
+pub fn blocks(program: &[Instruction]) -> Result<Vec<Block>> {
+    let starts: Vec<usize> = program
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| matches!(instruction, Instruction::Inp(_)))
+        .map(|(index, _)| index)
+        .chain([program.len()])
+        .collect();
+    starts
+        .windows(2)
+        .map(|pair| Block::analyze(&program[pair[0]..pair[1]]))
+        .collect()
+}
+
+/// A rule the digits of a valid model number must follow:
+/// `digit[later] == digit[earlier] + difference`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Constraint {
+    pub earlier: usize,
+    pub later: usize,
+    pub difference: i64,
+}
+
+/// Pairs each popping block with the push it undoes. `z` ends at zero only if every
+/// pop matches its digit so nothing more is pushed, giving one constraint per pair.
+pub fn constraints(blocks: &[Block]) -> Result<Vec<Constraint>> {
+    let mut stack: Vec<(usize, i64)> = Vec::new();
+    let mut constraints = Vec::new();
+    for (index, block) in blocks.iter().enumerate() {
+        if block.pops {
+            let (earlier, offset) = stack
+                .pop()
+                .ok_or_else(|| anyhow::anyhow!("block {index} pops an empty stack"))?;
+            let difference = offset
+                .checked_add(block.check)
+                .ok_or_else(|| anyhow::anyhow!("block {index} overflows its difference"))?;
+            constraints.push(Constraint {
+                earlier,
+                later: index,
+                difference,
+            });
+        } else {
+            stack.push((index, block.offset));
+        }
+    }
+    if !stack.is_empty() {
+        return Err(anyhow::anyhow!("{} pushes are never popped", stack.len()));
+    }
+    Ok(constraints)
+}
+
+/// The digits of the largest or smallest model number meeting the constraints, if
+/// they can all be met with the digits 1 to 9.
+fn model_number(digits: usize, constraints: &[Constraint], largest: bool) -> Option<Vec<i64>> {
+    let mut number = vec![0; digits];
+    for constraint in constraints {
+        //Put the earlier digit as high (or low) as the difference allows.
+        let earlier = if largest {
+            9.min(9_i64.checked_sub(constraint.difference)?)
+        } else {
+            1.max(1_i64.checked_sub(constraint.difference)?)
+        };
+        let later = earlier.checked_add(constraint.difference)?;
+        if !(1..=9).contains(&earlier) || !(1..=9).contains(&later) {
+            return None;
+        }
+        number[constraint.earlier] = earlier;
+        number[constraint.later] = later;
+    }
+    Some(number)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Monad {
+    program: Vec<Instruction>,
+    constraints: Vec<Constraint>,
+    digits: usize,
+}
+
+impl Monad {
+    pub fn new(program: Vec<Instruction>) -> Result<Self> {
+        let blocks = blocks(&program)?;
+        Ok(Self {
+            constraints: constraints(&blocks)?,
+            digits: blocks.len(),
+            program,
+        })
+    }
+
+    /// Whether the ALU accepts the model number, ending with `z` at zero.
+    #[must_use]
+    pub fn accepts(&self, digits: &[i64]) -> bool {
+        run(&self.program, digits).is_some_and(|variables| variables[Z] == 0)
+    }
+
+    /// The largest or smallest model number MONAD accepts, double checked on the ALU.
+    #[must_use]
+    pub fn model_number(&self, largest: bool) -> Option<u64> {
+        let digits = model_number(self.digits, &self.constraints, largest)?;
+        if !self.accepts(&digits) {
+            return None;
+        }
+        digits.iter().try_fold(0_u64, |number, &digit| {
+            number.checked_mul(10)?.checked_add(digit as u64)
+        })
+    }
+}
+
+fn variable_parser(input: &str) -> IResult<&str, Variable> {
+    map(one_of("wxyz"), |variable| variable as usize - 'w' as usize)(input)
+}
+
+fn operand_parser(input: &str) -> IResult<&str, Operand> {
+    alt((
+        map(variable_parser, Operand::Variable),
+        map(number, Operand::Value),
+    ))(input)
+}
+
+fn operation_parser(input: &str) -> IResult<&str, Operation> {
+    alt((
+        value(Operation::Add, tag("add")),
+        value(Operation::Mul, tag("mul")),
+        value(Operation::Div, tag("div")),
+        value(Operation::Mod, tag("mod")),
+        value(Operation::Eql, tag("eql")),
+    ))(input)
+}
+
+fn instruction_parser(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        map(preceded(tag("inp "), variable_parser), Instruction::Inp),
+        map(
+            separated_pair(
+                operation_parser,
+                space1,
+                separated_pair(variable_parser, space1, operand_parser),
+            ),
+            |(operation, (target, operand))| Instruction::Binary(operation, target, operand),
+        ),
+    ))(input)
+}
+
+/// Every ALU instruction in `input`, or an error at the first line that isn't an
+/// `inp` or an arithmetic instruction storing into `w`, `x`, `y` or `z`.
+fn program_parser(input: &str) -> Result<Vec<Instruction>> {
+    complete(input, separated_list0(line_ending, instruction_parser))
+}
+
+/// The MONAD program in `input`, or an error if a block strays from the push and
+/// pop pattern the constraints are read from, or the pushes and pops don't pair up.
+pub fn monad_parser(input: &str) -> Result<Monad> {
+    Monad::new(program_parser(input)?)
+}
+
+pub(super) fn input_generator(input: &str) -> Monad {
+    match monad_parser(input) {
+        Ok(monad) => monad,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &Monad) -> Option<u64> {
+    input.model_number(true)
+}
+
+#[must_use]
+pub fn part2(input: &Monad) -> Option<u64> {
+    input.model_number(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes out a MONAD program with the given `(divisor, check, offset)` blocks.
+    fn monad(blocks: &[(i64, i64, i64)]) -> String {
+        blocks
+            .iter()
+            .map(|(divisor, check, offset)| {
+                format!(
+                    "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {divisor}\nadd x {check}\n\
+                     eql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\n\
+                     mul y 0\nadd y w\nadd y {offset}\nmul y x\nadd z y\n"
+                )
+            })
+            .collect()
+    }
+
+    const BLOCKS: [(i64, i64, i64); 14] = [
+        (1, 12, 4),
+        (1, 11, 11),
+        (1, 13, 5),
+        (26, -6, 7),
+        (26, -13, 3),
+        (1, 15, 14),
+        (26, -7, 2),
+        (1, 10, 8),
+        (1, 14, 1),
+        (26, -3, 9),
+        (26, -9, 6),
+        (1, 11, 0),
+        (26, -2, 12),
+        (26, -10, 4),
+    ];
+
+    #[test]
+    fn test_run() {
+        let program = program_parser("inp z\ninp x\nmul z 3\neql z x").unwrap();
+        assert_eq!(run(&program, &[2, 6]), Some([0, 6, 0, 1]));
+        assert_eq!(run(&program, &[2, 7]), Some([0, 7, 0, 0]));
+        assert_eq!(run(&program, &[2]), None);
+        let program = program_parser("inp w\nadd z w\nmod z 2\ndiv w 2").unwrap();
+        assert_eq!(run(&program, &[13]), Some([6, 0, 0, 1]));
+        let program = program_parser("inp w\nmul w w\nmul w w").unwrap();
+        assert_eq!(run(&program, &[1 << 20]), None);
+    }
+
+    #[test]
+    fn test_constraints() {
+        let blocks = blocks(&program_parser(&monad(&BLOCKS)).unwrap()).unwrap();
+        assert_eq!(
+            blocks[3],
+            Block {
+                pops: true,
+                check: -6,
+                offset: 7
+            }
+        );
+        let constraints = constraints(&blocks).unwrap();
+        assert_eq!(constraints.len(), 7);
+        assert_eq!(
+            constraints[0],
+            Constraint {
+                earlier: 2,
+                later: 3,
+                difference: -1
+            }
+        );
+        assert!(super::constraints(&blocks[..3]).is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        let monad = input_generator(&monad(&BLOCKS));
+        assert_eq!(part1(&monad), Some(99_987_299_978_973));
+    }
+
+    #[test]
+    fn test_part2() {
+        let monad = input_generator(&monad(&BLOCKS));
+        assert_eq!(part2(&monad), Some(73_211_182_311_311));
+        //Nudging any single digit breaks a constraint.
+        let mut digits = vec![7, 3, 2, 1, 1, 1, 8, 2, 3, 1, 1, 3, 1, 1];
+        assert!(monad.accepts(&digits));
+        digits[13] = 2;
+        assert!(!monad.accepts(&digits));
+    }
+}
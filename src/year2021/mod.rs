@@ -0,0 +1,15 @@
+pub mod day19;
+pub mod day24;
+
+use crate::{runner::Solution, solution};
+
+/// Every solution for 2021.
+#[must_use]
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2021, 19, 1, day19::input_generator => day19::part1),
+        solution!(2021, 19, 2, day19::input_generator => day19::part2),
+        solution!(2021, 24, 1, day24::input_generator => day24::part1),
+        solution!(2021, 24, 2, day24::input_generator => day24::part2),
+    ]
+}
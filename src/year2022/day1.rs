@@ -0,0 +1,100 @@
+use anyhow::Result;
+use nom::{
+    character::complete::{line_ending, u32 as number},
+    multi::{count, separated_list0, separated_list1},
+    IResult,
+};
+
+use crate::{prelude::*, util::parse::complete};
+
+fn elf_parser(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(line_ending, number)(input)
+}
+
+fn elves_parser(input: &str) -> Result<Vec<Vec<u32>>> {
+    complete(input, separated_list0(count(line_ending, 2), elf_parser))
+}
+
+/// Each elf's total calories in `input`, most first, or an error at a line that
+/// isn't a number, or at an elf whose total doesn't fit in a `u32`.
+pub fn calories_parser(input: &str) -> Result<Vec<u32>> {
+    let mut totals = elves_parser(input)?
+        .iter()
+        .enumerate()
+        .map(|(index, elf)| {
+            elf.iter()
+                .copied()
+                .checked_sum()
+                .ok_or_else(|| anyhow::anyhow!("elf {} carries too many calories", index + 1))
+        })
+        .collect::<Result<Vec<u32>>>()?;
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(totals)
+}
+
+/// Each elf's total calories, most first.
+pub(super) fn input_generator(input: &str) -> Vec<u32> {
+    match calories_parser(input) {
+        Ok(totals) => totals,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[u32]) -> Option<u32> {
+    input.first().copied()
+}
+
+#[must_use]
+pub fn part2(input: &[u32]) -> Option<u32> {
+    input.iter().take(3).copied().checked_sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn test_input_generator() {
+        assert_eq!(
+            input_generator(EXAMPLE),
+            vec![24000, 11000, 10000, 6000, 4000]
+        );
+    }
+
+    #[test]
+    fn test_bad_elf() {
+        let error = elves_parser("1000\n\n2x00\n\n3000")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("line 3, column 2"), "{error}");
+        assert!(calories_parser("4000000000\n4000000000").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(24000));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(45000));
+        assert_eq!(part2(&input_generator("4294967295\n\n1")), None);
+    }
+}
@@ -0,0 +1,124 @@
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{i64 as number, line_ending},
+    combinator::{map, value},
+    multi::separated_list0,
+    sequence::preceded,
+    IResult,
+};
+
+use crate::util::{grid::Grid, ocr, parse::complete};
+
+const WIDTH: usize = 40;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+/// The value of the X register during each cycle, starting with the first, or
+/// `None` if it overflows.
+fn register_values(program: &[Instruction]) -> Option<Vec<i64>> {
+    let mut x: i64 = 1;
+    let mut values = Vec::with_capacity(2 * program.len());
+    for instruction in program {
+        match instruction {
+            Instruction::Noop => values.push(x),
+            //The addition takes two cycles, and only lands after both.
+            Instruction::Addx(amount) => {
+                values.extend([x, x]);
+                x = x.checked_add(*amount)?;
+            }
+        }
+    }
+    Some(values)
+}
+
+fn instruction_parser(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        value(Instruction::Noop, tag("noop")),
+        map(preceded(tag("addx "), number), Instruction::Addx),
+    ))(input)
+}
+
+/// The X register during each cycle of the program in `input`, or an error at the
+/// first line that is neither `noop` nor `addx` and a number, or if the register
+/// overflows.
+pub fn register_parser(input: &str) -> Result<Vec<i64>> {
+    let program = complete(input, separated_list0(line_ending, instruction_parser))?;
+    register_values(&program).ok_or_else(|| anyhow::anyhow!("the X register overflows"))
+}
+
+/// The X register during each cycle.
+pub(super) fn input_generator(input: &str) -> Vec<i64> {
+    match register_parser(input) {
+        Ok(values) => values,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[i64]) -> Option<i64> {
+    //The signal strength during the 20th cycle and every 40th after that.
+    input
+        .iter()
+        .zip(1..)
+        .skip(19)
+        .step_by(WIDTH)
+        .try_fold(0_i64, |total, (x, cycle)| {
+            total.checked_add(x.checked_mul(cycle)?)
+        })
+}
+
+#[must_use]
+pub fn part2(input: &[i64]) -> String {
+    //Each cycle draws the next pixel, which is lit if the three pixel wide sprite
+    //  centred on X covers it.
+    let rows = input
+        .chunks(WIDTH)
+        .map(|row| {
+            let mut pixels: Vec<bool> = row
+                .iter()
+                .zip(0..)
+                .map(|(&x, col): (&i64, i64)| (x - col).abs() <= 1)
+                .collect();
+            pixels.resize(WIDTH, false);
+            pixels
+        })
+        .collect();
+    let screen = Grid::from_rows(rows).expect("every row is padded to the same width");
+    //Fall back on the picture itself if it doesn't spell anything.
+    ocr::read(&screen).unwrap_or_else(|_| format!("\n{}", ocr::render(&screen)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_values() {
+        let values = input_generator("noop\naddx 3\naddx -5");
+        assert_eq!(values, vec![1, 1, 1, 4, 4]);
+        assert!(register_parser(&format!("addx {}\naddx 1", i64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        //X climbs by one every two cycles, so it is 10 during cycle 20, 30 during
+        //  cycle 60 and 50 during cycle 100.
+        let values = input_generator(&"addx 1\n".repeat(50));
+        assert_eq!(part1(&values), Some(20 * 10 + 60 * 30 + 100 * 50));
+        let values = input_generator(&format!("addx {}\n{}", i64::MAX / 2, "noop\n".repeat(20)));
+        assert_eq!(part1(&values), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        let values = input_generator(&"noop\n".repeat(2 * WIDTH));
+        let row = format!("###{}", ".".repeat(WIDTH - 3));
+        assert_eq!(part2(&values), format!("\n{row}\n{row}"));
+    }
+}
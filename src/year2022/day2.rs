@@ -0,0 +1,146 @@
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    character::complete::{char, line_ending},
+    combinator::value,
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    const fn score(self) -> u32 {
+        self as u32 + 1
+    }
+
+    /// The shape this one beats.
+    const fn beats(self) -> Self {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+
+    /// The shape that beats this one.
+    const fn beaten_by(self) -> Self {
+        self.beats().beats()
+    }
+}
+
+/// The second column of the strategy guide, which is read differently by each part.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+/// The score for one round, playing `mine` against `theirs`.
+fn round_score(theirs: Shape, mine: Shape) -> u32 {
+    let outcome = if mine == theirs {
+        3
+    } else if mine.beats() == theirs {
+        6
+    } else {
+        0
+    };
+    mine.score() + outcome
+}
+
+fn round_parser(input: &str) -> IResult<&str, (Shape, Column)> {
+    separated_pair(
+        alt((
+            value(Shape::Rock, char('A')),
+            value(Shape::Paper, char('B')),
+            value(Shape::Scissors, char('C')),
+        )),
+        char(' '),
+        alt((
+            value(Column::X, char('X')),
+            value(Column::Y, char('Y')),
+            value(Column::Z, char('Z')),
+        )),
+    )(input)
+}
+
+/// Every round of the strategy guide in `input`, or an error at the first line that
+/// isn't `A`, `B` or `C`, a space, and `X`, `Y` or `Z`.
+pub fn guide_parser(input: &str) -> Result<Vec<(Shape, Column)>> {
+    complete(input, separated_list0(line_ending, round_parser))
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<(Shape, Column)> {
+    match guide_parser(input) {
+        Ok(rounds) => rounds,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[(Shape, Column)]) -> u32 {
+    //The second column is the shape to play.
+    input
+        .iter()
+        .map(|&(theirs, column)| {
+            let mine = match column {
+                Column::X => Shape::Rock,
+                Column::Y => Shape::Paper,
+                Column::Z => Shape::Scissors,
+            };
+            round_score(theirs, mine)
+        })
+        .sum()
+}
+
+#[must_use]
+pub fn part2(input: &[(Shape, Column)]) -> u32 {
+    //The second column is how the round needs to end.
+    input
+        .iter()
+        .map(|&(theirs, column)| {
+            let mine = match column {
+                Column::X => theirs.beats(),
+                Column::Y => theirs,
+                Column::Z => theirs.beaten_by(),
+            };
+            round_score(theirs, mine)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "A Y
+B X
+C Z
+";
+
+    #[test]
+    fn test_round_score() {
+        assert_eq!(round_score(Shape::Rock, Shape::Paper), 8);
+        assert_eq!(round_score(Shape::Paper, Shape::Rock), 1);
+        assert_eq!(round_score(Shape::Scissors, Shape::Scissors), 6);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 12);
+    }
+}
@@ -0,0 +1,406 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{Context, Result};
+use nom::{
+    branch::alt,
+    character::complete::{char, u32 as number},
+    combinator::{map, value},
+    multi::many0,
+    IResult,
+};
+
+use crate::util::{
+    direction::Direction, grid::Grid, parse::complete, point::Point, point3::Point3,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Move {
+    Forward(u32),
+    Left,
+    Right,
+}
+
+/// Each facing, in the order the password scores them: right, down, left, up.
+const FACINGS: [Direction; 4] = [
+    Direction::East,
+    Direction::South,
+    Direction::West,
+    Direction::North,
+];
+
+/// A position on the board and the facing.
+type State = (Point, Direction);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Board {
+    tiles: Grid<u8>,
+}
+
+impl Board {
+    /// The board with `rows` padded with spaces to the width of the longest.
+    fn from_rows(mut rows: Vec<Vec<u8>>) -> Result<Self> {
+        let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+        for row in &mut rows {
+            row.resize(width, b' ');
+        }
+        Ok(Self {
+            tiles: Grid::from_rows(rows)?,
+        })
+    }
+
+    /// The tile at `position`, with everything off the map a space.
+    fn tile(&self, position: Point) -> u8 {
+        self.tiles.get(position).copied().unwrap_or(b' ')
+    }
+
+    fn start(&self) -> Option<State> {
+        let x = self
+            .tiles
+            .rows()
+            .next()?
+            .iter()
+            .position(|&tile| tile == b'.')?;
+        Some((Point::from((x, 0)), Direction::East))
+    }
+
+    /// Wraps around to the far side of the map, as if it were flat.
+    fn wrap_flat(&self, (mut position, facing): State) -> State {
+        let step = facing.offset();
+        while self.tile(position - step) != b' ' {
+            position -= step;
+        }
+        (position, facing)
+    }
+
+    /// Follows the path from the start, using `wrap` to find where each step off
+    /// the edge of the map leads, and returns the final password.
+    fn walk(&self, path: &[Move], wrap: impl Fn(State) -> State) -> Option<i64> {
+        let (mut position, mut facing) = self.start()?;
+        for &step in path {
+            match step {
+                Move::Left => facing = facing.turn_left(),
+                Move::Right => facing = facing.turn_right(),
+                Move::Forward(count) => {
+                    let start = (position, facing);
+                    let mut left = count;
+                    while left > 0 {
+                        let mut next = (position + facing.offset(), facing);
+                        if self.tile(next.0) == b' ' {
+                            next = wrap((position, facing));
+                        }
+                        if self.tile(next.0) == b'#' {
+                            break;
+                        }
+                        (position, facing) = next;
+                        left -= 1;
+                        //Every step can be retraced, so a walk that meets no wall
+                        //  comes back round to where it began; skip the whole laps.
+                        if (position, facing) == start {
+                            left %= count - left;
+                        }
+                    }
+                }
+            }
+        }
+        let score = FACINGS.iter().position(|&other| other == facing)?;
+        Some(1000 * (position.y + 1) + 4 * (position.x + 1) + score as i64)
+    }
+}
+
+/// A face of the cube: where it sits in the net, and which way its outward normal
+/// and its net's columns and rows point once folded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Face {
+    origin: Point,
+    normal: Point3,
+    right: Point3,
+    down: Point3,
+}
+
+impl Face {
+    /// The direction `facing` points on this face, once folded.
+    fn heading(&self, facing: Direction) -> Point3 {
+        match facing {
+            Direction::East => self.right,
+            Direction::South => self.down,
+            Direction::West => -self.right,
+            _ => -self.down,
+        }
+    }
+
+    /// The face next to this one in the net in direction `facing`, folded over
+    /// the shared edge.
+    fn neighbor(&self, facing: Direction) -> Self {
+        let Self {
+            normal,
+            right,
+            down,
+            ..
+        } = *self;
+        let (normal, right, down) = match facing {
+            Direction::East => (right, -normal, down),
+            Direction::South => (down, right, -normal),
+            Direction::West => (-right, normal, down),
+            _ => (-down, right, normal),
+        };
+        Self {
+            origin: self.origin + facing.offset(),
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+/// The map folded up into a cube, with the seams between faces worked out from
+/// the layout of the net.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cube {
+    size: i64,
+    /// Keyed by position in the net, measured in faces.
+    faces: HashMap<Point, Face>,
+}
+
+impl Cube {
+    /// Folds any of the eleven nets of a cube, in any orientation.
+    pub fn fold(board: &Board) -> Result<Self> {
+        let area = board
+            .tiles
+            .rows()
+            .flatten()
+            .filter(|&&tile| tile != b' ')
+            .count();
+        let size = (area / 6).isqrt();
+        if size == 0 || 6 * size * size != area {
+            return Err(anyhow::anyhow!("{area} tiles can't make a cube"));
+        }
+        let size = size as i64;
+        let is_face = |face: Point| board.tile(face * size) != b' ';
+        let start = board
+            .start()
+            .ok_or_else(|| anyhow::anyhow!("the map has no open tile on its top row"))?;
+        //Lay the first face flat facing up, then roll the cube across the net.
+        let first = Face {
+            origin: Point::new(start.0.x / size, 0),
+            normal: Point3::new(0, 0, 1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        };
+        let mut faces = HashMap::from([(first.origin, first)]);
+        let mut queue = VecDeque::from([first]);
+        while let Some(face) = queue.pop_front() {
+            for facing in Direction::ORTHOGONAL {
+                let next = face.neighbor(facing);
+                if is_face(next.origin) && !faces.contains_key(&next.origin) {
+                    faces.insert(next.origin, next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        let mut normals: Vec<_> = faces.values().map(|face| face.normal).collect();
+        normals.sort_unstable();
+        normals.dedup();
+        if faces.len() != 6 || normals.len() != 6 {
+            return Err(anyhow::anyhow!("the map doesn't fold into a cube"));
+        }
+        Ok(Self { size, faces })
+    }
+
+    /// Carries a step off the edge of a face over the seam onto the next face.
+    ///
+    /// With the cube spanning `-size..=size` on each axis in half-tiles, a tile's
+    /// centre is `size` out along its face's normal. Crossing the seam swaps the
+    /// roles of the old normal and the direction of travel.
+    fn wrap(&self, (position, facing): State) -> State {
+        let n = self.size;
+        let face = self.faces[&Point::new(position.x.div_euclid(n), position.y.div_euclid(n))];
+        let (r, c) = (position.y.rem_euclid(n), position.x.rem_euclid(n));
+        let heading = face.heading(facing);
+        let target = self
+            .faces
+            .values()
+            .find(|other| other.normal == heading)
+            .expect("every direction has a face");
+        let centre = face.normal * (n - 1)
+            + face.right * (2 * c + 1 - n)
+            + face.down * (2 * r + 1 - n)
+            + heading;
+        let r = (centre.dot(target.down) + n - 1) / 2;
+        let c = (centre.dot(target.right) + n - 1) / 2;
+        let facing = Direction::ORTHOGONAL
+            .into_iter()
+            .find(|&facing| target.heading(facing) == -face.normal)
+            .expect("the old normal lies in the new face");
+        (target.origin * n + Point::new(c, r), facing)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Notes {
+    board: Board,
+    path: Vec<Move>,
+}
+
+fn path_parser(input: &str) -> IResult<&str, Vec<Move>> {
+    many0(alt((
+        map(number, Move::Forward),
+        value(Move::Left, char('L')),
+        value(Move::Right, char('R')),
+    )))(input)
+}
+
+/// The board and the path in `input`, or an error if there is no blank line before
+/// the path, the map has a tile other than ` `, `.` or `#`, or the path isn't
+/// step counts and `L` and `R` turns.
+pub fn notes_parser(input: &str) -> Result<Notes> {
+    let input = input.replace("\r\n", "\n");
+    let (map, path) = input
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow::anyhow!("no blank line before the path"))?;
+    let path = complete(path.trim(), path_parser).context("reading the path")?;
+    let rows = map
+        .lines()
+        .map(
+            |line| match line.bytes().find(|tile| !b" .#".contains(tile)) {
+                Some(tile) => Err(anyhow::anyhow!("unexpected {:?} in the map", tile as char)),
+                None => Ok(line.as_bytes().to_vec()),
+            },
+        )
+        .collect::<Result<_>>()?;
+    Ok(Notes {
+        board: Board::from_rows(rows)?,
+        path,
+    })
+}
+
+pub(super) fn input_generator(input: &str) -> Notes {
+    match notes_parser(input) {
+        Ok(notes) => notes,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &Notes) -> Option<i64> {
+    input
+        .board
+        .walk(&input.path, |state| input.board.wrap_flat(state))
+}
+
+#[must_use]
+pub fn part2(input: &Notes) -> Option<i64> {
+    let cube = Cube::fold(&input.board).ok()?;
+    input.board.walk(&input.path, |state| cube.wrap(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+";
+
+    /// An open board with faces of `size` at the positions marked `#` in `net`.
+    fn open_board(net: &str, size: usize) -> Board {
+        let rows = net
+            .lines()
+            .flat_map(|line| {
+                let row: Vec<u8> = line
+                    .bytes()
+                    .flat_map(|face| vec![if face == b'#' { b'.' } else { b' ' }; size])
+                    .collect();
+                vec![row; size]
+            })
+            .collect();
+        Board::from_rows(rows).unwrap()
+    }
+
+    #[test]
+    fn test_path_parser() {
+        assert_eq!(
+            path_parser("10R5L"),
+            Ok((
+                "",
+                vec![Move::Forward(10), Move::Right, Move::Forward(5), Move::Left]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        let notes = input_generator(EXAMPLE);
+        let cube = Cube::fold(&notes.board).unwrap();
+        //The examples from the puzzle: A to B, and C to D.
+        assert_eq!(
+            cube.wrap((Point::new(11, 5), Direction::East)),
+            (Point::new(14, 8), Direction::South)
+        );
+        assert_eq!(
+            cube.wrap((Point::new(10, 11), Direction::South)),
+            (Point::new(1, 7), Direction::North)
+        );
+    }
+
+    #[test]
+    fn test_fold() {
+        let nets = [
+            //The example's net, the shape real inputs use, and a cross on its side.
+            "  #\n###\n  ##",
+            " ##\n #\n##\n#",
+            " #\n####\n #",
+            "##\n #\n ##\n  #",
+        ];
+        for net in nets {
+            let board = open_board(net, 3);
+            let cube = Cube::fold(&board).unwrap();
+            //Walking four faces straight on from anywhere comes back round to the start.
+            for &origin in cube.faces.keys() {
+                for (r, c, facing) in [
+                    (0, 0, Direction::East),
+                    (1, 2, Direction::South),
+                    (2, 1, Direction::West),
+                    (2, 2, Direction::North),
+                ] {
+                    let start = (origin * 3 + Point::new(c, r), facing);
+                    let mut state = start;
+                    for _ in 0..12 {
+                        let next = (state.0 + state.1.offset(), state.1);
+                        state = if board.tile(next.0) == b' ' {
+                            cube.wrap(state)
+                        } else {
+                            next
+                        };
+                    }
+                    assert_eq!(state, start, "{net:?}");
+                }
+            }
+        }
+        assert!(Cube::fold(&open_board("####\n####", 2)).is_err());
+        assert!(Cube::fold(&open_board("###\n###", 2)).is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(6032));
+        //Four billion laps of an open row, taken in one go.
+        assert_eq!(part1(&input_generator("....\n\n4000000001")), Some(1008));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(5031));
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+
+/// A set of item types, one bit per priority.
+type Items = u64;
+
+fn priority(item: u8) -> Option<u32> {
+    match item {
+        b'a'..=b'z' => Some(u32::from(item - b'a') + 1),
+        b'A'..=b'Z' => Some(u32::from(item - b'A') + 27),
+        _ => None,
+    }
+}
+
+fn items(contents: &[u8]) -> Items {
+    contents
+        .iter()
+        .filter_map(|&item| priority(item))
+        .fold(0, |set, priority| set | 1 << priority)
+}
+
+/// The priority of the only item type in every one of `sets`.
+fn common_priority(sets: impl Iterator<Item = Items>) -> Option<u32> {
+    let common = sets.reduce(|a, b| a & b)?;
+    (common.count_ones() == 1).then(|| common.trailing_zeros())
+}
+
+/// The contents of every rucksack in `input`, or an error saying which line isn't one.
+pub fn rucksacks_parser(input: &str) -> Result<Vec<Vec<u8>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line, contents)| {
+            if let Some(item) = contents.bytes().find(|&item| priority(item).is_none()) {
+                return Err(anyhow::anyhow!(
+                    "unexpected {:?} on line {}",
+                    char::from(item),
+                    line + 1
+                ));
+            }
+            if contents.len() % 2 != 0 {
+                return Err(anyhow::anyhow!("line {} can't be split evenly", line + 1));
+            }
+            Ok(contents.as_bytes().to_vec())
+        })
+        .collect()
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<Vec<u8>> {
+    match rucksacks_parser(input) {
+        Ok(rucksacks) => rucksacks,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[Vec<u8>]) -> Option<u32> {
+    input
+        .iter()
+        .map(|rucksack| {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            common_priority([items(first), items(second)].into_iter())
+        })
+        .sum()
+}
+
+#[must_use]
+pub fn part2(input: &[Vec<u8>]) -> Option<u32> {
+    //Each group of three elves shares exactly one badge.
+    input
+        .chunks(3)
+        .map(|group| common_priority(group.iter().map(|rucksack| items(rucksack))))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(priority(b'p'), Some(16));
+        assert_eq!(priority(b'L'), Some(38));
+        assert_eq!(priority(b'!'), None);
+    }
+
+    #[test]
+    fn test_rucksacks_parser() {
+        assert!(rucksacks_parser("abc").is_err());
+        assert!(rucksacks_parser("a1").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(157));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(70));
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use nom::{
+    character::complete::{char, line_ending, u32 as number},
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+/// An inclusive range of section IDs.
+type Sections = (u32, u32);
+
+fn contains((start, end): Sections, (other_start, other_end): Sections) -> bool {
+    start <= other_start && other_end <= end
+}
+
+fn overlaps((start, end): Sections, (other_start, other_end): Sections) -> bool {
+    start <= other_end && other_start <= end
+}
+
+fn sections_parser(input: &str) -> IResult<&str, Sections> {
+    separated_pair(number, char('-'), number)(input)
+}
+
+/// Every pair of section assignments in `input`, or an error at the first line
+/// that isn't two `start-end` ranges of IDs that fit in a `u32`.
+pub fn pairs_parser(input: &str) -> Result<Vec<(Sections, Sections)>> {
+    let pair_parser = separated_pair(sections_parser, char(','), sections_parser);
+    complete(input, separated_list0(line_ending, pair_parser))
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<(Sections, Sections)> {
+    match pairs_parser(input) {
+        Ok(pairs) => pairs,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[(Sections, Sections)]) -> usize {
+    input
+        .iter()
+        .filter(|&&(a, b)| contains(a, b) || contains(b, a))
+        .count()
+}
+
+#[must_use]
+pub fn part2(input: &[(Sections, Sections)]) -> usize {
+    input.iter().filter(|&&(a, b)| overlaps(a, b)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn test_pairs_parser() {
+        assert_eq!(pairs_parser("2-4,6-8").unwrap(), vec![((2, 4), (6, 8))]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 4);
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::{Context, Result};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, u32 as number},
+    combinator::map_opt,
+    multi::separated_list0,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Procedure {
+    /// Each stack of crates, bottom first.
+    stacks: Vec<Vec<u8>>,
+    moves: Vec<Move>,
+}
+
+impl Procedure {
+    /// The crates on top of each stack once every move is done, moving them one at
+    /// a time or, with `all_at_once`, several together.
+    #[must_use]
+    pub fn rearrange(&self, all_at_once: bool) -> String {
+        let mut stacks = self.stacks.clone();
+        for step in &self.moves {
+            let from = &mut stacks[step.from];
+            let mut lifted = from.split_off(from.len() - step.count);
+            if !all_at_once {
+                lifted.reverse();
+            }
+            stacks[step.to].extend(lifted);
+        }
+        stacks
+            .iter()
+            .filter_map(|stack| stack.last().map(|&label| char::from(label)))
+            .collect()
+    }
+}
+
+/// Reads the drawing of the stacks, where each crate is drawn as `[X]` four
+/// columns apart and the bottom line numbers the stacks.
+fn drawing_parser(drawing: &str) -> Result<Vec<Vec<u8>>> {
+    let mut lines: Vec<&str> = drawing.lines().collect();
+    let numbers = lines
+        .pop()
+        .ok_or_else(|| anyhow::anyhow!("missing drawing"))?;
+    let count = numbers.split_whitespace().count();
+    let mut stacks = vec![Vec::new(); count];
+    for line in lines.iter().rev() {
+        for (index, stack) in stacks.iter_mut().enumerate() {
+            match line.as_bytes().get(4 * index + 1) {
+                Some(label) if label.is_ascii_alphabetic() => stack.push(*label),
+                Some(b' ') | None => {}
+                Some(&other) => {
+                    return Err(anyhow::anyhow!(
+                        "unexpected {:?} in drawing",
+                        char::from(other)
+                    ))
+                }
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+fn move_parser(input: &str) -> IResult<&str, Move> {
+    //Stacks are numbered from 1, so there's no stack 0 to move to or from.
+    map_opt(
+        tuple((
+            preceded(tag("move "), number),
+            preceded(tag(" from "), number),
+            preceded(tag(" to "), number),
+        )),
+        |(count, from, to)| {
+            Some(Move {
+                count: count as usize,
+                from: (from as usize).checked_sub(1)?,
+                to: (to as usize).checked_sub(1)?,
+            })
+        },
+    )(input)
+}
+
+/// The drawing of the stacks and the moves in `input`, or an error at a crate in
+/// the drawing that isn't a letter, at a line that isn't a move, or at the first
+/// move that names a missing stack or takes more crates than its stack holds.
+pub fn procedure_parser(input: &str) -> Result<Procedure> {
+    let (drawing, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow::anyhow!("expected a drawing then moves"))?;
+    let stacks = drawing_parser(drawing)?;
+    let moves =
+        complete(moves, separated_list0(line_ending, move_parser)).context("reading the moves")?;
+
+    //Check every move can actually be carried out.
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for (index, step) in moves.iter().enumerate() {
+        if step.from >= heights.len() || step.to >= heights.len() {
+            return Err(anyhow::anyhow!("move {} names a missing stack", index + 1));
+        }
+        if heights[step.from] < step.count {
+            return Err(anyhow::anyhow!("move {} empties its stack", index + 1));
+        }
+        heights[step.from] -= step.count;
+        heights[step.to] += step.count;
+    }
+    Ok(Procedure { stacks, moves })
+}
+
+pub(super) fn input_generator(input: &str) -> Procedure {
+    match procedure_parser(input) {
+        Ok(procedure) => procedure,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &Procedure) -> String {
+    input.rearrange(false)
+}
+
+#[must_use]
+pub fn part2(input: &Procedure) -> String {
+    //The CrateMover 9001 picks up several crates at once.
+    input.rearrange(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn test_drawing_parser() {
+        let procedure = input_generator(EXAMPLE);
+        assert_eq!(
+            procedure.stacks,
+            vec![b"ZN".to_vec(), b"MCD".to_vec(), b"P".to_vec()]
+        );
+        assert_eq!(
+            procedure.moves[0],
+            Move {
+                count: 1,
+                from: 1,
+                to: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_impossible_move() {
+        assert!(procedure_parser("[A]\n 1 \n\nmove 2 from 1 to 1").is_err());
+        assert!(procedure_parser("[A]\n 1 \n\nmove 1 from 1 to 2").is_err());
+        assert!(procedure_parser("[A]\n 1 \n\nmove 1 from 0 to 1").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), "CMZ");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), "MCD");
+    }
+}
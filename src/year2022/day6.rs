@@ -0,0 +1,69 @@
+/// The number of characters read by the end of the first run of `length`
+/// characters that are all different.
+#[must_use]
+pub fn marker_end(signal: &[u8], length: usize) -> Option<usize> {
+    //Slide a window along, counting how many of each letter are in it and how many
+    //  letters appear more than once.
+    let mut counts = [0_usize; 256];
+    let mut repeated = 0;
+    for (index, &c) in signal.iter().enumerate() {
+        counts[usize::from(c)] += 1;
+        if counts[usize::from(c)] == 2 {
+            repeated += 1;
+        }
+        if index >= length {
+            let dropped = usize::from(signal[index - length]);
+            counts[dropped] -= 1;
+            if counts[dropped] == 1 {
+                repeated -= 1;
+            }
+        }
+        if index + 1 >= length && repeated == 0 {
+            return Some(index + 1);
+        }
+    }
+    None
+}
+
+#[must_use]
+pub fn part1(input: &str) -> Option<usize> {
+    marker_end(input.trim().as_bytes(), 4)
+}
+
+#[must_use]
+pub fn part2(input: &str) -> Option<usize> {
+    marker_end(input.trim().as_bytes(), 14)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn test_marker_end() {
+        assert_eq!(marker_end(b"aaaa", 4), None);
+        assert_eq!(marker_end(b"abcd", 4), Some(4));
+    }
+
+    #[test]
+    fn test_part1() {
+        for (signal, expected, _) in EXAMPLES {
+            assert_eq!(part1(signal), Some(expected), "{signal}");
+        }
+    }
+
+    #[test]
+    fn test_part2() {
+        for (signal, _, expected) in EXAMPLES {
+            assert_eq!(part2(signal), Some(expected), "{signal}");
+        }
+    }
+}
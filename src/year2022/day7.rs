@@ -0,0 +1,165 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, line_ending, u64 as number},
+    combinator::{map, value},
+    multi::separated_list0,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Line<'a> {
+    Root,
+    Up,
+    Down(&'a str),
+    List,
+    Directory(&'a str),
+    File(u64, &'a str),
+}
+
+fn name_parser(input: &str) -> IResult<&str, &str> {
+    take_till1(|c| c == '\n' || c == '\r')(input)
+}
+
+fn line_parser(input: &str) -> IResult<&str, Line<'_>> {
+    alt((
+        value(Line::Root, tag("$ cd /")),
+        value(Line::Up, tag("$ cd ..")),
+        map(preceded(tag("$ cd "), name_parser), Line::Down),
+        value(Line::List, tag("$ ls")),
+        map(preceded(tag("dir "), name_parser), Line::Directory),
+        map(
+            separated_pair(number, char(' '), name_parser),
+            |(size, name)| Line::File(size, name),
+        ),
+    ))(input)
+}
+
+/// Replays the terminal session, returning the total size of every directory.
+fn directory_sizes(lines: &[Line]) -> Result<Vec<u64>> {
+    let mut path: Vec<&str> = Vec::new();
+    let mut sizes: HashMap<Vec<&str>, u64> = HashMap::from([(Vec::new(), 0)]);
+    let mut files = HashSet::new();
+    for (index, line) in lines.iter().enumerate() {
+        match *line {
+            Line::Root => path.clear(),
+            Line::Up => {
+                path.pop()
+                    .ok_or_else(|| anyhow::anyhow!("line {} leaves the root", index + 1))?;
+            }
+            Line::Down(name) => {
+                path.push(name);
+                sizes.entry(path.clone()).or_default();
+            }
+            Line::List | Line::Directory(_) => {}
+            //Listing a directory twice mustn't count its files twice.
+            Line::File(size, name) => {
+                if files.insert((path.clone(), name)) {
+                    for depth in 0..=path.len() {
+                        let total = sizes.entry(path[..depth].to_vec()).or_default();
+                        *total = total.checked_add(size).ok_or_else(|| {
+                            anyhow::anyhow!("line {} overflows a directory's size", index + 1)
+                        })?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(sizes.into_values().collect())
+}
+
+/// The total size of every directory in the terminal session in `input`, or an
+/// error at the first line that isn't a command or a listing, at a `cd ..` out of
+/// the root, or at a file that takes a directory past a `u64`.
+pub fn session_parser(input: &str) -> Result<Vec<u64>> {
+    directory_sizes(&complete(input, separated_list0(line_ending, line_parser))?)
+}
+
+/// The total size of every directory; the largest is the root.
+pub(super) fn input_generator(input: &str) -> Vec<u64> {
+    match session_parser(input) {
+        Ok(sizes) => sizes,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[u64]) -> u64 {
+    input.iter().filter(|&&size| size <= 100_000).sum()
+}
+
+#[must_use]
+pub fn part2(input: &[u64]) -> Option<u64> {
+    let used = input.iter().max()?;
+    let needed = used.checked_add(30_000_000)?.checked_sub(70_000_000)?;
+    input.iter().filter(|&&size| size >= needed).min().copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn test_line_parser() {
+        assert_eq!(line_parser("$ cd a"), Ok(("", Line::Down("a"))));
+        assert_eq!(
+            line_parser("62596 h.lst"),
+            Ok(("", Line::File(62596, "h.lst")))
+        );
+    }
+
+    #[test]
+    fn test_directory_sizes() {
+        let mut sizes = input_generator(EXAMPLE);
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![584, 94853, 24_933_642, 48_381_165]);
+        assert!(session_parser("$ cd ..").is_err());
+    }
+
+    #[test]
+    fn test_repeated_listing() {
+        let sizes = input_generator("$ cd /\n$ ls\n10 a\n$ ls\n10 a");
+        assert_eq!(sizes, vec![10]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 95437);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(24_933_642));
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+
+use crate::util::{direction::Direction, grid::Grid, point::Point};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Forest {
+    heights: Grid<u8>,
+}
+
+impl Forest {
+    /// The heights of the trees seen looking out from `tree` in each direction,
+    /// nearest first.
+    fn views(&self, tree: Point) -> [Vec<u8>; 4] {
+        Direction::ORTHOGONAL.map(|direction| {
+            let step = direction.offset();
+            std::iter::successors(Some(tree + step), |&point| Some(point + step))
+                .map_while(|point| self.heights.get(point).copied())
+                .collect()
+        })
+    }
+
+    fn trees(&self) -> impl Iterator<Item = Point> + '_ {
+        self.heights.points()
+    }
+
+    /// Whether `tree` can be seen from outside the forest.
+    #[must_use]
+    pub fn is_visible(&self, tree: Point) -> bool {
+        let height = self.heights[tree];
+        self.views(tree)
+            .iter()
+            .any(|view| view.iter().all(|&other| other < height))
+    }
+
+    /// The product of how far can be seen from `tree` each way.
+    #[must_use]
+    pub fn scenic_score(&self, tree: Point) -> usize {
+        let height = self.heights[tree];
+        self.views(tree)
+            .iter()
+            .map(|view| {
+                //The view stops at the first tree at least as tall.
+                view.iter()
+                    .position(|&other| other >= height)
+                    .map_or(view.len(), |blocked| blocked + 1)
+            })
+            .product()
+    }
+}
+
+/// The tree heights in `input`, or an error at a character that isn't a digit, or
+/// at a row of a different width.
+pub fn forest_parser(input: &str) -> Result<Forest> {
+    Ok(Forest {
+        heights: Grid::digits(input)?,
+    })
+}
+
+pub(super) fn input_generator(input: &str) -> Forest {
+    match forest_parser(input) {
+        Ok(forest) => forest,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &Forest) -> usize {
+    input.trees().filter(|&tree| input.is_visible(tree)).count()
+}
+
+#[must_use]
+pub fn part2(input: &Forest) -> Option<usize> {
+    input.trees().map(|tree| input.scenic_score(tree)).max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn test_forest_parser() {
+        assert!(forest_parser("12\n3").is_err());
+        assert!(forest_parser("1x").is_err());
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let forest = input_generator(EXAMPLE);
+        assert_eq!(forest.scenic_score(Point::new(2, 1)), 4);
+        assert_eq!(forest.scenic_score(Point::new(2, 3)), 8);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(8));
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use nom::{
+    character::complete::{char, line_ending, one_of, u32 as number},
+    combinator::{map_opt, verify},
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::util::{direction::Direction, parse::complete, point::Point};
+
+/// The number of positions the last knot of a rope with `knots` knots visits.
+#[must_use]
+pub fn tail_positions(motions: &[(Direction, u32)], knots: usize) -> usize {
+    let mut rope = vec![Point::ORIGIN; knots];
+    let mut visited = HashSet::from([Point::ORIGIN]);
+    for &(direction, steps) in motions {
+        for _ in 0..steps {
+            rope[0] += direction.offset();
+            //Each knot moves one step towards the one ahead whenever they stop
+            //  touching, diagonally if need be.
+            for knot in 1..knots {
+                let (ahead, behind) = (rope[knot - 1], rope[knot]);
+                if ahead.chebyshev(behind) <= 1 {
+                    break;
+                }
+                rope[knot] += (ahead - behind).signum();
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+/// The most steps a single motion can take. The puzzle's take under 20, and every
+/// step is simulated, so this keeps a run of motions quick.
+pub const MAX_STEPS: u32 = 1 << 10;
+
+fn motion_parser(input: &str) -> IResult<&str, (Direction, u32)> {
+    separated_pair(
+        map_opt(one_of("UDLR"), Direction::from_char),
+        char(' '),
+        verify(number, |&steps| steps <= MAX_STEPS),
+    )(input)
+}
+
+/// Every motion of the head of the rope in `input`, or an error at the first line
+/// that isn't a direction and a step count of at most [`MAX_STEPS`].
+pub fn motions_parser(input: &str) -> Result<Vec<(Direction, u32)>> {
+    complete(input, separated_list0(line_ending, motion_parser))
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<(Direction, u32)> {
+    match motions_parser(input) {
+        Ok(motions) => motions,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[(Direction, u32)]) -> usize {
+    tail_positions(input, 2)
+}
+
+#[must_use]
+pub fn part2(input: &[(Direction, u32)]) -> usize {
+    tail_positions(input, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+    const LARGER: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn test_motions_parser() {
+        assert_eq!(
+            motions_parser("R 1024\nU 2").unwrap(),
+            vec![(Direction::East, 1024), (Direction::North, 2)]
+        );
+        assert!(motions_parser("R 1025").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 1);
+        assert_eq!(part2(&input_generator(LARGER)), 36);
+    }
+}
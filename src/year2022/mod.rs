@@ -0,0 +1,42 @@
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day22;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::{runner::Solution, solution};
+
+/// Every solution for 2022.
+#[must_use]
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2022, 1, 1, day1::input_generator => day1::part1),
+        solution!(2022, 1, 2, day1::input_generator => day1::part2),
+        solution!(2022, 2, 1, day2::input_generator => day2::part1),
+        solution!(2022, 2, 2, day2::input_generator => day2::part2),
+        solution!(2022, 3, 1, day3::input_generator => day3::part1),
+        solution!(2022, 3, 2, day3::input_generator => day3::part2),
+        solution!(2022, 4, 1, day4::input_generator => day4::part1),
+        solution!(2022, 4, 2, day4::input_generator => day4::part2),
+        solution!(2022, 5, 1, day5::input_generator => day5::part1),
+        solution!(2022, 5, 2, day5::input_generator => day5::part2),
+        solution!(2022, 6, 1, day6::part1),
+        solution!(2022, 6, 2, day6::part2),
+        solution!(2022, 7, 1, day7::input_generator => day7::part1),
+        solution!(2022, 7, 2, day7::input_generator => day7::part2),
+        solution!(2022, 8, 1, day8::input_generator => day8::part1),
+        solution!(2022, 8, 2, day8::input_generator => day8::part2),
+        solution!(2022, 9, 1, day9::input_generator => day9::part1),
+        solution!(2022, 9, 2, day9::input_generator => day9::part2),
+        solution!(2022, 10, 1, day10::input_generator => day10::part1),
+        solution!(2022, 10, 2, day10::input_generator => day10::part2),
+        solution!(2022, 22, 1, day22::input_generator => day22::part1),
+        solution!(2022, 22, 2, day22::input_generator => day22::part2),
+    ]
+}
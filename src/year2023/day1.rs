@@ -1,7 +1,11 @@
+use crate::util::trie::Trie;
+
 pub const NUMBER_WORDS: [&str; 9] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
+const NUMERALS: [&str; 9] = ["1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
 #[aoc(day1, part1)]
 #[must_use]
 pub fn part1(input: &str) -> u32 {
@@ -34,53 +38,24 @@ pub fn part1(input: &str) -> u32 {
 #[aoc(day1, part2)]
 #[must_use]
 pub fn part2(input: &str) -> u32 {
+    //Digits can be written out as well as in numerals.
+    let digits: Trie<u32> = NUMBER_WORDS
+        .into_iter()
+        .zip(1..)
+        .chain(NUMERALS.into_iter().zip(1..))
+        .collect();
     //For each line
-    // For the first number look at each character, if that character is a assign that digit to first_num.
-    //  Otherwise, look at that character through the end of the line and see if it starts with one of our words.
-
-    //Do the same but starting from the end of the line and working backwards.
-    // If the character is a digit, assign that digit to second_num.
-    //  Otherwise, look at that character through to the end of the line and see if it starts with one our our words.
+    //  look for a digit starting at every position, so that names sharing letters
+    //  like "eightwo" are both found, and take the first from each end.
     input
         .lines()
         .map(|line| {
-            let mut first_num = None;
-            let mut second_num = None;
-
-            for (line_idx, c) in line.chars().enumerate() {
-                first_num = char::to_digit(c, 10).map_or_else(
-                    || {
-                        for (word_idx, word) in NUMBER_WORDS.iter().enumerate() {
-                            if line[line_idx..].starts_with(word) {
-                                return Some(word_idx as u32 + 1);
-                            }
-                        }
-                        None
-                    },
-                    Some,
-                );
-
-                if first_num.is_some() {
-                    break;
-                }
-            }
-            for (line_idx, c) in line.chars().rev().enumerate() {
-                second_num = char::to_digit(c, 10).map_or_else(
-                    || {
-                        for (word_idx, word) in NUMBER_WORDS.iter().enumerate() {
-                            if line[line.len() - line_idx - 1..].starts_with(word) {
-                                return Some(word_idx as u32 + 1);
-                            }
-                        }
-                        None
-                    },
-                    Some,
-                );
-                if second_num.is_some() {
-                    break;
-                }
-            }
-            first_num.unwrap_or_default() * 10 + second_num.unwrap_or_default()
+            let mut found = (0..line.len())
+                .filter_map(|start| digits.longest_match(line, start))
+                .map(|(_, &digit)| digit);
+            let first_num = found.next().unwrap_or_default();
+            let second_num = found.next_back().unwrap_or(first_num);
+            first_num * 10 + second_num
         })
         .sum()
 }
@@ -88,6 +63,7 @@ pub fn part2(input: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input;
     #[test]
     fn part_1_no_ints_test() {
         let input = "abcde";
@@ -121,11 +97,12 @@ mod tests {
     }
 
     #[test]
-    fn part_1_solution() {
-        let input = include_str!("../input/2023/day1.txt");
+    fn part_1_solution() -> anyhow::Result<()> {
+        let input = input::load(2023, 1)?;
         let expected = 54708;
-        let actual = part1(input);
+        let actual = part1(&input);
         assert_eq!(expected, actual);
+        Ok(())
     }
 
     #[test]
@@ -171,10 +148,19 @@ mod tests {
     }
 
     #[test]
-    fn part_2_solution() {
-        let input = include_str!("../input/2023/day1.txt");
-        let expected = 54087;
+    fn part_2_overlapping_words_test() {
+        let input = "xtwone3fouroneight";
+        let expected = 28;
         let actual = part2(input);
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn part_2_solution() -> anyhow::Result<()> {
+        let input = input::load(2023, 1)?;
+        let expected = 54087;
+        let actual = part2(&input);
+        assert_eq!(expected, actual);
+        Ok(())
+    }
 }
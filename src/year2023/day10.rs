@@ -0,0 +1,301 @@
+use anyhow::Result;
+
+use crate::util::{
+    direction::Direction,
+    geometry::{interior_points, shoelace},
+    grid::Grid,
+    point::Point,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl Tile {
+    const PIPES: [Self; 6] = [
+        Self::Vertical,
+        Self::Horizontal,
+        Self::NorthEast,
+        Self::NorthWest,
+        Self::SouthWest,
+        Self::SouthEast,
+    ];
+
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Self::Vertical),
+            '-' => Some(Self::Horizontal),
+            'L' => Some(Self::NorthEast),
+            'J' => Some(Self::NorthWest),
+            '7' => Some(Self::SouthWest),
+            'F' => Some(Self::SouthEast),
+            '.' => Some(Self::Ground),
+            'S' => Some(Self::Start),
+            _ => None,
+        }
+    }
+
+    /// The two directions a pipe opens towards. Ground and the unresolved start
+    /// tile have none.
+    #[must_use]
+    pub const fn openings(self) -> Option<[Direction; 2]> {
+        match self {
+            Self::Vertical => Some([Direction::North, Direction::South]),
+            Self::Horizontal => Some([Direction::East, Direction::West]),
+            Self::NorthEast => Some([Direction::North, Direction::East]),
+            Self::NorthWest => Some([Direction::North, Direction::West]),
+            Self::SouthWest => Some([Direction::South, Direction::West]),
+            Self::SouthEast => Some([Direction::South, Direction::East]),
+            Self::Ground | Self::Start => None,
+        }
+    }
+
+    #[must_use]
+    pub fn connects(self, direction: Direction) -> bool {
+        self.openings()
+            .is_some_and(|openings| openings.contains(&direction))
+    }
+
+    /// The direction we leave this pipe in, having entered it travelling `heading`.
+    #[must_use]
+    pub fn exit(self, heading: Direction) -> Option<Direction> {
+        let [a, b] = self.openings()?;
+        let entry = heading.reverse();
+        if a == entry {
+            Some(b)
+        } else if b == entry {
+            Some(a)
+        } else {
+            None
+        }
+    }
+
+    /// The pipe that opens towards exactly `a` and `b`.
+    #[must_use]
+    pub fn joining(a: Direction, b: Direction) -> Option<Self> {
+        Self::PIPES
+            .into_iter()
+            .find(|pipe| a != b && pipe.connects(a) && pipe.connects(b))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Maze {
+    tiles: Grid<Tile>,
+    start: Point,
+}
+
+impl Maze {
+    fn get(&self, position: Point) -> Option<Tile> {
+        self.tiles.get(position).copied()
+    }
+
+    fn neighbor(&self, position: Point, direction: Direction) -> Option<Point> {
+        let next = position + direction.offset();
+        self.tiles.contains(next).then_some(next)
+    }
+
+    /// The pipe hidden under the start tile, deduced from which neighbours point back at it.
+    fn start_pipe(&self) -> Option<Tile> {
+        let mut connected = Direction::ORTHOGONAL.into_iter().filter(|&direction| {
+            self.neighbor(self.start, direction)
+                .and_then(|next| self.get(next))
+                .is_some_and(|tile| tile.connects(direction.reverse()))
+        });
+        Tile::joining(connected.next()?, connected.next()?)
+    }
+
+    /// Every position on the loop through the start tile, in the order walked.
+    #[must_use]
+    pub fn main_loop(&self) -> Option<Vec<Point>> {
+        let start_pipe = self.start_pipe()?;
+        let mut heading = start_pipe.openings()?[0];
+        let mut position = self.start;
+        let mut path = vec![];
+        loop {
+            path.push(position);
+            position = self.neighbor(position, heading)?;
+            if position == self.start {
+                return Some(path);
+            }
+            heading = self.get(position)?.exit(heading)?;
+        }
+    }
+
+    /// The number of tiles enclosed by the loop.
+    ///
+    /// The loop is a polygon through the centres of its tiles, so the shoelace
+    /// formula gives its area and Pick's theorem the tiles strictly inside it.
+    #[must_use]
+    pub fn enclosed(&self) -> Option<usize> {
+        let path = self.main_loop()?;
+        let inside = interior_points(shoelace(path.iter().copied()), path.len() as i128);
+        usize::try_from(inside).ok()
+    }
+}
+
+/// The maze of pipes in `input`, or an error at a character that isn't a pipe,
+/// ground or the start, at a row of a different width, or if there is no start.
+pub fn maze_parser(input: &str) -> Result<Maze> {
+    let tiles = Grid::parse(input, |c| {
+        Tile::from_char(char::from(c))
+            .ok_or_else(|| anyhow::anyhow!("unexpected tile {:?}", char::from(c)))
+    })?;
+    let start = tiles
+        .enumerate()
+        .find(|&(_, &tile)| tile == Tile::Start)
+        .map(|(point, _)| point)
+        .ok_or_else(|| anyhow::anyhow!("no start tile"))?;
+    Ok(Maze { tiles, start })
+}
+
+#[aoc_generator(day10)]
+pub(super) fn input_generator(input: &str) -> Maze {
+    match maze_parser(input) {
+        Ok(maze) => maze,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day10, part1)]
+#[must_use]
+pub fn part1(input: &Maze) -> Option<usize> {
+    //The farthest point is halfway round the loop.
+    input.main_loop().map(|path| path.len() / 2)
+}
+
+#[aoc(day10, part2)]
+#[must_use]
+pub fn part2(input: &Maze) -> Option<usize> {
+    input.enclosed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQUARE_LOOP: &str = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+    const COMPLEX_LOOP: &str = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+    const ENCLOSED: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    const SQUEEZED: &str = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+    const LARGER: &str = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    const JUNK: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn test_exit() {
+        assert_eq!(
+            Tile::Vertical.exit(Direction::North),
+            Some(Direction::North)
+        );
+        assert_eq!(
+            Tile::NorthEast.exit(Direction::South),
+            Some(Direction::East)
+        );
+        assert_eq!(
+            Tile::NorthEast.exit(Direction::West),
+            Some(Direction::North)
+        );
+        assert_eq!(Tile::NorthEast.exit(Direction::North), None);
+        assert_eq!(Tile::Ground.exit(Direction::North), None);
+    }
+
+    #[test]
+    fn test_joining() {
+        assert_eq!(
+            Tile::joining(Direction::South, Direction::East),
+            Some(Tile::SouthEast)
+        );
+        assert_eq!(
+            Tile::joining(Direction::West, Direction::East),
+            Some(Tile::Horizontal)
+        );
+        assert_eq!(Tile::joining(Direction::West, Direction::West), None);
+    }
+
+    #[test]
+    fn test_start_pipe() {
+        assert_eq!(
+            input_generator(SQUARE_LOOP).start_pipe(),
+            Some(Tile::SouthEast)
+        );
+        assert_eq!(
+            input_generator(COMPLEX_LOOP).start_pipe(),
+            Some(Tile::SouthEast)
+        );
+    }
+
+    #[test]
+    fn test_bad_tile() {
+        assert!(maze_parser("S-7\n|X|\nL-J").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(SQUARE_LOOP)), Some(4));
+        assert_eq!(part1(&input_generator(COMPLEX_LOOP)), Some(8));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(ENCLOSED)), Some(4));
+        assert_eq!(part2(&input_generator(SQUEEZED)), Some(4));
+        assert_eq!(part2(&input_generator(LARGER)), Some(8));
+        assert_eq!(part2(&input_generator(JUNK)), Some(10));
+    }
+}
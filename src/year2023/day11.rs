@@ -0,0 +1,121 @@
+use crate::util::point::Point;
+
+/// The position of a galaxy.
+pub type Galaxy = Point;
+
+#[aoc_generator(day11)]
+pub(super) fn input_generator(input: &str) -> Vec<Galaxy> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.char_indices()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(col, _)| Point::from((col, row)))
+        })
+        .collect()
+}
+
+/// Stretches one axis of galaxy coordinates so that every empty line takes up
+/// `expansion` lines instead of one.
+fn expand_axis(coordinates: impl Iterator<Item = i64>, expansion: u64) -> Vec<u64> {
+    let mut coordinates: Vec<u64> = coordinates.map(|coordinate| coordinate as u64).collect();
+    coordinates.sort_unstable();
+
+    let mut expanded = Vec::with_capacity(coordinates.len());
+    let mut empty_lines = 0;
+    let mut previous: Option<u64> = None;
+    for coordinate in coordinates {
+        if let Some(previous) = previous {
+            //Any gap of more than one line between neighbouring galaxies is empty space.
+            empty_lines += (coordinate - previous).saturating_sub(1);
+        } else {
+            empty_lines += coordinate;
+        }
+        previous = Some(coordinate);
+        expanded.push(coordinate + empty_lines * (expansion - 1));
+    }
+    expanded
+}
+
+/// The sum of `|a - b|` over every pair of the (sorted) `coordinates`.
+fn pairwise_distance(sorted: &[u64]) -> u64 {
+    //Each coordinate is subtracted from every one after it, and has every one
+    //  before it subtracted from it.
+    sorted
+        .iter()
+        .zip(0_u64..)
+        .fold((0, 0), |(total, prefix), (&coordinate, idx)| {
+            (total + coordinate * idx - prefix, prefix + coordinate)
+        })
+        .0
+}
+
+/// The sum of the Manhattan distances between every pair of galaxies, once each
+/// empty row and column has grown to `expansion` times its original size.
+#[must_use]
+pub fn total_distance(galaxies: &[Galaxy], expansion: u64) -> u64 {
+    let rows = expand_axis(galaxies.iter().map(|galaxy| galaxy.y), expansion);
+    let cols = expand_axis(galaxies.iter().map(|galaxy| galaxy.x), expansion);
+    pairwise_distance(&rows) + pairwise_distance(&cols)
+}
+
+#[aoc(day11, part1)]
+#[must_use]
+pub fn part1(input: &[Galaxy]) -> u64 {
+    total_distance(input, 2)
+}
+
+#[aoc(day11, part2)]
+#[must_use]
+pub fn part2(input: &[Galaxy]) -> u64 {
+    total_distance(input, 1_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test_input_generator() {
+        let galaxies = input_generator(EXAMPLE);
+        assert_eq!(galaxies.len(), 9);
+        assert_eq!(galaxies[0], Point::new(3, 0));
+        assert_eq!(galaxies[8], Point::new(4, 9));
+    }
+
+    #[test]
+    fn test_expand_axis() {
+        assert_eq!(expand_axis([0, 2, 2, 5].into_iter(), 2), vec![0, 3, 3, 8]);
+        assert_eq!(expand_axis([1].into_iter(), 10), vec![10]);
+    }
+
+    #[test]
+    fn test_pairwise_distance() {
+        assert_eq!(pairwise_distance(&[1, 3, 6]), 2 + 5 + 3);
+        assert_eq!(pairwise_distance(&[]), 0);
+    }
+
+    #[test]
+    fn test_total_distance() {
+        let galaxies = input_generator(EXAMPLE);
+        assert_eq!(total_distance(&galaxies, 10), 1030);
+        assert_eq!(total_distance(&galaxies, 100), 8410);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 374);
+    }
+}
@@ -0,0 +1,199 @@
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    character::complete::{char, digit1, line_ending, space1},
+    combinator::{map, map_res, value},
+    multi::{many1, separated_list0, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::util::{memo::Memo, parse::complete};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    /// The record with its springs and groups each repeated `copies` times, the
+    /// spring copies joined by unknown springs.
+    #[must_use]
+    pub fn unfold(&self, copies: usize) -> Self {
+        let mut springs = Vec::with_capacity((self.springs.len() + 1) * copies);
+        for copy in 0..copies {
+            if copy > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        Self {
+            springs,
+            groups: self.groups.repeat(copies),
+        }
+    }
+
+    /// The number of ways the unknown springs can be filled in to match the groups,
+    /// or `None` if there are more than a `u64` can count.
+    #[must_use]
+    pub fn arrangements(&self) -> Option<u64> {
+        self.count(0, 0, &mut Memo::new())
+    }
+
+    /// Arrangements of `springs[spring..]` that produce exactly `groups[group..]`.
+    fn count(
+        &self,
+        spring: usize,
+        group: usize,
+        memo: &mut Memo<(usize, usize), Option<u64>>,
+    ) -> Option<u64> {
+        let Some(&current) = self.springs.get(spring) else {
+            return Some(u64::from(group == self.groups.len()));
+        };
+        memo.get((spring, group), |memo| {
+            let mut total: u64 = 0;
+            //Treat this spring as operational and move on.
+            if current != Spring::Damaged {
+                total = self.count(spring + 1, group, memo)?;
+            }
+            //Or start the next group of damaged springs here, which needs a run of
+            //  springs that could all be damaged followed by one that could be operational.
+            if current != Spring::Operational {
+                if let Some(&len) = self.groups.get(group) {
+                    let end = spring.saturating_add(len);
+                    let fits = end <= self.springs.len()
+                        && self.springs[spring..end]
+                            .iter()
+                            .all(|&s| s != Spring::Operational)
+                        && self.springs.get(end) != Some(&Spring::Damaged);
+                    if fits {
+                        let next = (end + 1).min(self.springs.len());
+                        total = total.checked_add(self.count(next, group + 1, memo)?)?;
+                    }
+                }
+            }
+            Some(total)
+        })
+    }
+}
+
+fn spring_parser(input: &str) -> IResult<&str, Spring> {
+    alt((
+        value(Spring::Operational, char('.')),
+        value(Spring::Damaged, char('#')),
+        value(Spring::Unknown, char('?')),
+    ))(input)
+}
+
+fn record_parser(input: &str) -> IResult<&str, Record> {
+    map(
+        separated_pair(
+            many1(spring_parser),
+            space1,
+            separated_list1(char(','), map_res(digit1, str::parse::<usize>)),
+        ),
+        |(springs, groups)| Record { springs, groups },
+    )(input)
+}
+
+/// Every row of springs in `input`, or an error at the first line that isn't a run
+/// of `.`, `#` and `?` followed by a comma-separated list of group lengths.
+pub fn records_parser(input: &str) -> Result<Vec<Record>> {
+    complete(input, separated_list0(line_ending, record_parser))
+}
+
+#[aoc_generator(day12)]
+pub(super) fn input_generator(input: &str) -> Vec<Record> {
+    match records_parser(input) {
+        Ok(records) => records,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day12, part1)]
+#[must_use]
+pub fn part1(input: &[Record]) -> Option<u64> {
+    input.iter().try_fold(0_u64, |total, record| {
+        total.checked_add(record.arrangements()?)
+    })
+}
+
+#[aoc(day12, part2)]
+#[must_use]
+pub fn part2(input: &[Record]) -> Option<u64> {
+    input.iter().try_fold(0_u64, |total, record| {
+        total.checked_add(record.unfold(5).arrangements()?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn test_record_parser() {
+        let expected = Record {
+            springs: vec![Spring::Unknown, Spring::Damaged, Spring::Operational],
+            groups: vec![1, 12],
+        };
+        assert_eq!(record_parser("?#. 1,12"), Ok(("", expected)));
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let counts: Vec<u64> = input_generator(EXAMPLE)
+            .iter()
+            .map(|record| record.arrangements().unwrap())
+            .collect();
+        assert_eq!(counts, vec![1, 4, 1, 1, 4, 10]);
+        //Fifty groups spread over two hundred unknowns can be placed ~10^40 ways.
+        let springs = "?".repeat(200);
+        let groups = vec!["1"; 50].join(",");
+        let (_, record) = record_parser(&format!("{springs} {groups}")).unwrap();
+        assert_eq!(record.arrangements(), None);
+        let (_, record) = record_parser("? 18446744073709551615").unwrap();
+        assert_eq!(record.arrangements(), Some(0));
+    }
+
+    #[test]
+    fn test_unfold() {
+        let (_, record) = record_parser(".# 1").unwrap();
+        let (_, expected) = record_parser(".#?.#?.#?.#?.# 1,1,1,1,1").unwrap();
+        assert_eq!(record.unfold(5), expected);
+    }
+
+    #[test]
+    fn test_unfolded_arrangements() {
+        let counts: Vec<u64> = input_generator(EXAMPLE)
+            .iter()
+            .map(|record| record.unfold(5).arrangements().unwrap())
+            .collect();
+        assert_eq!(counts, vec![1, 16384, 1, 16, 2500, 506_250]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(21));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(525_152));
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::Result;
+
+/// A pattern stored as bitmasks of its rocks, once by row and once by column.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Pattern {
+    rows: Vec<u64>,
+    cols: Vec<u64>,
+}
+
+impl Pattern {
+    fn new(lines: &[&str]) -> Result<Self> {
+        let width = lines.first().map_or(0, |line| line.len());
+        if width > 64 || lines.len() > 64 {
+            return Err(anyhow::anyhow!(
+                "patterns larger than 64x64 are not supported"
+            ));
+        }
+        let mut rows = vec![0; lines.len()];
+        let mut cols = vec![0; width];
+        for (row, line) in lines.iter().enumerate() {
+            if line.len() != width {
+                return Err(anyhow::anyhow!("ragged pattern row {line:?}"));
+            }
+            for (col, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        rows[row] |= 1 << col;
+                        cols[col] |= 1 << row;
+                    }
+                    '.' => (),
+                    _ => return Err(anyhow::anyhow!("unexpected character {c:?}")),
+                }
+            }
+        }
+        Ok(Self { rows, cols })
+    }
+
+    /// The number of lines before a mirror line that reflects the pattern with
+    /// exactly `smudges` cells flipped.
+    fn mirror(lines: &[u64], smudges: u32) -> Option<usize> {
+        (1..lines.len()).find(|&split| {
+            let (before, after) = lines.split_at(split);
+            before
+                .iter()
+                .rev()
+                .zip(after)
+                .map(|(a, b)| (a ^ b).count_ones())
+                .sum::<u32>()
+                == smudges
+        })
+    }
+
+    /// The puzzle's summary of the mirror line: the columns to its left, or 100
+    /// times the rows above it.
+    #[must_use]
+    pub fn summarize(&self, smudges: u32) -> Option<usize> {
+        Self::mirror(&self.cols, smudges)
+            .or_else(|| Self::mirror(&self.rows, smudges).map(|rows| rows * 100))
+    }
+}
+
+/// Every pattern of ash and rocks in `input`, or an error at a character other
+/// than `#` or `.`, at a ragged row, or at a pattern larger than 64 by 64.
+pub fn patterns_parser(input: &str) -> Result<Vec<Pattern>> {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    lines
+        .split(|line| line.is_empty())
+        .filter(|block| !block.is_empty())
+        .map(Pattern::new)
+        .collect()
+}
+
+#[aoc_generator(day13)]
+pub(super) fn input_generator(input: &str) -> Vec<Pattern> {
+    match patterns_parser(input) {
+        Ok(patterns) => patterns,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day13, part1)]
+#[must_use]
+pub fn part1(input: &[Pattern]) -> Option<usize> {
+    input.iter().map(|pattern| pattern.summarize(0)).sum()
+}
+
+#[aoc(day13, part2)]
+#[must_use]
+pub fn part2(input: &[Pattern]) -> Option<usize> {
+    //Exactly one cell is smudged, so the true mirror line is the one that is out by one.
+    input.iter().map(|pattern| pattern.summarize(1)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn test_patterns_parser() {
+        let patterns = patterns_parser(EXAMPLE).unwrap();
+        assert_eq!(patterns.len(), 2);
+        assert_eq!(patterns[0].rows.len(), 7);
+        assert_eq!(patterns[0].cols.len(), 9);
+        assert_eq!(patterns[0].rows[0], 0b1100_1101);
+    }
+
+    #[test]
+    fn test_bad_pattern() {
+        assert!(patterns_parser("#.\n#").is_err());
+        assert!(patterns_parser("#x").is_err());
+    }
+
+    #[test]
+    fn test_summarize() {
+        let patterns = input_generator(EXAMPLE);
+        assert_eq!(patterns[0].summarize(0), Some(5));
+        assert_eq!(patterns[1].summarize(0), Some(400));
+        assert_eq!(patterns[0].summarize(1), Some(300));
+        assert_eq!(patterns[1].summarize(1), Some(100));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(405));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(400));
+    }
+}
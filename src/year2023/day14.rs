@@ -0,0 +1,183 @@
+use anyhow::Result;
+
+use crate::util::{cycle, grid::Grid, point::Point};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Cell {
+    Round,
+    Cube,
+    Empty,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Platform {
+    cells: Grid<Cell>,
+}
+
+impl Platform {
+    /// Rolls every round rock as far north as it will go.
+    #[must_use]
+    pub fn tilt_north(mut self) -> Self {
+        for col in 0..self.cells.width() {
+            //The row the next round rock in this column will come to rest on.
+            let mut resting = 0;
+            for row in 0..self.cells.height() {
+                let point = Point::from((col, row));
+                match self.cells[point] {
+                    Cell::Cube => resting = row + 1,
+                    Cell::Round => {
+                        self.cells[point] = Cell::Empty;
+                        self.cells[Point::from((col, resting))] = Cell::Round;
+                        resting += 1;
+                    }
+                    Cell::Empty => (),
+                }
+            }
+        }
+        self
+    }
+
+    /// Turns the platform a quarter turn clockwise, so what was west is now north.
+    #[must_use]
+    fn rotate_clockwise(self) -> Self {
+        Self {
+            cells: self.cells.rotate_cw(),
+        }
+    }
+
+    /// Tilts north, west, south then east.
+    #[must_use]
+    pub fn spin_cycle(self) -> Self {
+        //Tilting north then turning clockwise brings the next tilt direction round to north.
+        (0..4).fold(self, |platform, _| platform.tilt_north().rotate_clockwise())
+    }
+
+    /// The load on the north support beams.
+    #[must_use]
+    pub fn north_load(&self) -> usize {
+        self.cells
+            .rows()
+            .rev()
+            .zip(1..)
+            .map(|(row, load)| row.iter().filter(|&&cell| cell == Cell::Round).count() * load)
+            .sum()
+    }
+}
+
+/// The platform of rocks in `input`, or an error at a character other than `O`,
+/// `#` or `.`, or at a row of a different width.
+pub fn platform_parser(input: &str) -> Result<Platform> {
+    let cells = Grid::parse(input, |c| match c {
+        b'O' => Ok(Cell::Round),
+        b'#' => Ok(Cell::Cube),
+        b'.' => Ok(Cell::Empty),
+        _ => Err(anyhow::anyhow!("unexpected character {:?}", c as char)),
+    })?;
+    Ok(Platform { cells })
+}
+
+#[aoc_generator(day14)]
+pub(super) fn input_generator(input: &str) -> Platform {
+    match platform_parser(input) {
+        Ok(platform) => platform,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day14, part1)]
+#[must_use]
+pub fn part1(input: &Platform) -> usize {
+    input.clone().tilt_north().north_load()
+}
+
+#[aoc(day14, part2)]
+#[must_use]
+pub fn part2(input: &Platform) -> usize {
+    //The platform settles into a loop long before a billion spins, so find the loop
+    //  and work out where in it the billionth spin lands.
+    const SPINS: usize = 1_000_000_000;
+    cycle::nth(
+        input.clone(),
+        |platform| platform.clone().spin_cycle(),
+        SPINS,
+    )
+    .north_load()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    const TILTED: &str = "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+    const ONE_CYCLE: &str = ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....";
+
+    const THREE_CYCLES: &str = ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O";
+
+    #[test]
+    fn test_tilt_north() {
+        let platform = input_generator(EXAMPLE).tilt_north();
+        assert_eq!(platform, input_generator(TILTED));
+    }
+
+    #[test]
+    fn test_spin_cycle() {
+        let once = input_generator(EXAMPLE).spin_cycle();
+        assert_eq!(once, input_generator(ONE_CYCLE));
+        let thrice = once.spin_cycle().spin_cycle();
+        assert_eq!(thrice, input_generator(THREE_CYCLES));
+    }
+
+    #[test]
+    fn test_bad_platform() {
+        assert!(platform_parser("O.x").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 136);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 64);
+    }
+}
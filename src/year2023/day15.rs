@@ -0,0 +1,169 @@
+use anyhow::Result;
+
+use crate::util::hash::holiday_ascii;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    Remove,
+    Insert(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Step {
+    text: String,
+    label: String,
+    operation: Operation,
+}
+
+impl Step {
+    fn parse(text: &str) -> Result<Self> {
+        let (label, operation) = if let Some(label) = text.strip_suffix('-') {
+            (label, Operation::Remove)
+        } else if let Some((label, focal_length)) = text.split_once('=') {
+            (label, Operation::Insert(focal_length.parse()?))
+        } else {
+            return Err(anyhow::anyhow!("invalid step {text:?}"));
+        };
+        Ok(Self {
+            text: text.to_string(),
+            label: label.to_string(),
+            operation,
+        })
+    }
+}
+
+/// The 256 boxes, each holding lenses as (label, focal length) in slot order.
+#[derive(Debug, Clone)]
+pub struct Boxes {
+    boxes: Vec<Vec<(String, u8)>>,
+}
+
+impl Default for Boxes {
+    fn default() -> Self {
+        Self {
+            boxes: vec![Vec::new(); 256],
+        }
+    }
+}
+
+impl Boxes {
+    pub fn apply(&mut self, step: &Step) {
+        let lenses = &mut self.boxes[usize::from(holiday_ascii(step.label.as_bytes()))];
+        let slot = lenses.iter().position(|(label, _)| *label == step.label);
+        match (step.operation, slot) {
+            (Operation::Remove, Some(slot)) => {
+                lenses.remove(slot);
+            }
+            (Operation::Remove, None) => (),
+            //Replacing a lens keeps its place in the box; new lenses go to the back.
+            (Operation::Insert(focal_length), Some(slot)) => lenses[slot].1 = focal_length,
+            (Operation::Insert(focal_length), None) => {
+                lenses.push((step.label.clone(), focal_length));
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .zip(1..)
+            .flat_map(|(lenses, box_number)| {
+                lenses
+                    .iter()
+                    .zip(1..)
+                    .map(move |((_, focal_length), slot)| {
+                        box_number * slot * usize::from(*focal_length)
+                    })
+            })
+            .sum()
+    }
+}
+
+/// Every step of the initialization sequence in `input`, or an error at the first
+/// step that neither ends in `-` nor sets a focal length that fits in a `u8`.
+pub fn steps_parser(input: &str) -> Result<Vec<Step>> {
+    //Newlines in the sequence are to be ignored.
+    input
+        .trim()
+        .split(',')
+        .map(|step| Step::parse(&step.replace(['\n', '\r'], "")))
+        .collect()
+}
+
+#[aoc_generator(day15)]
+pub(super) fn input_generator(input: &str) -> Vec<Step> {
+    match steps_parser(input) {
+        Ok(steps) => steps,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day15, part1)]
+#[must_use]
+pub fn part1(input: &[Step]) -> u32 {
+    input
+        .iter()
+        .map(|step| u32::from(holiday_ascii(step.text.as_bytes())))
+        .sum()
+}
+
+#[aoc(day15, part2)]
+#[must_use]
+pub fn part2(input: &[Step]) -> usize {
+    let mut boxes = Boxes::default();
+    for step in input {
+        boxes.apply(step);
+    }
+    boxes.focusing_power()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+    #[test]
+    fn test_step_parse() {
+        let step = Step::parse("rn=1").unwrap();
+        assert_eq!(step.label, "rn");
+        assert_eq!(step.operation, Operation::Insert(1));
+        let step = Step::parse("cm-").unwrap();
+        assert_eq!(step.label, "cm");
+        assert_eq!(step.operation, Operation::Remove);
+        assert!(Step::parse("cm").is_err());
+        assert!(Step::parse("cm=x").is_err());
+    }
+
+    #[test]
+    fn test_boxes() {
+        let mut boxes = Boxes::default();
+        for step in input_generator(EXAMPLE) {
+            boxes.apply(&step);
+        }
+        assert_eq!(
+            boxes.boxes[0],
+            vec![("rn".to_string(), 1), ("cm".to_string(), 2)]
+        );
+        assert!(boxes.boxes[1].is_empty());
+        assert_eq!(
+            boxes.boxes[3],
+            vec![
+                ("ot".to_string(), 7),
+                ("ab".to_string(), 5),
+                ("pc".to_string(), 6)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 1320);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 145);
+    }
+}
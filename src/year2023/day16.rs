@@ -0,0 +1,191 @@
+use std::thread;
+
+use anyhow::Result;
+
+use crate::util::{bit_grid::BitGrid, direction::Direction, grid::Grid, point::Point};
+
+/// A distinct index per straight heading, for recording which beams have passed
+/// a tile. Beams never travel diagonally.
+const fn index(heading: Direction) -> usize {
+    heading as usize / 2
+}
+
+/// The heading(s) a beam travelling `heading` leaves `tile` with.
+fn deflect(tile: u8, heading: Direction) -> (Direction, Option<Direction>) {
+    use Direction::{East, North, South, West};
+    match (tile, heading) {
+        (b'/', North) | (b'\\', South) => (East, None),
+        (b'/', East) | (b'\\', West) => (North, None),
+        (b'/', South) | (b'\\', North) => (West, None),
+        (b'/', West) | (b'\\', East) => (South, None),
+        (b'|', East | West) => (North, Some(South)),
+        (b'-', North | South) => (East, Some(West)),
+        _ => (heading, None),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Contraption {
+    tiles: Grid<u8>,
+}
+
+impl Contraption {
+    fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// The number of tiles energized by a beam entering at `start` travelling `heading`.
+    #[must_use]
+    pub fn energized(&self, start: Point, heading: Direction) -> usize {
+        //Splitters can send beams round in loops, so stop following a beam as soon as
+        //  it passes a tile in a direction some beam has already taken.
+        let mut seen: [BitGrid; 4] =
+            std::array::from_fn(|_| BitGrid::new(self.tiles.width(), self.tiles.height()));
+        let mut beams = vec![(start, heading)];
+        while let Some((position, heading)) = beams.pop() {
+            if !seen[index(heading)].insert(position) {
+                continue;
+            }
+
+            let (first, second) = deflect(self.tiles[position], heading);
+            for heading in std::iter::once(first).chain(second) {
+                let next = position + heading.offset();
+                if self.tiles.contains(next) {
+                    beams.push((next, heading));
+                }
+            }
+        }
+        let [north, east, south, west] = &seen;
+        (&(north | east) | &(south | west)).count()
+    }
+
+    /// Every way a beam can enter from the edge of the contraption.
+    fn edge_starts(&self) -> Vec<(Point, Direction)> {
+        let (width, height) = (self.tiles.width(), self.tiles.height());
+        let mut starts = Vec::with_capacity(2 * (height + width));
+        for y in 0..height {
+            starts.push((Point::from((0, y)), Direction::East));
+            starts.push((Point::from((width - 1, y)), Direction::West));
+        }
+        for x in 0..width {
+            starts.push((Point::from((x, 0)), Direction::South));
+            starts.push((Point::from((x, height - 1)), Direction::North));
+        }
+        starts
+    }
+}
+
+/// The contraption of mirrors and splitters in `input`, or an error at a tile
+/// other than `.`, `/`, `\\`, `|` or `-`, or at a row of a different width.
+pub fn contraption_parser(input: &str) -> Result<Contraption> {
+    let tiles = Grid::parse(input, |tile| {
+        if b"./\\|-".contains(&tile) {
+            Ok(tile)
+        } else {
+            Err(anyhow::anyhow!("unexpected tile {:?}", char::from(tile)))
+        }
+    })?;
+    Ok(Contraption { tiles })
+}
+
+#[aoc_generator(day16)]
+pub(super) fn input_generator(input: &str) -> Contraption {
+    match contraption_parser(input) {
+        Ok(contraption) => contraption,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day16, part1)]
+#[must_use]
+pub fn part1(input: &Contraption) -> usize {
+    if input.is_empty() {
+        return 0;
+    }
+    input.energized(Point::ORIGIN, Direction::East)
+}
+
+#[aoc(day16, part2)]
+#[must_use]
+pub fn part2(input: &Contraption) -> usize {
+    if input.is_empty() {
+        return 0;
+    }
+    //Every entry point is independent, so share them out across the available cores.
+    let starts = input.edge_starts();
+    let threads = thread::available_parallelism().map_or(1, usize::from);
+    let chunk_size = starts.len().div_ceil(threads);
+    thread::scope(|scope| {
+        let workers: Vec<_> = starts
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(|| {
+                    chunk
+                        .iter()
+                        .map(|&(start, heading)| input.energized(start, heading))
+                        .max()
+                        .unwrap_or_default()
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("beam worker panicked"))
+            .max()
+            .unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn test_deflect() {
+        assert_eq!(deflect(b'.', Direction::East), (Direction::East, None));
+        assert_eq!(deflect(b'/', Direction::East), (Direction::North, None));
+        assert_eq!(deflect(b'\\', Direction::East), (Direction::South, None));
+        assert_eq!(
+            deflect(b'|', Direction::West),
+            (Direction::North, Some(Direction::South))
+        );
+        assert_eq!(deflect(b'|', Direction::North), (Direction::North, None));
+        assert_eq!(deflect(b'-', Direction::East), (Direction::East, None));
+    }
+
+    #[test]
+    fn test_bad_contraption() {
+        assert!(contraption_parser("..\n.").is_err());
+        assert!(contraption_parser(".x").is_err());
+    }
+
+    #[test]
+    fn test_energized() {
+        let contraption = input_generator(EXAMPLE);
+        assert_eq!(
+            contraption.energized(Point::new(3, 0), Direction::South),
+            51
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 46);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 51);
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::Result;
+
+use crate::util::{direction::Direction, grid::Grid, point::Point, search::dijkstra};
+
+/// A position in the search: where the crucible is, which way it is facing, and
+/// how many blocks it has just moved in a straight line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+struct State {
+    position: Point,
+    heading: Direction,
+    run: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct City {
+    heat_loss: Grid<u8>,
+}
+
+impl City {
+    /// The moves the crucible can make from `state`, with the heat each loses.
+    fn moves(
+        &self,
+        state: State,
+        min_run: usize,
+        max_run: usize,
+    ) -> impl Iterator<Item = (State, u32)> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |heading| {
+                if heading == state.heading.reverse() {
+                    return None;
+                }
+                let straight = heading == state.heading;
+                if (straight && state.run >= max_run)
+                    || (!straight && state.run < min_run && state.run > 0)
+                {
+                    return None;
+                }
+                let position = state.position + heading.offset();
+                let heat_loss = *self.heat_loss.get(position)?;
+                let next = State {
+                    position,
+                    heading,
+                    run: if straight { state.run + 1 } else { 1 },
+                };
+                Some((next, u32::from(heat_loss)))
+            })
+    }
+
+    /// The least heat lost getting a crucible from the top left block to the bottom
+    /// right one, when it must move between `min_run` and `max_run` blocks in a
+    /// straight line before turning or stopping.
+    #[must_use]
+    pub fn least_heat_loss(&self, min_run: usize, max_run: usize) -> Option<u32> {
+        if self.heat_loss.is_empty() {
+            return None;
+        }
+        let end = Point::from((self.heat_loss.width() - 1, self.heat_loss.height() - 1));
+        //At the start the crucible hasn't moved yet, so it may set off east or turn south.
+        let start = State {
+            position: Point::ORIGIN,
+            heading: Direction::East,
+            run: 0,
+        };
+        dijkstra(
+            start,
+            |&state| self.moves(state, min_run, max_run),
+            |state| state.position == end && state.run >= min_run,
+        )
+        .map(|found| found.cost)
+    }
+}
+
+/// The map of heat loss in `input`, or an error at a character that isn't a digit,
+/// or at a row of a different width.
+pub fn city_parser(input: &str) -> Result<City> {
+    Ok(City {
+        heat_loss: Grid::digits(input)?,
+    })
+}
+
+#[aoc_generator(day17)]
+pub(super) fn input_generator(input: &str) -> City {
+    match city_parser(input) {
+        Ok(city) => city,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day17, part1)]
+#[must_use]
+pub fn part1(input: &City) -> Option<u32> {
+    input.least_heat_loss(1, 3)
+}
+
+#[aoc(day17, part2)]
+#[must_use]
+pub fn part2(input: &City) -> Option<u32> {
+    //Ultra crucibles need four blocks to get going, and can manage ten before turning.
+    input.least_heat_loss(4, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    const UNFORTUNATE: &str = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+    #[test]
+    fn test_city_parser() {
+        let city = city_parser("12\n34").unwrap();
+        assert_eq!(
+            city.heat_loss,
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap()
+        );
+        assert!(city_parser("12\n3").is_err());
+        assert!(city_parser("1x").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(102));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(94));
+        assert_eq!(part2(&input_generator(UNFORTUNATE)), Some(71));
+    }
+}
@@ -0,0 +1,186 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, line_ending, one_of, space1, u32 as number},
+    combinator::{map, map_opt},
+    multi::separated_list0,
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+use crate::util::{
+    direction::Direction,
+    geometry::{interior_points, shoelace},
+    parse::complete,
+    point::Point,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Dig {
+    direction: Direction,
+    distance: i64,
+}
+
+/// One line of the plan, read both as written and with the instruction hidden in
+/// its colour code.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Instruction {
+    plain: Dig,
+    decoded: Dig,
+}
+
+fn direction_parser(input: &str) -> IResult<&str, Direction> {
+    map_opt(one_of("UDLR"), Direction::from_char)(input)
+}
+
+/// Parses the `(#xxxxxd)` colour code: five hex digits of distance followed by a
+/// digit naming the direction.
+fn color_parser(input: &str) -> IResult<&str, Dig> {
+    let hex = |c: char| c.is_ascii_hexdigit();
+    delimited(
+        tag("(#"),
+        map_opt(
+            tuple((take_while_m_n(5, 5, hex), take_while_m_n(1, 1, hex))),
+            |(distance, direction): (&str, &str)| {
+                let direction = match direction {
+                    "0" => Direction::East,
+                    "1" => Direction::South,
+                    "2" => Direction::West,
+                    "3" => Direction::North,
+                    _ => return None,
+                };
+                let distance = i64::from_str_radix(distance, 16).ok()?;
+                Some(Dig {
+                    direction,
+                    distance,
+                })
+            },
+        ),
+        char(')'),
+    )(input)
+}
+
+fn instruction_parser(input: &str) -> IResult<&str, Instruction> {
+    map(
+        tuple((direction_parser, space1, number, space1, color_parser)),
+        |(direction, _, distance, _, decoded)| Instruction {
+            plain: Dig {
+                direction,
+                distance: i64::from(distance),
+            },
+            decoded,
+        },
+    )(input)
+}
+
+/// Every instruction of the dig plan in `input`, or an error at the first line
+/// that isn't a direction, a distance and a `(#xxxxxd)` colour code whose last
+/// digit is 0 to 3.
+pub fn plan_parser(input: &str) -> Result<Vec<Instruction>> {
+    complete(input, separated_list0(line_ending, instruction_parser))
+}
+
+#[aoc_generator(day18)]
+pub(super) fn input_generator(input: &str) -> Vec<Instruction> {
+    match plan_parser(input) {
+        Ok(plan) => plan,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+/// The number of cubic metres of lava the lagoon dug by `digs` will hold.
+#[must_use]
+pub fn lagoon_volume(digs: impl Iterator<Item = Dig>) -> i128 {
+    //The shoelace formula gives the area enclosed by the centres of the trench
+    //  cubes. Pick's theorem turns that into the number of cubes strictly inside,
+    //  and adding the trench itself gives the whole lagoon.
+    let mut corner = Point::ORIGIN;
+    let mut boundary = 0;
+    let corners: Vec<Point> = digs
+        .map(|dig| {
+            corner += dig.direction.offset() * dig.distance;
+            boundary += i128::from(dig.distance);
+            corner
+        })
+        .collect();
+    interior_points(shoelace(corners), boundary) + boundary
+}
+
+#[aoc(day18, part1)]
+#[must_use]
+pub fn part1(input: &[Instruction]) -> i128 {
+    lagoon_volume(input.iter().map(|instruction| instruction.plain))
+}
+
+#[aoc(day18, part2)]
+#[must_use]
+pub fn part2(input: &[Instruction]) -> i128 {
+    lagoon_volume(input.iter().map(|instruction| instruction.decoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    #[test]
+    fn test_instruction_parser() {
+        let expected = Instruction {
+            plain: Dig {
+                direction: Direction::East,
+                distance: 6,
+            },
+            decoded: Dig {
+                direction: Direction::East,
+                distance: 461_937,
+            },
+        };
+        assert_eq!(instruction_parser("R 6 (#70c710)"), Ok(("", expected)));
+    }
+
+    #[test]
+    fn test_bad_color() {
+        assert!(instruction_parser("R 6 (#70c714)").is_err());
+        assert!(instruction_parser("R 6 (#70c71)").is_err());
+    }
+
+    #[test]
+    fn test_lagoon_volume() {
+        let square = [
+            Direction::East,
+            Direction::South,
+            Direction::West,
+            Direction::North,
+        ]
+        .map(|direction| Dig {
+            direction,
+            distance: 2,
+        });
+        assert_eq!(lagoon_volume(square.into_iter()), 9);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 62);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 952_408_144_115);
+    }
+}
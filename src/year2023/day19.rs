@@ -0,0 +1,407 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, line_ending, multispace1, u64 as number},
+    combinator::{map, value},
+    multi::{many0, separated_list0, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+
+use crate::{
+    prelude::*,
+    util::{graph::topo_sort, parse::complete},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Comparison {
+    Less,
+    Greater,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Rule {
+    category: Category,
+    comparison: Comparison,
+    value: u64,
+    target: Target,
+}
+
+impl Rule {
+    fn matches(&self, part: &Part) -> bool {
+        let rating = part[self.category as usize];
+        match self.comparison {
+            Comparison::Less => rating < self.value,
+            Comparison::Greater => rating > self.value,
+        }
+    }
+
+    /// Splits a half-open rating range into the piece this rule sends to its target
+    /// and the piece that falls through to the next rule.
+    fn split(&self, (start, end): Range) -> (Option<Range>, Option<Range>) {
+        let non_empty = |(start, end): Range| (start < end).then_some((start, end));
+        match self.comparison {
+            Comparison::Less => (
+                non_empty((start, end.min(self.value))),
+                non_empty((start.max(self.value), end)),
+            ),
+            Comparison::Greater => (
+                non_empty((start.max(self.value.saturating_add(1)), end)),
+                non_empty((start, end.min(self.value.saturating_add(1)))),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+    fallback: Target,
+}
+
+impl Workflow {
+    /// The names of the workflows this one can send parts to.
+    fn next(&self) -> impl Iterator<Item = &str> {
+        self.rules
+            .iter()
+            .map(|rule| &rule.target)
+            .chain([&self.fallback])
+            .filter_map(|target| match target {
+                Target::Workflow(name) => Some(name.as_str()),
+                Target::Accept | Target::Reject => None,
+            })
+    }
+}
+
+/// A part's x, m, a and s ratings.
+pub type Part = [u64; 4];
+
+/// A half-open range of ratings.
+type Range = (u64, u64);
+
+/// Ranges of x, m, a and s ratings.
+type Ranges = [Range; 4];
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+impl System {
+    /// The workflows and parts, or an error if a workflow is defined twice, there is
+    /// no `in` workflow, a rule sends parts to a workflow that doesn't exist, or the
+    /// workflows can send a part round in a loop.
+    pub fn new(workflows: Vec<(String, Workflow)>, parts: Vec<Part>) -> Result<Self> {
+        let mut by_name = HashMap::new();
+        for (name, workflow) in workflows {
+            if by_name.contains_key(&name) {
+                return Err(anyhow!("workflow {name} is defined twice"));
+            }
+            by_name.insert(name, workflow);
+        }
+        if !by_name.contains_key("in") {
+            return Err(anyhow!("there is no in workflow"));
+        }
+        let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (name, workflow) in &by_name {
+            let next: Vec<&str> = workflow.next().collect();
+            if let Some(missing) = next.iter().find(|next| !by_name.contains_key(**next)) {
+                return Err(anyhow!("{name} sends parts to unknown workflow {missing}"));
+            }
+            edges.insert(name, next);
+        }
+        if let Err(cycle) = topo_sort(&edges) {
+            return Err(anyhow!("the workflows loop: {}", cycle.join(" -> ")));
+        }
+        Ok(Self {
+            workflows: by_name,
+            parts,
+        })
+    }
+
+    fn workflow(&self, name: &str) -> &Workflow {
+        self.workflows
+            .get(name)
+            .unwrap_or_else(|| panic!("unknown workflow {name}"))
+    }
+
+    /// Runs `part` through the workflows starting at `in`.
+    #[must_use]
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut name = "in";
+        loop {
+            let workflow = self.workflow(name);
+            let target = workflow
+                .rules
+                .iter()
+                .find(|rule| rule.matches(part))
+                .map_or(&workflow.fallback, |rule| &rule.target);
+            match target {
+                Target::Accept => return true,
+                Target::Reject => return false,
+                Target::Workflow(next) => name = next,
+            }
+        }
+    }
+
+    /// The number of rating combinations within `ranges` that `target` accepts.
+    fn accepted_combinations(&self, target: &Target, ranges: Ranges) -> u64 {
+        let name = match target {
+            Target::Accept => return ranges.iter().map(|(start, end)| end - start).product(),
+            Target::Reject => return 0,
+            Target::Workflow(name) => name,
+        };
+
+        let workflow = self.workflow(name);
+        let mut remaining = ranges;
+        let mut total = 0;
+        for rule in &workflow.rules {
+            let category = rule.category as usize;
+            let (matched, unmatched) = rule.split(remaining[category]);
+            if let Some(matched) = matched {
+                let mut sent = remaining;
+                sent[category] = matched;
+                total += self.accepted_combinations(&rule.target, sent);
+            }
+            match unmatched {
+                Some(unmatched) => remaining[category] = unmatched,
+                None => return total,
+            }
+        }
+        total + self.accepted_combinations(&workflow.fallback, remaining)
+    }
+}
+
+fn category_parser(input: &str) -> IResult<&str, Category> {
+    alt((
+        value(Category::X, char('x')),
+        value(Category::M, char('m')),
+        value(Category::A, char('a')),
+        value(Category::S, char('s')),
+    ))(input)
+}
+
+fn target_parser(input: &str) -> IResult<&str, Target> {
+    alt((
+        value(Target::Accept, char('A')),
+        value(Target::Reject, char('R')),
+        map(alpha1, |name: &str| Target::Workflow(name.to_string())),
+    ))(input)
+}
+
+fn rule_parser(input: &str) -> IResult<&str, Rule> {
+    map(
+        tuple((
+            category_parser,
+            alt((
+                value(Comparison::Less, char('<')),
+                value(Comparison::Greater, char('>')),
+            )),
+            number,
+            preceded(char(':'), target_parser),
+        )),
+        |(category, comparison, value, target)| Rule {
+            category,
+            comparison,
+            value,
+            target,
+        },
+    )(input)
+}
+
+fn rules_parser(input: &str) -> IResult<&str, Vec<Rule>> {
+    many0(terminated(rule_parser, char(',')))(input)
+}
+
+fn workflow_parser(input: &str) -> IResult<&str, (String, Workflow)> {
+    map(
+        pair(
+            alpha1,
+            delimited(char('{'), pair(rules_parser, target_parser), char('}')),
+        ),
+        |(name, (rules, fallback))| (name.to_string(), Workflow { rules, fallback }),
+    )(input)
+}
+
+fn part_parser(input: &str) -> IResult<&str, Part> {
+    map(
+        delimited(
+            char('{'),
+            tuple((
+                preceded(tag("x="), number),
+                preceded(tag(",m="), number),
+                preceded(tag(",a="), number),
+                preceded(tag(",s="), number),
+            )),
+            char('}'),
+        ),
+        |(x, m, a, s)| [x, m, a, s],
+    )(input)
+}
+
+/// The workflows and parts in `input`, or an error if they can't be read or
+/// [`System::new`] rejects the workflows.
+pub fn system_parser(input: &str) -> Result<System> {
+    let (workflows, parts) = complete(
+        input,
+        separated_pair(
+            separated_list1(line_ending, workflow_parser),
+            multispace1,
+            separated_list0(line_ending, part_parser),
+        ),
+    )?;
+    System::new(workflows, parts)
+}
+
+#[aoc_generator(day19)]
+pub(super) fn input_generator(input: &str) -> System {
+    match system_parser(input) {
+        Ok(system) => system,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day19, part1)]
+#[must_use]
+pub fn part1(input: &System) -> Option<u64> {
+    input
+        .parts
+        .iter()
+        .filter(|part| input.accepts(part))
+        .try_fold(0_u64, |total, part| {
+            total.checked_add(part.iter().copied().checked_sum()?)
+        })
+}
+
+#[aoc(day19, part2)]
+#[must_use]
+pub fn part2(input: &System) -> u64 {
+    //Rather than trying every combination, send whole ranges of ratings through the
+    //  workflows, splitting them at each rule.
+    input.accepted_combinations(&Target::Workflow("in".to_string()), [(1, 4001); 4])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+    #[test]
+    fn test_workflow_parser() {
+        let expected = Workflow {
+            rules: vec![
+                Rule {
+                    category: Category::A,
+                    comparison: Comparison::Less,
+                    value: 2006,
+                    target: Target::Workflow("qkq".to_string()),
+                },
+                Rule {
+                    category: Category::M,
+                    comparison: Comparison::Greater,
+                    value: 2090,
+                    target: Target::Accept,
+                },
+            ],
+            fallback: Target::Workflow("rfg".to_string()),
+        };
+        assert_eq!(
+            workflow_parser("px{a<2006:qkq,m>2090:A,rfg}"),
+            Ok(("", ("px".to_string(), expected)))
+        );
+    }
+
+    #[test]
+    fn test_system_parser_errors() {
+        let error = |input: &str| system_parser(input).unwrap_err().to_string();
+        assert_eq!(
+            error("in{x>1:zz,A}\n\n{x=1,m=2,a=3,s=4}"),
+            "in sends parts to unknown workflow zz"
+        );
+        assert_eq!(
+            error("in{x>1:px,A}\npx{m<5:in,R}\n\n{x=1,m=2,a=3,s=4}"),
+            "the workflows loop: in -> px"
+        );
+        assert_eq!(error("in{x>1:in,A}\n\n"), "the workflows loop: in");
+        assert_eq!(error("px{x>1:A,R}\n\n"), "there is no in workflow");
+        assert_eq!(error("in{A}\nin{R}\n\n"), "workflow in is defined twice");
+    }
+
+    #[test]
+    fn test_part_parser() {
+        assert_eq!(
+            part_parser("{x=787,m=2655,a=1222,s=2876}"),
+            Ok(("", [787, 2655, 1222, 2876]))
+        );
+    }
+
+    #[test]
+    fn test_rule_split() {
+        let (_, rule) = rule_parser("a<2006:qkq").unwrap();
+        assert_eq!(rule.split((1, 4001)), (Some((1, 2006)), Some((2006, 4001))));
+        assert_eq!(rule.split((3000, 4001)), (None, Some((3000, 4001))));
+
+        let (_, rule) = rule_parser("m>2090:A").unwrap();
+        assert_eq!(rule.split((1, 4001)), (Some((2091, 4001)), Some((1, 2091))));
+    }
+
+    #[test]
+    fn test_accepts() {
+        let system = input_generator(EXAMPLE);
+        let accepted: Vec<bool> = system
+            .parts
+            .iter()
+            .map(|part| system.accepts(part))
+            .collect();
+        assert_eq!(accepted, vec![true, false, true, false, true]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(19114));
+        let huge = "in{x>18446744073709551615:R,A}\n\n{x=18446744073709551615,m=1,a=0,s=0}";
+        assert_eq!(part1(&input_generator(huge)), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 167_409_079_868_000);
+    }
+}
@@ -0,0 +1,623 @@
+use std::{cmp::Ordering, convert::identity, fmt};
+
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, map_opt, value},
+    multi::{fold_many0, separated_list0},
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+#[cfg(feature = "winnow")]
+use winnow::{
+    ascii::{dec_uint, line_ending},
+    combinator::{self as wc, repeat, separated},
+    token::literal,
+    ModalResult, Parser,
+};
+
+#[cfg(feature = "winnow")]
+use crate::util::winnow_parse;
+use crate::{
+    prelude::*,
+    runner,
+    util::parse::{complete_bytes, labeled, lenient, newline, uint, ws},
+};
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Rgb {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl PartialOrd for Rgb {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.red <= other.red && self.green <= other.green && self.blue <= other.blue {
+            if self.red == other.red && self.green == other.green && self.blue == other.blue {
+                Some(Ordering::Equal)
+            } else {
+                Some(Ordering::Less)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Rgb>,
+}
+
+/// Written as the puzzle lists a round, like `4 red, 3 blue`, leaving out colors
+/// with no cubes. A round with no cubes at all lists every color at zero, since
+/// the parser needs at least one count per round.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts = [
+            (self.red, "red"),
+            (self.green, "green"),
+            (self.blue, "blue"),
+        ];
+        let empty = *self == Self::default();
+        let mut shown = counts.iter().filter(|(count, _)| empty || *count > 0);
+        if let Some((count, color)) = shown.next() {
+            write!(f, "{count} {color}")?;
+        }
+        shown.try_for_each(|(count, color)| write!(f, ", {count} {color}"))
+    }
+}
+
+/// Written as the puzzle lists a game, like `Game 3: 4 red; 1 green, 2 blue`.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+        for (i, round) in self.rounds.iter().enumerate() {
+            let separator = if i == 0 { " " } else { "; " };
+            write!(f, "{separator}{round}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Rgb {
+    /// Adds `count` cubes of `color`, or `None` if that color's total overflows.
+    fn add(mut self, (count, color): (u32, Color)) -> Option<Self> {
+        let total = match color {
+            Color::Red => &mut self.red,
+            Color::Green => &mut self.green,
+            Color::Blue => &mut self.blue,
+        };
+        *total = total.checked_add(count)?;
+        Some(self)
+    }
+}
+
+fn color_parser(input: &[u8]) -> IResult<&[u8], Color> {
+    alt((
+        value(Color::Red, tag("red")),
+        value(Color::Blue, tag("blue")),
+        value(Color::Green, tag("green")),
+    ))(input)
+}
+
+fn color_number_parser(input: &[u8]) -> IResult<&[u8], (u32, Color)> {
+    tuple((ws(uint), ws(color_parser)))(input)
+}
+
+//Adds each count into the round as it is read, rather than collecting them first,
+//and fails the round if a color's total overflows.
+fn set_parser(input: &[u8]) -> IResult<&[u8], Rgb> {
+    let (input, first) = color_number_parser(input)?;
+    map_opt(
+        fold_many0(
+            preceded(ws(tag(",")), color_number_parser),
+            move || Rgb::default().add(first),
+            |rgb, count| rgb?.add(count),
+        ),
+        identity,
+    )(input)
+}
+
+fn get_color_set(input: &[u8]) -> IResult<&[u8], Vec<Rgb>> {
+    separated_list0(ws(tag(";")), set_parser)(input)
+}
+
+fn game_parser(input: &[u8]) -> IResult<&[u8], Game> {
+    map(labeled("Game", get_color_set), |(id, rounds)| Game {
+        id,
+        rounds,
+    })(input)
+}
+
+/// Every game in `input`, or an error pointing at the first line that isn't one.
+/// The input is all ASCII, so it is parsed as bytes to skip decoding characters.
+/// With the `winnow` feature this is [`winnow_games_parser`], and otherwise
+/// [`nom_games_parser`].
+pub fn games_parser(input: &str) -> Result<Vec<Game>> {
+    #[cfg(feature = "winnow")]
+    return winnow_games_parser(input);
+    #[cfg(not(feature = "winnow"))]
+    nom_games_parser(input)
+}
+
+/// [`games_parser`] written with nom.
+pub fn nom_games_parser(input: &str) -> Result<Vec<Game>> {
+    complete_bytes(input, separated_list0(newline, game_parser))
+}
+
+#[cfg(feature = "winnow")]
+fn winnow_color(input: &mut &[u8]) -> ModalResult<Color> {
+    wc::alt((
+        literal("red").value(Color::Red),
+        literal("blue").value(Color::Blue),
+        literal("green").value(Color::Green),
+    ))
+    .parse_next(input)
+}
+
+#[cfg(feature = "winnow")]
+fn winnow_color_number(input: &mut &[u8]) -> ModalResult<(u32, Color)> {
+    (winnow_parse::ws(dec_uint), winnow_parse::ws(winnow_color)).parse_next(input)
+}
+
+#[cfg(feature = "winnow")]
+fn winnow_set(input: &mut &[u8]) -> ModalResult<Rgb> {
+    let first = winnow_color_number.parse_next(input)?;
+    repeat(
+        0..,
+        wc::preceded(winnow_parse::ws(','), winnow_color_number),
+    )
+    .fold(
+        move || Rgb::default().add(first),
+        |rgb: Option<Rgb>, count| rgb?.add(count),
+    )
+    .verify_map(identity)
+    .parse_next(input)
+}
+
+#[cfg(feature = "winnow")]
+fn winnow_game(input: &mut &[u8]) -> ModalResult<Game> {
+    winnow_parse::labeled("Game", separated(0.., winnow_set, winnow_parse::ws(';')))
+        .map(|(id, rounds)| Game { id, rounds })
+        .parse_next(input)
+}
+
+/// [`games_parser`] written with winnow.
+#[cfg(feature = "winnow")]
+pub fn winnow_games_parser(input: &str) -> Result<Vec<Game>> {
+    winnow_parse::complete_bytes(input, separated(0.., winnow_game, line_ending))
+}
+
+#[aoc_generator(day2)]
+pub(super) fn input_generator(input: &str) -> Vec<Game> {
+    if runner::lenient() {
+        //Skipping bad lines always goes through nom, whichever backend is built.
+        let (games, skipped) = lenient(input, |line| complete_bytes(line, game_parser));
+        runner::warn(skipped);
+        return games;
+    }
+    match games_parser(input) {
+        Ok(games) => games,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day2, part1)]
+#[must_use]
+pub fn part1(input: &[Game]) -> Option<u32> {
+    let max_cubes = Rgb {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+    input
+        .iter()
+        .filter(|&game| game.rounds.iter().all(|rgb| rgb <= &max_cubes))
+        .map(|game| game.id)
+        .checked_sum()
+}
+
+#[aoc(day2, part2)]
+#[must_use]
+pub fn part2(input: &[Game]) -> Option<u64> {
+    input
+        .iter()
+        .map(|game| {
+            game.rounds.iter().fold(
+                (u32::MIN, u32::MIN, u32::MIN),
+                |(max_red, max_green, max_blue), rgb| {
+                    (
+                        max_red.max(rgb.red),
+                        max_green.max(rgb.green),
+                        max_blue.max(rgb.blue),
+                    )
+                },
+            )
+        })
+        .try_fold(0_u64, |total, (max_red, max_green, max_blue)| {
+            let power = u64::from(max_red) * u64::from(max_green);
+            total.checked_add(power.checked_mul(u64::from(max_blue))?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn test_color_parser() {
+        let input = b"red";
+        assert_eq!(color_parser(input), Ok((&b""[..], Color::Red)));
+    }
+
+    #[test]
+    fn test_color_number_parser() {
+        let input = b"3 blue,";
+        assert_eq!(
+            color_number_parser(input),
+            Ok((&b","[..], (3, Color::Blue)))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let game = Game {
+            id: 3,
+            rounds: vec![
+                Rgb {
+                    red: 20,
+                    green: 8,
+                    blue: 6,
+                },
+                Rgb {
+                    red: 0,
+                    green: 0,
+                    blue: 5,
+                },
+            ],
+        };
+        assert_eq!(game.to_string(), "Game 3: 20 red, 8 green, 6 blue; 5 blue");
+        let empty = Game {
+            id: 4,
+            rounds: vec![Rgb::default()],
+        };
+        assert_eq!(empty.to_string(), "Game 4: 0 red, 0 green, 0 blue");
+        assert_eq!(games_parser(&empty.to_string()).unwrap(), vec![empty]);
+        let example = "Game 1: 4 red, 3 blue; 1 red, 2 green, 6 blue; 2 green";
+        assert_eq!(games_parser(example).unwrap()[0].to_string(), example);
+    }
+
+    prop_compose! {
+        fn rgb()(red in any::<u32>(), green in any::<u32>(), blue in any::<u32>()) -> Rgb {
+            Rgb { red, green, blue }
+        }
+    }
+
+    prop_compose! {
+        fn game()(id in any::<u32>(), rounds in vec(rgb(), 0..5)) -> Game {
+            Game { id, rounds }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(g in game()) {
+            prop_assert_eq!(games_parser(&g.to_string()).unwrap(), vec![g]);
+        }
+    }
+
+    #[test]
+    fn test_set_parser() {
+        assert_eq!(
+            set_parser(b"1 red, 2 green, 6 blue, 3 red; 2 green"),
+            Ok((
+                &b"; 2 green"[..],
+                Rgb {
+                    red: 4,
+                    green: 2,
+                    blue: 6,
+                }
+            ))
+        );
+        assert!(set_parser(b"; 1 red").is_err());
+    }
+
+    #[test]
+    fn test_get_color_set() {
+        let input = b"3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let expected = vec![
+            Rgb {
+                red: 4,
+                green: 0,
+                blue: 3,
+            },
+            Rgb {
+                red: 1,
+                green: 2,
+                blue: 6,
+            },
+            Rgb {
+                red: 0,
+                green: 2,
+                blue: 0,
+            },
+        ];
+        assert_eq!(get_color_set(input), Ok((&b""[..], expected)));
+    }
+
+    #[test]
+    fn test_parse_games() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        let expected = vec![
+            Game {
+                id: 1,
+                rounds: vec![
+                    Rgb {
+                        red: 4,
+                        green: 0,
+                        blue: 3,
+                    },
+                    Rgb {
+                        red: 1,
+                        green: 2,
+                        blue: 6,
+                    },
+                    Rgb {
+                        red: 0,
+                        green: 2,
+                        blue: 0,
+                    },
+                ],
+            },
+            Game {
+                id: 2,
+                rounds: vec![
+                    Rgb {
+                        red: 0,
+                        green: 2,
+                        blue: 1,
+                    },
+                    Rgb {
+                        red: 1,
+                        green: 3,
+                        blue: 4,
+                    },
+                    Rgb {
+                        red: 0,
+                        green: 1,
+                        blue: 1,
+                    },
+                ],
+            },
+            Game {
+                id: 3,
+                rounds: vec![
+                    Rgb {
+                        red: 20,
+                        green: 8,
+                        blue: 6,
+                    },
+                    Rgb {
+                        red: 4,
+                        green: 13,
+                        blue: 5,
+                    },
+                    Rgb {
+                        red: 1,
+                        green: 5,
+                        blue: 0,
+                    },
+                ],
+            },
+            Game {
+                id: 4,
+                rounds: vec![
+                    Rgb {
+                        red: 3,
+                        green: 1,
+                        blue: 6,
+                    },
+                    Rgb {
+                        red: 6,
+                        green: 3,
+                        blue: 0,
+                    },
+                    Rgb {
+                        red: 14,
+                        green: 3,
+                        blue: 15,
+                    },
+                ],
+            },
+            Game {
+                id: 5,
+                rounds: vec![
+                    Rgb {
+                        red: 6,
+                        green: 3,
+                        blue: 1,
+                    },
+                    Rgb {
+                        red: 1,
+                        green: 2,
+                        blue: 2,
+                    },
+                ],
+            },
+        ];
+
+        assert_eq!(games_parser(input).unwrap(), expected);
+        //cargo-aoc hands generators the file as it is, Windows line endings and all.
+        let windows = input.replace('\n', "\r\n") + "\r\n";
+        assert_eq!(games_parser(&windows).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_games_error() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue; 3 purple";
+        let error = games_parser(input).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "unexpected input at line 2, column 15:\nGame 2: 1 blue; 3 purple\n              ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_games_overflow() {
+        assert!(games_parser("Game 1: 4294967295 red, 1 red").is_err());
+        let most = games_parser("Game 1: 4294967294 red, 1 red").unwrap();
+        assert_eq!(most[0].rounds[0].red, u32::MAX);
+    }
+
+    #[cfg(feature = "winnow")]
+    #[test]
+    fn test_winnow_matches_nom() {
+        let inputs = [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\r\nGame 2: 1 blue\n",
+            "Game 4: 0 red, 0 green, 0 blue",
+            "Game 5:",
+            "",
+            "Game 1: 3 blue, 4 red\nGame 2: 1 blue; 3 purple",
+            "Game x: 3 blue",
+            "Game 1: 3 blue,",
+        ];
+        for input in inputs {
+            let nom = nom_games_parser(input).map_err(|e| e.to_string());
+            let winnow = winnow_games_parser(input).map_err(|e| e.to_string());
+            assert_eq!(nom, winnow, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = vec![
+            Game {
+                id: 1,
+                rounds: vec![
+                    Rgb {
+                        red: 4,
+                        green: 0,
+                        blue: 3,
+                    },
+                    Rgb {
+                        red: 1,
+                        green: 2,
+                        blue: 6,
+                    },
+                    Rgb {
+                        red: 0,
+                        green: 2,
+                        blue: 0,
+                    },
+                ],
+            },
+            Game {
+                id: 2,
+                rounds: vec![
+                    Rgb {
+                        red: 0,
+                        green: 2,
+                        blue: 1,
+                    },
+                    Rgb {
+                        red: 1,
+                        green: 3,
+                        blue: 4,
+                    },
+                    Rgb {
+                        red: 0,
+                        green: 1,
+                        blue: 1,
+                    },
+                ],
+            },
+            Game {
+                id: 3,
+                rounds: vec![
+                    Rgb {
+                        red: 20,
+                        green: 8,
+                        blue: 6,
+                    },
+                    Rgb {
+                        red: 4,
+                        green: 13,
+                        blue: 5,
+                    },
+                    Rgb {
+                        red: 1,
+                        green: 5,
+                        blue: 0,
+                    },
+                ],
+            },
+            Game {
+                id: 4,
+                rounds: vec![
+                    Rgb {
+                        red: 3,
+                        green: 1,
+                        blue: 6,
+                    },
+                    Rgb {
+                        red: 6,
+                        green: 3,
+                        blue: 0,
+                    },
+                    Rgb {
+                        red: 14,
+                        green: 3,
+                        blue: 15,
+                    },
+                ],
+            },
+            Game {
+                id: 5,
+                rounds: vec![
+                    Rgb {
+                        red: 6,
+                        green: 3,
+                        blue: 1,
+                    },
+                    Rgb {
+                        red: 1,
+                        green: 2,
+                        blue: 2,
+                    },
+                ],
+            },
+        ];
+
+        let result = part1(input.as_slice());
+
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn part_2_test() -> Result<()> {
+        let input = crate::input::load(2023, 2)?;
+        let games = games_parser(&input)?;
+        let result = part2(&games);
+        assert_eq!(result, Some(62_241));
+        Ok(())
+    }
+}
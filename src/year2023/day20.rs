@@ -0,0 +1,342 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{anyhow, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, line_ending},
+    combinator::{opt, value},
+    multi::{separated_list0, separated_list1},
+    sequence::{pair, separated_pair},
+    IResult,
+};
+
+use crate::util::{math, parse::complete};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ModuleKind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// A module that is only ever sent pulses, such as `rx`.
+    Sink,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Pulse {
+    from: usize,
+    to: usize,
+    high: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Machine {
+    names: Vec<String>,
+    kinds: Vec<ModuleKind>,
+    outputs: Vec<Vec<usize>>,
+    inputs: Vec<Vec<usize>>,
+    broadcaster: usize,
+}
+
+/// The remembered state of every module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MachineState {
+    on: Vec<bool>,
+    /// The last pulse each conjunction received from each of its inputs, aligned with
+    /// `Machine::inputs`.
+    memory: Vec<Vec<bool>>,
+}
+
+impl Machine {
+    fn new(modules: &[(ModuleKind, &str, Vec<&str>)]) -> Result<Self> {
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        let mut names = Vec::new();
+        let mut kinds = Vec::new();
+        for (kind, name, destinations) in modules {
+            if index.insert(name, names.len()).is_some() {
+                return Err(anyhow!("module {name} is defined twice"));
+            }
+            //A conjunction remembers one pulse per input, so each wire must be single.
+            if let Some(twice) = destinations
+                .iter()
+                .enumerate()
+                .find_map(|(i, to)| destinations[..i].contains(to).then_some(to))
+            {
+                return Err(anyhow!("module {name} sends to {twice} twice"));
+            }
+            names.push(name.to_string());
+            kinds.push(*kind);
+        }
+        //Anything that only appears as a destination is a sink.
+        for (_, _, destinations) in modules {
+            for &name in destinations {
+                index.entry(name).or_insert_with(|| {
+                    names.push(name.to_string());
+                    kinds.push(ModuleKind::Sink);
+                    names.len() - 1
+                });
+            }
+        }
+
+        let mut outputs = vec![Vec::new(); names.len()];
+        let mut inputs = vec![Vec::new(); names.len()];
+        for (_, name, destinations) in modules {
+            let from = index[name];
+            for destination in destinations {
+                let to = index[destination];
+                outputs[from].push(to);
+                inputs[to].push(from);
+            }
+        }
+
+        let broadcaster = kinds
+            .iter()
+            .position(|&kind| kind == ModuleKind::Broadcaster)
+            .ok_or_else(|| anyhow!("no broadcaster module"))?;
+        Ok(Self {
+            names,
+            kinds,
+            outputs,
+            inputs,
+            broadcaster,
+        })
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|module| module == name)
+    }
+
+    #[must_use]
+    pub fn initial_state(&self) -> MachineState {
+        MachineState {
+            on: vec![false; self.names.len()],
+            memory: self
+                .inputs
+                .iter()
+                .map(|inputs| vec![false; inputs.len()])
+                .collect(),
+        }
+    }
+
+    /// Pushes the button once, passing every pulse sent to `observe` in the order
+    /// they are processed.
+    pub fn press(&self, state: &mut MachineState, mut observe: impl FnMut(Pulse)) {
+        let mut queue = VecDeque::from([Pulse {
+            from: self.broadcaster,
+            to: self.broadcaster,
+            high: false,
+        }]);
+        while let Some(pulse) = queue.pop_front() {
+            observe(pulse);
+            let module = pulse.to;
+            let sent = match self.kinds[module] {
+                ModuleKind::Broadcaster => Some(pulse.high),
+                //Flip-flops ignore high pulses and toggle on low ones.
+                ModuleKind::FlipFlop if pulse.high => None,
+                ModuleKind::FlipFlop => {
+                    state.on[module] = !state.on[module];
+                    Some(state.on[module])
+                }
+                //Conjunctions send low only once every input last sent high.
+                ModuleKind::Conjunction => {
+                    let slot = self.inputs[module]
+                        .iter()
+                        .position(|&input| input == pulse.from)
+                        .expect("pulse from a module that is not an input");
+                    state.memory[module][slot] = pulse.high;
+                    Some(!state.memory[module].iter().all(|&high| high))
+                }
+                ModuleKind::Sink => None,
+            };
+            if let Some(high) = sent {
+                queue.extend(self.outputs[module].iter().map(|&to| Pulse {
+                    from: module,
+                    to,
+                    high,
+                }));
+            }
+        }
+    }
+}
+
+fn module_parser(input: &str) -> IResult<&str, (ModuleKind, &str, Vec<&str>)> {
+    let (input, ((prefix, name), destinations)) = separated_pair(
+        pair(
+            opt(alt((
+                value(ModuleKind::FlipFlop, char('%')),
+                value(ModuleKind::Conjunction, char('&')),
+            ))),
+            alpha1,
+        ),
+        tag(" -> "),
+        separated_list1(tag(", "), alpha1),
+    )(input)?;
+
+    let kind = match (prefix, name) {
+        (Some(kind), _) => kind,
+        (None, "broadcaster") => ModuleKind::Broadcaster,
+        (None, _) => ModuleKind::Sink,
+    };
+    Ok((input, (kind, name, destinations)))
+}
+
+/// The modules and their wiring in `input`, or an error at the first line that
+/// isn't a module and its destinations, or if a module is defined twice or sends to
+/// the same destination twice.
+pub fn machine_parser(input: &str) -> Result<Machine> {
+    Machine::new(&complete(
+        input,
+        separated_list0(line_ending, module_parser),
+    )?)
+}
+
+#[aoc_generator(day20)]
+pub(super) fn input_generator(input: &str) -> Machine {
+    match machine_parser(input) {
+        Ok(machine) => machine,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day20, part1)]
+#[must_use]
+pub fn part1(input: &Machine) -> u64 {
+    let mut state = input.initial_state();
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..1000 {
+        input.press(&mut state, |pulse| {
+            if pulse.high {
+                high += 1;
+            } else {
+                low += 1;
+            }
+        });
+    }
+    low * high
+}
+
+#[aoc(day20, part2)]
+#[must_use]
+pub fn part2(input: &Machine) -> Option<u64> {
+    //`rx` is fed by a single conjunction, which only sends it a low pulse when all of
+    //  its own inputs have just sent it high. Each of those inputs is the end of an
+    //  independent counter that fires on a fixed cycle, so find each cycle length
+    //  and combine them.
+    let rx = input.find("rx")?;
+    let [feeder] = input.inputs[rx][..] else {
+        return None;
+    };
+    let watched = &input.inputs[feeder];
+    let mut cycles: Vec<Option<u64>> = vec![None; watched.len()];
+
+    let mut state = input.initial_state();
+    for presses in 1..=100_000 {
+        input.press(&mut state, |pulse| {
+            if pulse.to == feeder && pulse.high {
+                if let Some(slot) = watched.iter().position(|&input| input == pulse.from) {
+                    cycles[slot].get_or_insert(presses);
+                }
+            }
+        });
+        if cycles.iter().all(Option::is_some) {
+            return cycles
+                .into_iter()
+                .collect::<Option<Vec<u64>>>()
+                .map(math::lcm_all);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIMPLE: &str = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+    const INTERESTING: &str = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+    /// Two flip-flop counters of different lengths feeding `rx` through conjunctions.
+    const COUNTERS: &str = "broadcaster -> aa, ba
+%aa -> ab, ea
+%ab -> ea
+&ea -> ia
+&ia -> hub
+%ba -> bb, eb
+%bb -> bc, eb
+%bc -> eb
+&eb -> ib
+&ib -> hub
+&hub -> rx
+";
+
+    #[test]
+    fn test_module_parser() {
+        assert_eq!(
+            module_parser("%a -> inv, con"),
+            Ok(("", (ModuleKind::FlipFlop, "a", vec!["inv", "con"])))
+        );
+        assert_eq!(
+            module_parser("broadcaster -> a"),
+            Ok(("", (ModuleKind::Broadcaster, "broadcaster", vec!["a"])))
+        );
+    }
+
+    #[test]
+    fn test_sinks() {
+        let machine = input_generator(INTERESTING);
+        let output = machine.find("output").unwrap();
+        assert_eq!(machine.kinds[output], ModuleKind::Sink);
+    }
+
+    #[test]
+    fn test_missing_broadcaster() {
+        assert!(machine_parser("%a -> b\n").is_err());
+    }
+
+    #[test]
+    fn test_repeated_wiring() {
+        let error = |input: &str| machine_parser(input).unwrap_err().to_string();
+        assert_eq!(
+            error("broadcaster -> a\n%a -> b\n%a -> b\n&b -> rx"),
+            "module a is defined twice"
+        );
+        assert_eq!(
+            error("broadcaster -> a\n%a -> b, b\n&b -> rx"),
+            "module a sends to b twice"
+        );
+    }
+
+    #[test]
+    fn test_press() {
+        let machine = input_generator(SIMPLE);
+        let mut state = machine.initial_state();
+        let mut pulses = 0;
+        machine.press(&mut state, |_| pulses += 1);
+        assert_eq!(pulses, 12);
+        assert_eq!(state, machine.initial_state());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(SIMPLE)), 32_000_000);
+        assert_eq!(part1(&input_generator(INTERESTING)), 11_687_500);
+    }
+
+    #[test]
+    fn test_part2() {
+        //The two-bit counter completes every 3 presses and the three-bit one every 7.
+        assert_eq!(part2(&input_generator(COUNTERS)), Some(21));
+        assert_eq!(part2(&input_generator(SIMPLE)), None);
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::Result;
+
+use crate::util::{direction::Direction, grid::Grid, point::Point, polynomial::extrapolate};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Garden {
+    tiles: Grid<u8>,
+    start: Point,
+}
+
+impl Garden {
+    /// Whether `plot` on the infinitely tiled map holds a rock.
+    fn is_rock(&self, plot: Point) -> bool {
+        let x = plot.x.rem_euclid(self.tiles.width() as i64);
+        let y = plot.y.rem_euclid(self.tiles.height() as i64);
+        self.tiles[Point::new(x, y)] == b'#'
+    }
+
+    /// The fewest steps needed to reach every plot within `steps` of the start on the
+    /// infinitely tiled map, as a square of side `2 * steps + 1` centred on the start.
+    fn distances(&self, steps: usize) -> Grid<Option<usize>> {
+        let side = 2 * steps + 1;
+        let centre = Point::from((steps, steps));
+        let mut distances = Grid::new(side, side, None);
+        let mut queue = VecDeque::from([centre]);
+        distances[centre] = Some(0);
+        while let Some(point) = queue.pop_front() {
+            let distance = distances[point].unwrap_or_default();
+            if distance == steps {
+                continue;
+            }
+            //Staying within `steps` of the start means never leaving the square.
+            for direction in Direction::ORTHOGONAL {
+                let next = point + direction.offset();
+                let slot = &mut distances[next];
+                if slot.is_none() && !self.is_rock(self.start + next - centre) {
+                    *slot = Some(distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    /// The number of plots the elf could be standing on after exactly `steps` steps
+    /// on the infinitely tiled map.
+    #[must_use]
+    pub fn reachable(&self, steps: usize) -> u64 {
+        //Anywhere reachable in fewer steps of the same parity can be reached in
+        //  exactly `steps` by stepping back and forth.
+        self.distances(steps)
+            .rows()
+            .flatten()
+            .flatten()
+            .filter(|&&distance| distance % 2 == steps % 2)
+            .count() as u64
+    }
+
+    /// A slow but obviously correct simulation of the elf's steps, tracking every
+    /// plot it could be on after each one.
+    #[must_use]
+    pub fn simulate(&self, steps: usize) -> usize {
+        let mut plots = HashSet::from([self.start]);
+        for _ in 0..steps {
+            plots = plots
+                .iter()
+                .flat_map(|&plot| Direction::ORTHOGONAL.map(|direction| plot + direction.offset()))
+                .filter(|&plot| !self.is_rock(plot))
+                .collect();
+        }
+        plots.len()
+    }
+
+    /// The number of plots reachable in exactly `steps` steps, extrapolated from
+    /// the counts a whole number of map widths apart.
+    ///
+    /// This relies on the map being square with a clear path from the start to
+    /// each edge, so that the reachable area grows quadratically with the number of
+    /// maps crossed.
+    #[must_use]
+    pub fn extrapolated_reachable(&self, steps: usize) -> Option<u64> {
+        if self.tiles.width() != self.tiles.height() {
+            return None;
+        }
+        let size = self.tiles.width();
+        let (maps, remainder) = (steps / size, steps % size);
+        if maps < 3 {
+            return Some(self.reachable(steps));
+        }
+        let samples = [0, 1, 2].map(|maps| self.reachable(remainder + maps * size) as i64);
+        u64::try_from(extrapolate(&samples, maps as i64)?).ok()
+    }
+}
+
+/// The garden map in `input`, or an error at a tile other than `.`, `#` or `S`, at
+/// a row of a different width, or if there is no `S` to start from.
+pub fn garden_parser(input: &str) -> Result<Garden> {
+    let tiles = Grid::parse(input, |c| match c {
+        b'.' | b'#' | b'S' => Ok(c),
+        _ => Err(anyhow::anyhow!("unexpected {:?}", char::from(c))),
+    })?;
+    let start = tiles
+        .enumerate()
+        .find(|&(_, &tile)| tile == b'S')
+        .map(|(point, _)| point)
+        .ok_or_else(|| anyhow::anyhow!("no starting position"))?;
+    Ok(Garden { tiles, start })
+}
+
+#[aoc_generator(day21)]
+pub(super) fn input_generator(input: &str) -> Garden {
+    match garden_parser(input) {
+        Ok(garden) => garden,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day21, part1)]
+#[must_use]
+pub fn part1(input: &Garden) -> u64 {
+    //64 steps never leaves the first copy of the map.
+    input.reachable(64)
+}
+
+#[aoc(day21, part2)]
+#[must_use]
+pub fn part2(input: &Garden) -> Option<u64> {
+    input.extrapolated_reachable(26_501_365)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    /// Like the real puzzle input: square, with clear lanes from the start to each
+    /// edge and all around the border.
+    const LANES: &str = "...........
+.##.....#..
+..#....##..
+.#.......#.
+...#...#...
+.....S.....
+..#....#...
+.##.....#..
+...#.......
+.#......##.
+...........";
+
+    #[test]
+    fn test_garden_parser() {
+        let garden = garden_parser("#.\n.S").unwrap();
+        assert!(garden.is_rock(Point::new(0, 0)));
+        assert!(!garden.is_rock(Point::new(1, 1)));
+        assert!(garden.is_rock(Point::new(-2, 2)));
+        assert_eq!(garden.start, Point::new(1, 1));
+        assert!(garden_parser("..\n.").is_err());
+        assert!(garden_parser("..\n..").is_err());
+        assert!(garden_parser(".x").is_err());
+    }
+
+    #[test]
+    fn test_simulate() {
+        let garden = input_generator(EXAMPLE);
+        assert_eq!(garden.simulate(6), 16);
+        assert_eq!(garden.simulate(10), 50);
+        assert_eq!(garden.simulate(50), 1594);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let garden = input_generator(EXAMPLE);
+        for steps in [6, 10, 50, 100] {
+            assert_eq!(garden.reachable(steps), garden.simulate(steps) as u64);
+        }
+        assert_eq!(garden.reachable(100), 6536);
+        assert_eq!(garden.reachable(1000), 668_697);
+    }
+
+    #[test]
+    fn test_extrapolated_reachable() {
+        let garden = input_generator(LANES);
+        for steps in [5, 38, 71, 120] {
+            assert_eq!(
+                garden.extrapolated_reachable(steps),
+                Some(garden.simulate(steps) as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(input_generator(EXAMPLE).reachable(6), 16);
+    }
+}
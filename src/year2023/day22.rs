@@ -0,0 +1,212 @@
+use std::collections::VecDeque;
+
+use anyhow::Result;
+use nom::{
+    character::complete::{char, line_ending, u32 as number},
+    combinator::map,
+    multi::separated_list0,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+
+use crate::util::{aabb::Aabb3, parse::complete, point3::Point3};
+
+/// The bricks once they have all fallen as far as they can, along with which rest
+/// on which.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stack {
+    bricks: Vec<Aabb3>,
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Lets `bricks` fall. This keeps a height for every cell of the floor, so the
+    /// floor should be no bigger than [`snapshot_parser`] allows.
+    #[must_use]
+    pub fn settle(mut bricks: Vec<Aabb3>) -> Self {
+        //Dropping the bricks lowest first means everything beneath one has already
+        //  landed by the time it falls.
+        bricks.sort_by_key(|brick| brick.min.z);
+        let width = bricks.iter().map(|brick| brick.max.x as usize + 1).max();
+        let depth = bricks.iter().map(|brick| brick.max.y as usize + 1).max();
+        let (width, depth) = (width.unwrap_or(0), depth.unwrap_or(0));
+
+        //The height of the top of the pile above each cell, and the brick there.
+        let mut tops: Vec<Vec<(i64, Option<usize>)>> = vec![vec![(0, None); depth]; width];
+        let mut supports = vec![Vec::new(); bricks.len()];
+        let mut supported_by = vec![Vec::new(); bricks.len()];
+        for (index, brick) in bricks.iter_mut().enumerate() {
+            //The `(x, y)` cells the brick covers when seen from above.
+            let footprint = brick.footprint();
+            let cells = || {
+                footprint
+                    .points()
+                    .map(|cell| (cell.x as usize, cell.y as usize))
+            };
+            let floor = cells().map(|(x, y)| tops[x][y].0).max().unwrap_or(0);
+            for (x, y) in cells() {
+                if let (height, Some(below)) = tops[x][y] {
+                    if height == floor && !supported_by[index].contains(&below) {
+                        supported_by[index].push(below);
+                        supports[below].push(index);
+                    }
+                }
+            }
+
+            *brick = brick.shift(Point3::new(0, 0, floor + 1 - brick.min.z));
+            for (x, y) in cells() {
+                tops[x][y] = (brick.max.z, Some(index));
+            }
+        }
+        Self {
+            bricks,
+            supports,
+            supported_by,
+        }
+    }
+
+    /// Whether `brick` can be removed without any other brick falling.
+    #[must_use]
+    pub fn is_safe(&self, brick: usize) -> bool {
+        self.supports[brick]
+            .iter()
+            .all(|&above| self.supported_by[above].len() > 1)
+    }
+
+    /// The number of other bricks that would fall if `brick` were removed.
+    #[must_use]
+    pub fn chain_reaction(&self, brick: usize) -> usize {
+        //A brick falls once every brick it rests on has fallen.
+        let mut remaining: Vec<usize> = self.supported_by.iter().map(Vec::len).collect();
+        let mut falling = VecDeque::from([brick]);
+        let mut fallen = 0;
+        while let Some(brick) = falling.pop_front() {
+            for &above in &self.supports[brick] {
+                remaining[above] -= 1;
+                if remaining[above] == 0 {
+                    fallen += 1;
+                    falling.push_back(above);
+                }
+            }
+        }
+        fallen
+    }
+}
+
+fn position_parser(input: &str) -> IResult<&str, Point3> {
+    map(
+        tuple((number, char(','), number, char(','), number)),
+        |(x, _, y, _, z)| Point3::new(x.into(), y.into(), z.into()),
+    )(input)
+}
+
+fn brick_parser(input: &str) -> IResult<&str, Aabb3> {
+    map(
+        separated_pair(position_parser, char('~'), position_parser),
+        |(a, b)| Aabb3::new(a, b),
+    )(input)
+}
+
+/// The most cells of floor [`Stack::settle`] keeps heights for. The puzzle's floor
+/// is 10 by 10.
+pub const MAX_FLOOR: i64 = 1 << 16;
+
+/// Every brick in the snapshot in `input`, before it settles, or an error at the
+/// first line that isn't two corners `x,y,z~x,y,z`, or if the bricks cover a floor
+/// of more than [`MAX_FLOOR`] cells.
+pub fn snapshot_parser(input: &str) -> Result<Vec<Aabb3>> {
+    let bricks = complete(input, separated_list0(line_ending, brick_parser))?;
+    if let Some(brick) = bricks.iter().find(|brick| brick.min.z == 0) {
+        return Err(anyhow::anyhow!("brick {brick:?} is in the ground"));
+    }
+    let floor = |axis: fn(&Aabb3) -> i64| bricks.iter().map(axis).max().map_or(0, |max| max + 1);
+    let (width, depth) = (floor(|brick| brick.max.x), floor(|brick| brick.max.y));
+    if width.saturating_mul(depth) > MAX_FLOOR {
+        return Err(anyhow::anyhow!(
+            "the bricks cover a {width} by {depth} floor, more than {MAX_FLOOR} cells"
+        ));
+    }
+    Ok(bricks)
+}
+
+#[aoc_generator(day22)]
+pub(super) fn input_generator(input: &str) -> Stack {
+    match snapshot_parser(input) {
+        Ok(bricks) => Stack::settle(bricks),
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day22, part1)]
+#[must_use]
+pub fn part1(input: &Stack) -> usize {
+    (0..input.bricks.len())
+        .filter(|&brick| input.is_safe(brick))
+        .count()
+}
+
+#[aoc(day22, part2)]
+#[must_use]
+pub fn part2(input: &Stack) -> usize {
+    (0..input.bricks.len())
+        .map(|brick| input.chain_reaction(brick))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    #[test]
+    fn test_brick_parser() {
+        assert_eq!(
+            brick_parser("1,2,9~1,0,8"),
+            Ok(("", Aabb3::new(Point3::new(1, 0, 8), Point3::new(1, 2, 9))))
+        );
+        assert!(snapshot_parser("1,0,0~1,2,0").is_err());
+        let wide = snapshot_parser("9999999,9,1~9999999,9,4").unwrap_err();
+        assert_eq!(
+            wide.to_string(),
+            "the bricks cover a 10000000 by 10 floor, more than 65536 cells"
+        );
+        assert!(snapshot_parser("4294967295,4294967295,1~4294967295,4294967295,1").is_err());
+    }
+
+    #[test]
+    fn test_settle() {
+        let stack = input_generator(EXAMPLE);
+        let heights: Vec<i64> = stack.bricks.iter().map(|brick| brick.min.z).collect();
+        assert_eq!(heights, vec![1, 2, 2, 3, 3, 4, 5]);
+        assert_eq!(stack.supported_by[0], Vec::<usize>::new());
+        assert_eq!(stack.supports[0], vec![1, 2]);
+        assert_eq!(stack.supported_by[3], vec![1, 2]);
+    }
+
+    #[test]
+    fn test_chain_reaction() {
+        let stack = input_generator(EXAMPLE);
+        assert_eq!(stack.chain_reaction(0), 6);
+        assert_eq!(stack.chain_reaction(5), 1);
+        assert_eq!(stack.chain_reaction(6), 0);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 5);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 7);
+    }
+}
@@ -0,0 +1,243 @@
+use anyhow::Result;
+
+use crate::util::{direction::Direction, grid::Grid, point::Point};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map {
+    tiles: Grid<u8>,
+    start: Point,
+    end: Point,
+}
+
+/// The map boiled down to its junctions, with the length of each trail between
+/// them.
+#[derive(Debug, PartialEq, Eq)]
+pub struct JunctionGraph {
+    edges: Vec<Vec<(usize, u32)>>,
+    start: usize,
+    end: usize,
+}
+
+impl Map {
+    fn step(&self, position: Point, direction: Direction) -> Option<Point> {
+        let next = position + direction.offset();
+        self.tiles
+            .get(next)
+            .is_some_and(|&tile| tile != b'#')
+            .then_some(next)
+    }
+
+    fn is_junction(&self, position: Point) -> bool {
+        position == self.start
+            || position == self.end
+            || Direction::ORTHOGONAL
+                .into_iter()
+                .filter(|&direction| self.step(position, direction).is_some())
+                .count()
+                > 2
+    }
+
+    /// Follows the trail leaving `junction` by `first` to the next junction, giving
+    /// up on dead ends and, if `slippery`, on trails that go up a slope.
+    fn follow(&self, junction: Point, first: Direction, slippery: bool) -> Option<(Point, u32)> {
+        let (mut previous, mut direction) = (junction, first);
+        let mut length = 0;
+        loop {
+            //A slope is drawn as the arrow pointing the only way off it.
+            let tile = self.tiles[previous];
+            if slippery && tile != b'.' && Direction::from_char(char::from(tile)) != Some(direction)
+            {
+                return None;
+            }
+            let current = self.step(previous, direction)?;
+            length += 1;
+            if self.is_junction(current) {
+                return Some((current, length));
+            }
+            //Away from junctions there is only one way on that isn't back.
+            direction = Direction::ORTHOGONAL.into_iter().find(|&direction| {
+                self.step(current, direction)
+                    .is_some_and(|next| next != previous)
+            })?;
+            previous = current;
+        }
+    }
+
+    #[must_use]
+    pub fn junction_graph(&self, slippery: bool) -> JunctionGraph {
+        let junctions: Vec<Point> = self
+            .tiles
+            .enumerate()
+            .filter(|&(point, &tile)| tile != b'#' && self.is_junction(point))
+            .map(|(point, _)| point)
+            .collect();
+        let index = |position| {
+            junctions
+                .iter()
+                .position(|&junction| junction == position)
+                .expect("trails end at junctions")
+        };
+
+        let edges = junctions
+            .iter()
+            .map(|&junction| {
+                Direction::ORTHOGONAL
+                    .into_iter()
+                    .filter_map(|direction| self.follow(junction, direction, slippery))
+                    .map(|(next, length)| (index(next), length))
+                    .collect()
+            })
+            .collect();
+        JunctionGraph {
+            edges,
+            start: index(self.start),
+            end: index(self.end),
+        }
+    }
+}
+
+impl JunctionGraph {
+    /// The length of the longest path from start to end that never visits a
+    /// junction twice, or `None` if there isn't one or there are more junctions than
+    /// the 64 the visited mask can hold.
+    #[must_use]
+    pub fn longest_path(&self) -> Option<u32> {
+        if self.edges.len() > 64 {
+            return None;
+        }
+        //The end has only one way in, so once at the junction before it, any path
+        //  that doesn't go straight there can never reach it.
+        let last = self.edges.iter().enumerate().find_map(|(junction, edges)| {
+            edges
+                .iter()
+                .find(|&&(next, _)| next == self.end)
+                .map(|&(_, length)| (junction, length))
+        });
+        self.search(self.start, 1 << self.start, last)
+    }
+
+    fn search(&self, junction: usize, visited: u64, last: Option<(usize, u32)>) -> Option<u32> {
+        if junction == self.end {
+            return Some(0);
+        }
+        if let Some((last, length)) = last {
+            if junction == last {
+                return Some(length);
+            }
+        }
+        self.edges[junction]
+            .iter()
+            .filter(|&&(next, _)| visited & (1 << next) == 0)
+            .filter_map(|&(next, length)| {
+                Some(length + self.search(next, visited | (1 << next), last)?)
+            })
+            .max()
+    }
+}
+
+/// The map of hiking trails in `input`, or an error at a tile other than a path,
+/// forest or slope, at a row of a different width, or if there is no path in
+/// through the top row or out through the bottom.
+pub fn map_parser(input: &str) -> Result<Map> {
+    let tiles = Grid::parse(input, |tile| {
+        if b".#^v<>".contains(&tile) {
+            Ok(tile)
+        } else {
+            Err(anyhow::anyhow!("unexpected tile {:?}", char::from(tile)))
+        }
+    })?;
+    let opening = |y: usize| {
+        let x = tiles.rows().nth(y)?.iter().position(|&tile| tile == b'.')?;
+        Some(Point::from((x, y)))
+    };
+    let start = opening(0).ok_or_else(|| anyhow::anyhow!("no way in on the top row"))?;
+    let end = opening(tiles.height().saturating_sub(1))
+        .ok_or_else(|| anyhow::anyhow!("no way out on the bottom row"))?;
+    Ok(Map { tiles, start, end })
+}
+
+#[aoc_generator(day23)]
+pub(super) fn input_generator(input: &str) -> Map {
+    match map_parser(input) {
+        Ok(map) => map,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day23, part1)]
+#[must_use]
+pub fn part1(input: &Map) -> Option<u32> {
+    input.junction_graph(true).longest_path()
+}
+
+#[aoc(day23, part2)]
+#[must_use]
+pub fn part2(input: &Map) -> Option<u32> {
+    //Without the slopes the trails can be walked either way.
+    input.junction_graph(false).longest_path()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn test_map_parser() {
+        let map = map_parser("#.#\n#.#\n#.#").unwrap();
+        assert_eq!((map.start, map.end), (Point::new(1, 0), Point::new(1, 2)));
+        assert!(map_parser("#.#\n#.").is_err());
+        assert!(map_parser("#.#\n#x#").is_err());
+        assert!(map_parser("###\n#.#").is_err());
+    }
+
+    #[test]
+    fn test_junction_graph() {
+        let map = input_generator(EXAMPLE);
+        //The start, the end and seven junctions in between.
+        assert_eq!(map.junction_graph(false).edges.len(), 9);
+        let slippery = map.junction_graph(true);
+        let edges: usize = slippery.edges.iter().map(Vec::len).sum();
+        let both_ways: usize = map.junction_graph(false).edges.iter().map(Vec::len).sum();
+        assert_eq!(both_ways, 2 * edges);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(94));
+    }
+
+    #[test]
+    fn test_too_many_junctions() {
+        let field = vec![".".repeat(21); 21].join("\n");
+        assert_eq!(part1(&input_generator(&field)), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(154));
+    }
+}
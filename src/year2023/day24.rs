@@ -0,0 +1,261 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, i64 as number, line_ending, space0},
+    combinator::map,
+    multi::separated_list0,
+    sequence::{delimited, separated_pair, tuple},
+    IResult,
+};
+
+use crate::{
+    prelude::*,
+    util::{combinatorics::pairs, linalg::solve_integers, parse::complete, rational::Ratio},
+};
+
+type Vector = [i128; 3];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Hailstone {
+    position: Vector,
+    velocity: Vector,
+}
+
+fn sub(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// The cross product, or `None` if it won't fit in an `i128`.
+fn cross(a: Vector, b: Vector) -> Option<Vector> {
+    let term = |i: usize, j: usize| a[i].checked_mul(b[j])?.checked_sub(a[j].checked_mul(b[i])?);
+    Some([term(1, 2)?, term(2, 0)?, term(0, 1)?])
+}
+
+/// The dot product, or `None` if it won't fit in an `i128`.
+fn dot(a: Vector, b: Vector) -> Option<i128> {
+    (0..3).try_fold(0_i128, |total, axis| {
+        total.checked_add(a[axis].checked_mul(b[axis])?)
+    })
+}
+
+impl Hailstone {
+    /// Whether the paths of two hailstones, ignoring the z axis, cross in the future
+    /// within the square from `min` to `max` on each axis.
+    #[must_use]
+    pub fn crosses_within(&self, other: &Self, min: i128, max: i128) -> bool {
+        //Solve `p + t * v = q + s * u` for the times `t` and `s` of each hailstone.
+        let rows: Vec<Vec<i128>> = (0..2)
+            .map(|axis| {
+                vec![
+                    self.velocity[axis],
+                    -other.velocity[axis],
+                    other.position[axis] - self.position[axis],
+                ]
+            })
+            .collect();
+        let Some(times) = solve_integers(&rows) else {
+            return false;
+        };
+        if times.iter().any(|&time| time < Ratio::ZERO) {
+            return false;
+        }
+        (0..2).all(|axis| {
+            Ratio::integer(self.velocity[axis])
+                .checked_mul(times[0])
+                .and_then(|offset| offset.checked_add(Ratio::integer(self.position[axis])))
+                .is_some_and(|crossing| {
+                    (Ratio::integer(min)..=Ratio::integer(max)).contains(&crossing)
+                })
+        })
+    }
+
+    /// The time at which this hailstone passes through the plane through the origin
+    /// with the given `normal`, if it does so at a whole time that can be worked out
+    /// in an `i128`.
+    fn meets_plane(&self, normal: Vector) -> Option<i128> {
+        let speed = dot(self.velocity, normal)?;
+        let distance = dot(self.position, normal)?.checked_neg()?;
+        (distance.checked_rem(speed)? == 0).then(|| distance / speed)
+    }
+
+    /// Where this hailstone is at `time`, or `None` if that won't fit in an `i128`.
+    fn at(&self, time: i128) -> Option<Vector> {
+        let [x, y, z] = [0, 1, 2].map(|axis| {
+            time.checked_mul(self.velocity[axis])?
+                .checked_add(self.position[axis])
+        });
+        Some([x?, y?, z?])
+    }
+}
+
+/// The rock that, thrown from some integer position with some integer velocity,
+/// hits every one of `hailstones`.
+#[must_use]
+pub fn rock(hailstones: &[Hailstone]) -> Option<Hailstone> {
+    hailstones.windows(3).find_map(|window| {
+        //Seen from the first hailstone, which then sits still at the origin, the
+        //  rock's path goes through the origin and crosses the path of each of the
+        //  other two. So it lies in the plane through the origin and each of their
+        //  paths, and the times it hits them are where they cross the other plane.
+        let frame = window[0];
+        let [a, b] = [window[1], window[2]].map(|hailstone| Hailstone {
+            position: sub(hailstone.position, frame.position),
+            velocity: sub(hailstone.velocity, frame.velocity),
+        });
+        let (normal_a, normal_b) = (
+            cross(a.position, a.velocity)?,
+            cross(b.position, b.velocity)?,
+        );
+        let times = [a.meets_plane(normal_b)?, b.meets_plane(normal_a)?];
+        let hits = [window[1].at(times[0])?, window[2].at(times[1])?];
+
+        //Knowing where the rock is at two different times pins down its path.
+        let [x, y, z] = [0, 1, 2].map(|axis| {
+            let rows: Vec<Vec<i128>> = (0..2)
+                .map(|hit| vec![1, times[hit], hits[hit][axis]])
+                .collect();
+            let solution = solve_integers(&rows)?;
+            Some((solution[0].to_integer()?, solution[1].to_integer()?))
+        });
+        let (x, y, z) = (x?, y?, z?);
+        Some(Hailstone {
+            position: [x.0, y.0, z.0],
+            velocity: [x.1, y.1, z.1],
+        })
+    })
+}
+
+fn vector_parser(input: &str) -> IResult<&str, Vector> {
+    let comma = || tuple((char(','), space0));
+    map(
+        tuple((number, comma(), number, comma(), number)),
+        |(x, _, y, _, z)| [x, y, z].map(i128::from),
+    )(input)
+}
+
+fn hailstone_parser(input: &str) -> IResult<&str, Hailstone> {
+    map(
+        separated_pair(
+            vector_parser,
+            delimited(space0, tag("@"), space0),
+            vector_parser,
+        ),
+        |(position, velocity)| Hailstone { position, velocity },
+    )(input)
+}
+
+/// Every hailstone in `input`, or an error at the first line that isn't a position
+/// and a velocity, each three comma-separated numbers that fit in an `i64`.
+pub fn hailstones_parser(input: &str) -> Result<Vec<Hailstone>> {
+    complete(input, separated_list0(line_ending, hailstone_parser))
+}
+
+#[aoc_generator(day24)]
+pub(super) fn input_generator(input: &str) -> Vec<Hailstone> {
+    match hailstones_parser(input) {
+        Ok(hailstones) => hailstones,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+fn crossings_within(hailstones: &[Hailstone], min: i128, max: i128) -> usize {
+    pairs(hailstones)
+        .filter(|(a, b)| a.crosses_within(b, min, max))
+        .count()
+}
+
+#[aoc(day24, part1)]
+#[must_use]
+pub fn part1(input: &[Hailstone]) -> usize {
+    crossings_within(input, 200_000_000_000_000, 400_000_000_000_000)
+}
+
+#[aoc(day24, part2)]
+#[must_use]
+pub fn part2(input: &[Hailstone]) -> Option<i128> {
+    rock(input)?.position.into_iter().checked_sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+    #[test]
+    fn test_hailstone_parser() {
+        assert_eq!(
+            hailstone_parser("20, 19, 15 @  1, -5, -3"),
+            Ok((
+                "",
+                Hailstone {
+                    position: [20, 19, 15],
+                    velocity: [1, -5, -3]
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_crosses_within() {
+        let hailstones = input_generator(EXAMPLE);
+        assert!(hailstones[0].crosses_within(&hailstones[1], 7, 27));
+        assert!(!hailstones[0].crosses_within(&hailstones[3], 7, 27));
+        //Parallel paths never cross.
+        assert!(!hailstones[1].crosses_within(&hailstones[2], 7, 27));
+        //Crossed in the past.
+        assert!(!hailstones[0].crosses_within(&hailstones[4], 7, 27));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(crossings_within(&input_generator(EXAMPLE), 7, 27), 2);
+    }
+
+    #[test]
+    fn test_rock() {
+        let expected = Hailstone {
+            position: [24, 13, 10],
+            velocity: [-3, 1, 2],
+        };
+        assert_eq!(rock(&input_generator(EXAMPLE)), Some(expected));
+    }
+
+    #[test]
+    fn test_rock_full_size() {
+        //Positions and velocities as large as in real puzzle inputs.
+        let hailstones = input_generator(
+            "356539367795120, 113831836891126, 268758345704252 @ -146, -10, -287
+364572853236584, 311755746259384, -73065099371764 @ -182, -272, 229
+81585209589288, 96758669461096, -187791543098016 @ 154, 11, 211",
+        );
+        let expected = Hailstone {
+            position: [
+                315_533_108_337_704,
+                222_871_208_630_164,
+                194_201_510_327_132,
+            ],
+            velocity: [-102, -127, -207],
+        };
+        assert_eq!(rock(&hailstones), Some(expected));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(47));
+        //Seen from the first, the others are so far off and so fast that the
+        //  planes through their paths can't be worked out in an `i128`.
+        let (min, max) = (i64::MIN, i64::MAX);
+        let extremes = format!(
+            "{min}, {min}, {min} @ {min}, {min}, {min}
+{max}, {max}, {max} @ {max}, {max}, {max}
+{max}, {max}, {max} @ {max}, {max}, {max}"
+        );
+        assert_eq!(part2(&input_generator(&extremes)), None);
+    }
+}
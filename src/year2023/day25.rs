@@ -0,0 +1,82 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, line_ending, space1},
+    multi::{separated_list0, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+use crate::util::graph::{self, Graph};
+
+fn connections_parser(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    separated_pair(alpha1, tag(": "), separated_list1(space1, alpha1))(input)
+}
+
+/// The wiring diagram in `input`, or an error at the first line that isn't a
+/// component and a space-separated list of the components it connects to.
+pub fn wiring_parser(input: &str) -> Result<Graph> {
+    let lines = complete(input, separated_list0(line_ending, connections_parser))?;
+    let edges = lines.into_iter().flat_map(|(component, connected)| {
+        connected.into_iter().map(move |other| (component, other))
+    });
+    Ok(Graph::from_labelled_edges(edges).0)
+}
+
+#[aoc_generator(day25)]
+pub(super) fn input_generator(input: &str) -> Graph {
+    match wiring_parser(input) {
+        Ok(graph) => graph,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day25, part1)]
+#[must_use]
+pub fn part1(input: &Graph) -> Option<usize> {
+    //The puzzle promises that cutting exactly three wires splits the components.
+    let cut = graph::min_cut(input).filter(|cut| cut.weight == 3)?;
+    Some(cut.side.len() * (input.len() - cut.side.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn test_connections_parser() {
+        assert_eq!(
+            connections_parser("jqt: rhn xhk nvd"),
+            Ok(("", ("jqt", vec!["rhn", "xhk", "nvd"])))
+        );
+    }
+
+    #[test]
+    fn test_wiring_parser() {
+        let graph = wiring_parser(EXAMPLE).unwrap();
+        assert_eq!(graph.len(), 15);
+        assert_eq!(graph.neighbors(0).len(), 4);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(54));
+    }
+}
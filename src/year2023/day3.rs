@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use crate::{
+    prelude::*,
+    util::{grid::Grid, point::Point},
+};
+
+/// A number written across the schematic, and the cells around it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Number {
+    pub value: u32,
+    pub surroundings: Vec<Point>,
+}
+
+/// Anything other than a digit or a `.` is a symbol.
+fn is_symbol(cell: u8) -> bool {
+    cell != b'.' && !cell.is_ascii_digit()
+}
+
+/// Every number in the schematic, reading each row left to right, or `None` if one
+/// doesn't fit in a `u32`.
+#[must_use]
+pub fn numbers(schematic: &Grid<u8>) -> Option<Vec<Number>> {
+    let mut numbers = Vec::new();
+    for (y, row) in schematic.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let length = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if length == 0 {
+                x += 1;
+                continue;
+            }
+            let value = row[x..x + length].iter().try_fold(0_u32, |value, &digit| {
+                value.checked_mul(10)?.checked_add(u32::from(digit - b'0'))
+            })?;
+            let mut surroundings: Vec<Point> = (x..x + length)
+                .flat_map(|col| schematic.neighbors8(Point::new(col as i64, y as i64)))
+                .filter(|&point| !schematic[point].is_ascii_digit())
+                .collect();
+            surroundings.sort_unstable_by_key(|point| (point.y, point.x));
+            surroundings.dedup();
+            numbers.push(Number {
+                value,
+                surroundings,
+            });
+            x += length;
+        }
+    }
+    Some(numbers)
+}
+
+#[aoc_generator(day3)]
+pub(super) fn input_generator(input: &str) -> Grid<u8> {
+    match input.parse() {
+        Ok(schematic) => schematic,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day3, part1)]
+#[must_use]
+pub fn part1(input: &Grid<u8>) -> Option<u32> {
+    numbers(input)?
+        .iter()
+        .filter(|number| {
+            number
+                .surroundings
+                .iter()
+                .any(|&point| is_symbol(input[point]))
+        })
+        .map(|number| number.value)
+        .checked_sum()
+}
+
+#[aoc(day3, part2)]
+#[must_use]
+pub fn part2(input: &Grid<u8>) -> Option<u64> {
+    //Collect the numbers next to each `*`; those with exactly two are gears.
+    let mut gears: HashMap<Point, Vec<u32>> = HashMap::new();
+    for number in numbers(input)? {
+        for &point in &number.surroundings {
+            if input[point] == b'*' {
+                gears.entry(point).or_default().push(number.value);
+            }
+        }
+    }
+    gears
+        .values()
+        .filter(|values| values.len() == 2)
+        .map(|values| u64::from(values[0]) * u64::from(values[1]))
+        .checked_sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn test_numbers() {
+        let numbers = numbers(&input_generator(EXAMPLE)).unwrap();
+        assert_eq!(numbers.len(), 10);
+        assert_eq!(numbers[0].value, 467);
+        assert_eq!(numbers[0].surroundings.len(), 5);
+        assert!(numbers[0].surroundings.contains(&Point::new(3, 1)));
+        assert_eq!(numbers[4].value, 617);
+        assert!(super::numbers(&input_generator("4294967296")).is_none());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(4361));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(467_835));
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, space1, u32 as number},
+    combinator::map,
+    multi::{separated_list0, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::{
+    prelude::*,
+    util::parse::{complete_bytes, labeled, ws},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Card {
+    id: u32,
+    winners: Vec<u32>,
+    haves: Vec<u32>,
+}
+
+impl Card {
+    /// The number of our numbers that also appear in the winning numbers.
+    #[must_use]
+    pub fn matches(&self) -> usize {
+        self.haves
+            .iter()
+            .filter(|have| self.winners.contains(have))
+            .count()
+    }
+}
+
+fn number_list_parser(input: &[u8]) -> IResult<&[u8], Vec<u32>> {
+    separated_list1(space1, number)(input)
+}
+
+fn card_parser(input: &[u8]) -> IResult<&[u8], Card> {
+    map(
+        labeled(
+            "Card",
+            separated_pair(number_list_parser, ws(tag("|")), number_list_parser),
+        ),
+        |(id, (winners, haves))| Card { id, winners, haves },
+    )(input)
+}
+
+/// Every card in `input`, or an error pointing at the first line that isn't one.
+pub fn cards_parser(input: &str) -> Result<Vec<Card>> {
+    complete_bytes(input, separated_list0(line_ending, card_parser))
+}
+
+#[aoc_generator(day4)]
+pub(super) fn input_generator(input: &str) -> Vec<Card> {
+    match cards_parser(input) {
+        Ok(cards) => cards,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day4, part1)]
+#[must_use]
+pub fn part1(input: &[Card]) -> Option<u32> {
+    //A card with more than 32 matches is worth more points than a u32 can hold.
+    input
+        .iter()
+        .map(Card::matches)
+        .filter(|&matches| matches > 0)
+        .try_fold(0_u32, |total, matches| {
+            let points = 1_u32.checked_shl(u32::try_from(matches - 1).ok()?)?;
+            total.checked_add(points)
+        })
+}
+
+#[aoc(day4, part2)]
+#[must_use]
+pub fn part2(input: &[Card]) -> Option<u64> {
+    //Every card starts with a single copy. Walking the cards in order, each copy of
+    //  card i wins one copy of each of the next `matches` cards, so the copies can be
+    //  pushed forward in a single pass instead of recursing card by card.
+    let mut copies = vec![1_u64; input.len()];
+    for (idx, card) in input.iter().enumerate() {
+        let end = (idx + 1 + card.matches()).min(input.len());
+        for next in idx + 1..end {
+            copies[next] = copies[next].checked_add(copies[idx])?;
+        }
+    }
+    copies.into_iter().checked_sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_card_parser() {
+        let input = b"Card   3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1";
+        let expected = Card {
+            id: 3,
+            winners: vec![1, 21, 53, 59, 44],
+            haves: vec![69, 82, 63, 72, 16, 21, 14, 1],
+        };
+        assert_eq!(card_parser(input), Ok((&b""[..], expected)));
+    }
+
+    #[test]
+    fn test_cards_parser() {
+        let cards = cards_parser(EXAMPLE).unwrap();
+        assert_eq!(cards.len(), 6);
+        assert_eq!(cards[5].id, 6);
+    }
+
+    #[test]
+    fn test_matches() {
+        let cards = cards_parser(EXAMPLE).unwrap();
+        let matches: Vec<usize> = cards.iter().map(Card::matches).collect();
+        assert_eq!(matches, vec![4, 2, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_part1() {
+        let cards = input_generator(EXAMPLE);
+        assert_eq!(part1(&cards), Some(13));
+    }
+
+    #[test]
+    fn test_part1_too_many_matches() {
+        let numbers = (1..=33)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let cards = input_generator(&format!("Card 1: {numbers} | {numbers}"));
+        assert_eq!(part1(&cards), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        let cards = input_generator(EXAMPLE);
+        assert_eq!(part2(&cards), Some(30));
+        //Each card wins a copy of the next two, so the copies grow like the
+        //  Fibonacci numbers down the pile.
+        let pile: String = (1..=100)
+            .map(|id| format!("Card {id}: 1 2 | 1 2\n"))
+            .collect();
+        assert_eq!(part2(&input_generator(&pile)), None);
+    }
+}
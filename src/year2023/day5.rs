@@ -0,0 +1,200 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::{line_ending, multispace0, multispace1, space1, u32 as unsigned},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+use crate::util::interval::{Interval, PiecewiseMap, RangeSet, Shift};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<PiecewiseMap>,
+}
+
+impl Almanac {
+    fn location(&self, seed: i64) -> i64 {
+        self.maps.iter().fold(seed, |value, map| map.apply(value))
+    }
+}
+
+//Every number is under 2^32, so shifting one through any number of maps can't
+//  take it anywhere near the limits of an `i64`.
+fn number(input: &str) -> IResult<&str, i64> {
+    map(unsigned, i64::from)(input)
+}
+
+fn seeds_parser(input: &str) -> IResult<&str, Vec<i64>> {
+    preceded(
+        tag("seeds:"),
+        preceded(space1, separated_list1(space1, number)),
+    )(input)
+}
+
+fn shift_parser(input: &str) -> IResult<&str, Shift> {
+    map(
+        tuple((number, preceded(space1, number), preceded(space1, number))),
+        |(destination, source, len)| Shift {
+            source: Interval::from_len(source, len),
+            offset: destination - source,
+        },
+    )(input)
+}
+
+fn map_parser(input: &str) -> IResult<&str, PiecewiseMap> {
+    map(
+        preceded(
+            terminated(take_until(" map:"), tuple((tag(" map:"), line_ending))),
+            separated_list1(line_ending, shift_parser),
+        ),
+        PiecewiseMap::new,
+    )(input)
+}
+
+/// The seeds and maps in `input`, or an error at the first thing that isn't a list
+/// of seeds or a named map, or at a number that is negative or doesn't fit in a
+/// `u32`.
+pub fn almanac_parser(input: &str) -> Result<Almanac> {
+    let parser = map(
+        separated_pair(
+            seeds_parser,
+            multispace1,
+            separated_list1(multispace1, map_parser),
+        ),
+        |(seeds, maps)| Almanac { seeds, maps },
+    );
+
+    complete(input, terminated(parser, multispace0))
+}
+
+#[aoc_generator(day5)]
+pub(super) fn input_generator(input: &str) -> Almanac {
+    match almanac_parser(input) {
+        Ok(almanac) => almanac,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day5, part1)]
+#[must_use]
+pub fn part1(input: &Almanac) -> i64 {
+    input
+        .seeds
+        .iter()
+        .map(|&seed| input.location(seed))
+        .min()
+        .unwrap_or_default()
+}
+
+#[aoc(day5, part2)]
+#[must_use]
+pub fn part2(input: &Almanac) -> i64 {
+    //The seeds come in (start, len) pairs. Rather than mapping every seed, push the
+    //  whole ranges through each map, splitting them wherever they cross a boundary.
+    let seeds: RangeSet = input
+        .seeds
+        .chunks_exact(2)
+        .map(|pair| Interval::from_len(pair[0], pair[1]))
+        .collect();
+
+    input
+        .maps
+        .iter()
+        .fold(seeds, |ranges, map| map.apply_set(&ranges))
+        .min()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_seeds_parser() {
+        let input = "seeds: 79 14 55 13\n";
+        assert_eq!(seeds_parser(input), Ok(("\n", vec![79, 14, 55, 13])));
+    }
+
+    #[test]
+    fn test_shift_parser() {
+        let input = "50 98 2";
+        let expected = Shift {
+            source: Interval::new(98, 100),
+            offset: -48,
+        };
+        assert_eq!(shift_parser(input), Ok(("", expected)));
+        assert!(shift_parser("50 98 -2").is_err());
+        assert!(shift_parser("4294967296 0 1").is_err());
+    }
+
+    #[test]
+    fn test_almanac_parser() {
+        let almanac = almanac_parser(EXAMPLE).unwrap();
+        assert_eq!(almanac.seeds, vec![79, 14, 55, 13]);
+        assert_eq!(almanac.maps.len(), 7);
+    }
+
+    #[test]
+    fn test_location() {
+        let almanac = input_generator(EXAMPLE);
+        let locations: Vec<i64> = almanac
+            .seeds
+            .iter()
+            .map(|&seed| almanac.location(seed))
+            .collect();
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn test_part1() {
+        let almanac = input_generator(EXAMPLE);
+        assert_eq!(part1(&almanac), 35);
+    }
+
+    #[test]
+    fn test_part2() {
+        let almanac = input_generator(EXAMPLE);
+        assert_eq!(part2(&almanac), 46);
+    }
+}
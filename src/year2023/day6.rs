@@ -0,0 +1,188 @@
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, space1, u64 as number},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+use crate::{prelude::*, util::parse::complete};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn beaten_by(self, hold: u64) -> bool {
+        //Widen first, since long races go further than a u64 can count.
+        u128::from(hold) * u128::from(self.time - hold) > u128::from(self.distance)
+    }
+
+    /// The number of hold times that beat the record distance.
+    ///
+    /// Holding for `h` travels `h * (time - h)`, so the winning holds are the integers
+    /// strictly between the roots of `h^2 - time*h + distance = 0`. The winners are
+    /// symmetric around `time / 2`, so only the lower bound needs finding.
+    #[must_use]
+    pub fn ways_to_win(self) -> u64 {
+        let (time, distance) = (u128::from(self.time), u128::from(self.distance));
+        let Some(discriminant) = (time * time).checked_sub(4 * distance) else {
+            return 0;
+        };
+        //The lower root is `(time - sqrt(discriminant)) / 2`. Flooring the square root
+        //  and the halving can only land on the last losing hold before it, never
+        //  further back, so at most one step up reaches the first winner.
+        let mut lowest = u64::try_from((time - discriminant.isqrt()) / 2)
+            .expect("the lower root is at most time / 2");
+        if !self.beaten_by(lowest) {
+            lowest += 1;
+        }
+        //An exact double root, or no root in range, leaves nothing to win.
+        if lowest > self.time / 2 || !self.beaten_by(lowest) {
+            return 0;
+        }
+        self.time - 2 * lowest + 1
+    }
+}
+
+/// `left` followed by the digits of `right`, if that fits.
+fn concat_digits(left: u64, right: u64) -> Option<u64> {
+    let shift = 10_u64.checked_pow(right.checked_ilog10().unwrap_or_default() + 1)?;
+    left.checked_mul(shift)?.checked_add(right)
+}
+
+fn row_parser<'a>(label: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<u64>> {
+    preceded(
+        tag(label),
+        preceded(space1, separated_list1(space1, number)),
+    )
+}
+
+/// Every race in the table in `input`, or an error unless it is a `Time:` row and a
+/// `Distance:` row of numbers that fit in a `u64`.
+pub fn races_parser(input: &str) -> Result<Vec<Race>> {
+    let parser = map(
+        separated_pair(row_parser("Time:"), line_ending, row_parser("Distance:")),
+        |(times, distances)| {
+            times
+                .into_iter()
+                .zip(distances)
+                .map(|(time, distance)| Race { time, distance })
+                .collect()
+        },
+    );
+
+    complete(input, parser)
+}
+
+#[aoc_generator(day6)]
+pub(super) fn input_generator(input: &str) -> Vec<Race> {
+    match races_parser(input) {
+        Ok(races) => races,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day6, part1)]
+#[must_use]
+pub fn part1(input: &[Race]) -> Option<u64> {
+    input
+        .iter()
+        .map(|race| race.ways_to_win())
+        .checked_product()
+}
+
+#[aoc(day6, part2)]
+#[must_use]
+pub fn part2(input: &[Race]) -> Option<u64> {
+    //The kerning was wrong: all of the columns are really one race.
+    let race = input.iter().try_fold(
+        Race {
+            time: 0,
+            distance: 0,
+        },
+        |acc, race| {
+            Some(Race {
+                time: concat_digits(acc.time, race.time)?,
+                distance: concat_digits(acc.distance, race.distance)?,
+            })
+        },
+    )?;
+    Some(race.ways_to_win())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn test_races_parser() {
+        let expected = vec![
+            Race {
+                time: 7,
+                distance: 9,
+            },
+            Race {
+                time: 15,
+                distance: 40,
+            },
+            Race {
+                time: 30,
+                distance: 200,
+            },
+        ];
+        assert_eq!(races_parser(EXAMPLE).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        let races = input_generator(EXAMPLE);
+        let ways: Vec<u64> = races.iter().map(|race| race.ways_to_win()).collect();
+        assert_eq!(ways, vec![4, 8, 9]);
+    }
+
+    #[test]
+    fn test_ways_to_win_matches_brute_force() {
+        for time in 0..60 {
+            for distance in 0..(time * time / 4 + 2) {
+                let race = Race { time, distance };
+                let expected = (0..=time).filter(|&hold| race.beaten_by(hold)).count() as u64;
+                assert_eq!(race.ways_to_win(), expected, "{race:?}");
+            }
+        }
+        let long = Race {
+            time: u64::MAX,
+            distance: 0,
+        };
+        assert_eq!(long.ways_to_win(), u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_concat_digits() {
+        assert_eq!(concat_digits(0, 7), Some(7));
+        assert_eq!(concat_digits(7, 15), Some(715));
+        assert_eq!(concat_digits(715, 30), Some(71530));
+        assert_eq!(concat_digits(9, 0), Some(90));
+        assert_eq!(concat_digits(u64::MAX / 10, 99), None);
+    }
+
+    #[test]
+    fn test_part1() {
+        let races = input_generator(EXAMPLE);
+        assert_eq!(part1(&races), Some(288));
+    }
+
+    #[test]
+    fn test_part2() {
+        let races = input_generator(EXAMPLE);
+        assert_eq!(part2(&races), Some(71503));
+    }
+}
@@ -0,0 +1,261 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+use nom::{
+    bytes::complete::take,
+    character::complete::{line_ending, space1, u64 as number},
+    combinator::{map, map_opt},
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::util::parse::complete;
+
+/// Card labels from weakest to strongest under the standard rules.
+const STANDARD_ORDER: &[u8; 13] = b"23456789TJQKA";
+/// Card labels from weakest to strongest when `J` is a joker.
+const JOKER_ORDER: &[u8; 13] = b"J23456789TQKA";
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// Each hand type keyed by its label counts, largest first.
+const HAND_TYPES: [(&[u8], HandType); 7] = [
+    (&[5], HandType::FiveOfAKind),
+    (&[4, 1], HandType::FourOfAKind),
+    (&[3, 2], HandType::FullHouse),
+    (&[3, 1, 1], HandType::ThreeOfAKind),
+    (&[2, 2, 1], HandType::TwoPair),
+    (&[2, 1, 1, 1], HandType::OnePair),
+    (&[1, 1, 1, 1, 1], HandType::HighCard),
+];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Rules {
+    Standard,
+    Jokers,
+}
+
+impl Rules {
+    const fn order(self) -> &'static [u8; 13] {
+        match self {
+            Self::Standard => STANDARD_ORDER,
+            Self::Jokers => JOKER_ORDER,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Hand {
+    hand_type: HandType,
+    /// The strength of each card, in the order they were dealt.
+    strengths: [u8; 5],
+}
+
+impl Hand {
+    /// Classifies `labels` under `rules`, returning `None` if any label is not a card.
+    #[must_use]
+    pub fn new(labels: [u8; 5], rules: Rules) -> Option<Self> {
+        let order = rules.order();
+        let mut strengths = [0; 5];
+        let mut counts = [0_u8; 13];
+        for (strength, label) in strengths.iter_mut().zip(labels) {
+            let position = order.iter().position(|&card| card == label)?;
+            *strength = position as u8;
+            counts[position] += 1;
+        }
+
+        //A joker always does best by copying whichever card we already hold the most of.
+        let jokers = match rules {
+            Rules::Standard => 0,
+            Rules::Jokers => std::mem::take(&mut counts[0]),
+        };
+        let mut counts: Vec<u8> = counts.into_iter().filter(|&count| count > 0).collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match counts.first_mut() {
+            Some(largest) => *largest += jokers,
+            None => counts.push(jokers),
+        }
+
+        let hand_type = HAND_TYPES
+            .iter()
+            .find(|(pattern, _)| *pattern == counts.as_slice())
+            .map(|&(_, hand_type)| hand_type)?;
+        Some(Self {
+            hand_type,
+            strengths,
+        })
+    }
+
+    #[must_use]
+    pub const fn hand_type(&self) -> HandType {
+        self.hand_type
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        //The type decides first; ties are broken by the first card that differs.
+        self.hand_type
+            .cmp(&other.hand_type)
+            .then_with(|| self.strengths.cmp(&other.strengths))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Play {
+    labels: [u8; 5],
+    bid: u64,
+}
+
+//Only takes five labels that are all cards, so every play makes a hand.
+fn labels_parser(input: &str) -> IResult<&str, [u8; 5]> {
+    map_opt(take(5_usize), |labels: &str| {
+        let labels = labels.as_bytes().try_into().ok()?;
+        Hand::new(labels, Rules::Standard).map(|_| labels)
+    })(input)
+}
+
+fn play_parser(input: &str) -> IResult<&str, Play> {
+    map(
+        separated_pair(labels_parser, space1, number),
+        |(labels, bid)| Play { labels, bid },
+    )(input)
+}
+
+/// Every hand and its bid in `input`, or an error at the first line that isn't
+/// five card labels and a bid that fits in a `u64`.
+pub fn plays_parser(input: &str) -> Result<Vec<Play>> {
+    complete(input, separated_list0(line_ending, play_parser))
+}
+
+#[aoc_generator(day7)]
+pub(super) fn input_generator(input: &str) -> Vec<Play> {
+    match plays_parser(input) {
+        Ok(plays) => plays,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+/// Each bid times its hand's rank, summed, or `None` if that overflows.
+fn total_winnings(plays: &[Play], rules: Rules) -> Option<u64> {
+    let mut hands: Vec<(Hand, u64)> = plays
+        .iter()
+        .map(|play| {
+            let hand = Hand::new(play.labels, rules).expect("labels are checked when parsed");
+            (hand, play.bid)
+        })
+        .collect();
+    hands.sort_unstable();
+
+    hands
+        .iter()
+        .zip(1..)
+        .try_fold(0_u64, |total, ((_, bid), rank)| {
+            total.checked_add(bid.checked_mul(rank)?)
+        })
+}
+
+#[aoc(day7, part1)]
+#[must_use]
+pub fn part1(input: &[Play]) -> Option<u64> {
+    total_winnings(input, Rules::Standard)
+}
+
+#[aoc(day7, part2)]
+#[must_use]
+pub fn part2(input: &[Play]) -> Option<u64> {
+    total_winnings(input, Rules::Jokers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    fn hand_type(labels: &[u8; 5], rules: Rules) -> HandType {
+        Hand::new(*labels, rules).unwrap().hand_type()
+    }
+
+    #[test]
+    fn test_play_parser() {
+        let expected = Play {
+            labels: *b"32T3K",
+            bid: 765,
+        };
+        assert_eq!(play_parser("32T3K 765"), Ok(("", expected)));
+        assert!(play_parser("XXXXX 1").is_err());
+        assert!(plays_parser("32T3K 765\nXXXXX 1").is_err());
+    }
+
+    #[test]
+    fn test_classification() {
+        assert_eq!(hand_type(b"AAAAA", Rules::Standard), HandType::FiveOfAKind);
+        assert_eq!(hand_type(b"AA8AA", Rules::Standard), HandType::FourOfAKind);
+        assert_eq!(hand_type(b"23332", Rules::Standard), HandType::FullHouse);
+        assert_eq!(hand_type(b"TTT98", Rules::Standard), HandType::ThreeOfAKind);
+        assert_eq!(hand_type(b"23432", Rules::Standard), HandType::TwoPair);
+        assert_eq!(hand_type(b"A23A4", Rules::Standard), HandType::OnePair);
+        assert_eq!(hand_type(b"23456", Rules::Standard), HandType::HighCard);
+    }
+
+    #[test]
+    fn test_joker_classification() {
+        assert_eq!(hand_type(b"QJJQ2", Rules::Jokers), HandType::FourOfAKind);
+        assert_eq!(hand_type(b"JJJJJ", Rules::Jokers), HandType::FiveOfAKind);
+        assert_eq!(hand_type(b"T55J5", Rules::Jokers), HandType::FourOfAKind);
+        assert_eq!(hand_type(b"2233J", Rules::Jokers), HandType::FullHouse);
+        assert_eq!(hand_type(b"32T3K", Rules::Jokers), HandType::OnePair);
+    }
+
+    #[test]
+    fn test_tiebreak() {
+        let first = Hand::new(*b"33332", Rules::Standard).unwrap();
+        let second = Hand::new(*b"2AAAA", Rules::Standard).unwrap();
+        assert!(first > second);
+
+        let joker = Hand::new(*b"JKKK2", Rules::Jokers).unwrap();
+        let queen = Hand::new(*b"QQQQ2", Rules::Jokers).unwrap();
+        assert!(joker < queen);
+    }
+
+    #[test]
+    fn test_invalid_label() {
+        assert_eq!(Hand::new(*b"2345X", Rules::Standard), None);
+    }
+
+    #[test]
+    fn test_part1() {
+        let plays = input_generator(EXAMPLE);
+        assert_eq!(part1(&plays), Some(6440));
+        let plays = input_generator("23456 1\n23457 9223372036854775808");
+        assert_eq!(part1(&plays), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        let plays = input_generator(EXAMPLE);
+        assert_eq!(part2(&plays), Some(5905));
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, char, line_ending, multispace1},
+    combinator::value,
+    multi::{many1, separated_list1},
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+use crate::util::{cycle, math, parse::complete};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Network {
+    turns: Vec<Turn>,
+    names: Vec<String>,
+    /// The (left, right) successors of each node, by index into `names`.
+    edges: Vec<(usize, usize)>,
+}
+
+impl Network {
+    fn new(turns: Vec<Turn>, nodes: &[(&str, (&str, &str))]) -> Result<Self> {
+        let index: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, _))| (*name, idx))
+            .collect();
+        let lookup = |name: &str| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| anyhow::anyhow!("unknown node {name}"))
+        };
+
+        let edges = nodes
+            .iter()
+            .map(|(_, (left, right))| Ok((lookup(left)?, lookup(right)?)))
+            .collect::<Result<_>>()?;
+        let names = nodes.iter().map(|(name, _)| (*name).to_string()).collect();
+        Ok(Self {
+            turns,
+            names,
+            edges,
+        })
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|node| node == name)
+    }
+
+    fn next(&self, node: usize, turn: Turn) -> usize {
+        match turn {
+            Turn::Left => self.edges[node].0,
+            Turn::Right => self.edges[node].1,
+        }
+    }
+
+    /// The number of steps needed to walk from `start` to a node satisfying `is_end`.
+    fn steps(&self, start: usize, is_end: impl Fn(usize) -> bool) -> Option<usize> {
+        let mut node = start;
+        //If we ever stand on the same node at the same point in the instructions
+        //  twice without finishing, we are going round in circles.
+        let limit = self.names.len() * self.turns.len();
+        for (step, &turn) in self.turns.iter().cycle().enumerate().take(limit + 1) {
+            if is_end(node) {
+                return Some(step);
+            }
+            node = self.next(node, turn);
+        }
+        None
+    }
+
+    fn is_ghost_end(&self, node: usize) -> bool {
+        self.names[node].ends_with('Z')
+    }
+
+    /// The period with which a ghost starting at `start` stands on a `..Z` node.
+    ///
+    /// Returns `None` unless the ghost is on an end node at exactly the positive
+    /// multiples of some period, which is what lets the ghosts be combined with an LCM.
+    #[must_use]
+    pub fn ghost_period(&self, start: usize) -> Option<u64> {
+        let (cycle, states) = cycle::detect((start, 0), |&(node, turn)| {
+            (
+                self.next(node, self.turns[turn]),
+                (turn + 1) % self.turns.len(),
+            )
+        });
+        let hits: Vec<bool> = states
+            .iter()
+            .map(|&(node, _)| self.is_ghost_end(node))
+            .collect();
+
+        let period = hits.iter().skip(1).position(|&hit| hit)? + 1;
+        let periodic = cycle.length % period == 0
+            && hits
+                .iter()
+                .enumerate()
+                .skip(1)
+                .all(|(step, &hit)| hit == (step % period == 0));
+        periodic.then_some(period as u64)
+    }
+}
+
+fn turns_parser(input: &str) -> IResult<&str, Vec<Turn>> {
+    many1(alt((
+        value(Turn::Left, char('L')),
+        value(Turn::Right, char('R')),
+    )))(input)
+}
+
+fn node_parser(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    separated_pair(
+        alphanumeric1,
+        tag(" = "),
+        delimited(
+            char('('),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            char(')'),
+        ),
+    )(input)
+}
+
+/// The instructions and nodes in `input`, or an error if the instructions aren't all
+/// `L` and `R`, a line isn't a node and its two neighbours, or a neighbour is never
+/// defined.
+pub fn network_parser(input: &str) -> Result<Network> {
+    let parser = separated_pair(
+        turns_parser,
+        multispace1,
+        separated_list1(line_ending, node_parser),
+    );
+
+    let (turns, nodes) = complete(input, parser)?;
+    Network::new(turns, &nodes)
+}
+
+#[aoc_generator(day8)]
+pub(super) fn input_generator(input: &str) -> Network {
+    match network_parser(input) {
+        Ok(network) => network,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[aoc(day8, part1)]
+#[must_use]
+pub fn part1(input: &Network) -> Option<usize> {
+    let start = input.find("AAA")?;
+    let end = input.find("ZZZ")?;
+    input.steps(start, |node| node == end)
+}
+
+#[aoc(day8, part2)]
+#[must_use]
+pub fn part2(input: &Network) -> Option<u64> {
+    //Every ghost loops round its own cycle, so they all line up on the least
+    //  common multiple of their periods.
+    input
+        .names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| name.ends_with('A'))
+        .map(|(start, _)| input.ghost_period(start))
+        .try_fold(1, |lcm, period| math::checked_lcm(lcm, period?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_2: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_3: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn test_turns_parser() {
+        assert_eq!(
+            turns_parser("LLR\n"),
+            Ok(("\n", vec![Turn::Left, Turn::Left, Turn::Right]))
+        );
+    }
+
+    #[test]
+    fn test_node_parser() {
+        assert_eq!(
+            node_parser("AAA = (BBB, CCC)"),
+            Ok(("", ("AAA", ("BBB", "CCC"))))
+        );
+    }
+
+    #[test]
+    fn test_unknown_node() {
+        assert!(network_parser("L\n\nAAA = (BBB, AAA)").is_err());
+    }
+
+    #[test]
+    fn test_ghost_period() {
+        let network = input_generator(EXAMPLE_3);
+        let periods: Vec<Option<u64>> = ["11A", "22A"]
+            .iter()
+            .map(|name| network.ghost_period(network.find(name).unwrap()))
+            .collect();
+        assert_eq!(periods, vec![Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE_1)), Some(2));
+        assert_eq!(part1(&input_generator(EXAMPLE_2)), Some(6));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE_3)), Some(6));
+    }
+}
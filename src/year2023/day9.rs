@@ -0,0 +1,153 @@
+use std::io::BufRead;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    input::read_lines,
+    prelude::*,
+    util::parse::{number_lines, numbers},
+};
+
+#[aoc_generator(day9)]
+pub(super) fn input_generator(input: &str) -> Vec<Vec<i64>> {
+    match number_lines(input) {
+        Ok(sequences) => sequences,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+/// The histories in `reader`, parsed one line at a time rather than all at once.
+pub fn stream_generator(reader: impl BufRead) -> impl Iterator<Item = Result<Vec<i64>>> {
+    read_lines(reader, numbers::<i64>)
+}
+
+/// The sequence followed by each row of differences, down to the first row of all zeros,
+/// or `None` if a difference overflows.
+fn difference_rows(sequence: &[i64]) -> Option<Vec<Vec<i64>>> {
+    let mut rows = vec![sequence.to_vec()];
+    while let Some(row) = rows.last().filter(|row| row.iter().any(|&x| x != 0)) {
+        let differences = row
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(pair[0]))
+            .collect::<Option<_>>()?;
+        rows.push(differences);
+    }
+    Some(rows)
+}
+
+/// The value that would come after the end of `sequence`, or `None` if it doesn't
+/// fit in an `i64`.
+#[must_use]
+pub fn extrapolate_forward(sequence: &[i64]) -> Option<i64> {
+    //Each row's next value is its last value plus the next value of the row below,
+    //  so summing the last value of every row does the whole climb at once.
+    difference_rows(sequence)?
+        .iter()
+        .filter_map(|row| row.last())
+        .copied()
+        .checked_sum()
+}
+
+/// The value that would come before the start of `sequence`, or `None` if it
+/// doesn't fit in an `i64`.
+#[must_use]
+pub fn extrapolate_backward(sequence: &[i64]) -> Option<i64> {
+    //Going backwards each row subtracts the row below, so the signs alternate.
+    difference_rows(sequence)?
+        .iter()
+        .filter_map(|row| row.first())
+        .rev()
+        .try_fold(0, |below, first| first.checked_sub(below))
+}
+
+/// The sum of `extrapolate` over every history, or `None` if it overflows.
+fn total(input: &[Vec<i64>], extrapolate: fn(&[i64]) -> Option<i64>) -> Option<i64> {
+    input.iter().try_fold(0_i64, |total, sequence| {
+        total.checked_add(extrapolate(sequence)?)
+    })
+}
+
+/// [`total`] over histories as they are read.
+fn total_streaming(reader: impl BufRead, extrapolate: fn(&[i64]) -> Option<i64>) -> Result<i64> {
+    stream_generator(reader).try_fold(0_i64, |total, sequence| {
+        extrapolate(&sequence?)
+            .and_then(|value| total.checked_add(value))
+            .context("the answer doesn't fit in an i64")
+    })
+}
+
+#[aoc(day9, part1)]
+#[must_use]
+pub fn part1(input: &[Vec<i64>]) -> Option<i64> {
+    total(input, extrapolate_forward)
+}
+
+#[aoc(day9, part2)]
+#[must_use]
+pub fn part2(input: &[Vec<i64>]) -> Option<i64> {
+    total(input, extrapolate_backward)
+}
+
+/// [`part1`] over histories as they are read, so the input is never held whole.
+pub fn part1_streaming(reader: impl BufRead) -> Result<i64> {
+    total_streaming(reader, extrapolate_forward)
+}
+
+/// [`part2`] over histories as they are read, so the input is never held whole.
+pub fn part2_streaming(reader: impl BufRead) -> Result<i64> {
+    total_streaming(reader, extrapolate_backward)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn test_input_generator() {
+        assert_eq!(input_generator("4 -2 0 -15\n"), vec![vec![4, -2, 0, -15]]);
+    }
+
+    #[test]
+    fn test_difference_rows() {
+        let expected = vec![vec![0, 3, 6, 9], vec![3, 3, 3], vec![0, 0]];
+        assert_eq!(difference_rows(&[0, 3, 6, 9]), Some(expected));
+        assert_eq!(difference_rows(&[i64::MIN, 1]), None);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(extrapolate_forward(&[10, 13, 16, 21, 30, 45]), Some(68));
+        assert_eq!(extrapolate_backward(&[10, 13, 16, 21, 30, 45]), Some(5));
+        assert_eq!(extrapolate_forward(&[-3, -6, -9]), Some(-12));
+        assert_eq!(extrapolate_forward(&[0, i64::MAX]), None);
+        assert_eq!(extrapolate_backward(&[i64::MIN, 0]), None);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(114));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(2));
+    }
+
+    #[test]
+    fn test_streaming() {
+        assert_eq!(part1_streaming(EXAMPLE.as_bytes()).unwrap(), 114);
+        assert_eq!(part2_streaming(EXAMPLE.as_bytes()).unwrap(), 2);
+        let error = part1_streaming("1 2\n3 x".as_bytes()).unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            "line 2: \"x\" is not a number: invalid digit found in string"
+        );
+        let error = part1_streaming("0 9223372036854775807".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "the answer doesn't fit in an i64");
+    }
+}
@@ -0,0 +1,83 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::{runner::Solution, solution};
+
+/// Every solution for 2023.
+#[must_use]
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2023, 1, 1, day1::part1),
+        solution!(2023, 1, 2, day1::part2),
+        solution!(2023, 2, 1, day2::input_generator => day2::part1),
+        solution!(2023, 2, 2, day2::input_generator => day2::part2),
+        solution!(2023, 3, 1, day3::input_generator => day3::part1),
+        solution!(2023, 3, 2, day3::input_generator => day3::part2),
+        solution!(2023, 4, 1, day4::input_generator => day4::part1),
+        solution!(2023, 4, 2, day4::input_generator => day4::part2),
+        solution!(2023, 5, 1, day5::input_generator => day5::part1),
+        solution!(2023, 5, 2, day5::input_generator => day5::part2),
+        solution!(2023, 6, 1, day6::input_generator => day6::part1),
+        solution!(2023, 6, 2, day6::input_generator => day6::part2),
+        solution!(2023, 7, 1, day7::input_generator => day7::part1),
+        solution!(2023, 7, 2, day7::input_generator => day7::part2),
+        solution!(2023, 8, 1, day8::input_generator => day8::part1),
+        solution!(2023, 8, 2, day8::input_generator => day8::part2),
+        solution!(2023, 9, 1, day9::input_generator => day9::part1, stream day9::part1_streaming),
+        solution!(2023, 9, 2, day9::input_generator => day9::part2, stream day9::part2_streaming),
+        solution!(2023, 10, 1, day10::input_generator => day10::part1),
+        solution!(2023, 10, 2, day10::input_generator => day10::part2),
+        solution!(2023, 11, 1, day11::input_generator => day11::part1),
+        solution!(2023, 11, 2, day11::input_generator => day11::part2),
+        solution!(2023, 12, 1, day12::input_generator => day12::part1),
+        solution!(2023, 12, 2, day12::input_generator => day12::part2),
+        solution!(2023, 13, 1, day13::input_generator => day13::part1),
+        solution!(2023, 13, 2, day13::input_generator => day13::part2),
+        solution!(2023, 14, 1, day14::input_generator => day14::part1),
+        solution!(2023, 14, 2, day14::input_generator => day14::part2),
+        solution!(2023, 15, 1, day15::input_generator => day15::part1),
+        solution!(2023, 15, 2, day15::input_generator => day15::part2),
+        solution!(2023, 16, 1, day16::input_generator => day16::part1),
+        solution!(2023, 16, 2, day16::input_generator => day16::part2),
+        solution!(2023, 17, 1, day17::input_generator => day17::part1),
+        solution!(2023, 17, 2, day17::input_generator => day17::part2),
+        solution!(2023, 18, 1, day18::input_generator => day18::part1),
+        solution!(2023, 18, 2, day18::input_generator => day18::part2),
+        solution!(2023, 19, 1, day19::input_generator => day19::part1),
+        solution!(2023, 19, 2, day19::input_generator => day19::part2),
+        solution!(2023, 20, 1, day20::input_generator => day20::part1),
+        solution!(2023, 20, 2, day20::input_generator => day20::part2),
+        solution!(2023, 21, 1, day21::input_generator => day21::part1),
+        solution!(2023, 21, 2, day21::input_generator => day21::part2),
+        solution!(2023, 22, 1, day22::input_generator => day22::part1),
+        solution!(2023, 22, 2, day22::input_generator => day22::part2),
+        solution!(2023, 23, 1, day23::input_generator => day23::part1),
+        solution!(2023, 23, 2, day23::input_generator => day23::part2),
+        solution!(2023, 24, 1, day24::input_generator => day24::part1),
+        solution!(2023, 24, 2, day24::input_generator => day24::part2),
+        solution!(2023, 25, 1, day25::input_generator => day25::part1),
+    ]
+}
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use nom::{
+    character::complete::{line_ending, space1, u64 as number},
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::{
+    prelude::*,
+    util::{counter::Counter, parse::complete},
+};
+
+/// The two historians' lists of location IDs.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lists {
+    left: Vec<u64>,
+    right: Vec<u64>,
+}
+
+fn pair_parser(input: &str) -> IResult<&str, (u64, u64)> {
+    separated_pair(number, space1, number)(input)
+}
+
+/// The two lists of location IDs in `input`, or an error at the first line that
+/// isn't two IDs that fit in a `u64`.
+pub fn lists_parser(input: &str) -> Result<Lists> {
+    let pairs = complete(input, separated_list0(line_ending, pair_parser))?;
+    let (left, right) = pairs.into_iter().unzip();
+    Ok(Lists { left, right })
+}
+
+pub(super) fn input_generator(input: &str) -> Lists {
+    match lists_parser(input) {
+        Ok(lists) => lists,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &Lists) -> Option<u64> {
+    //Pair the smallest with the smallest, the second smallest with the second
+    //  smallest, and so on.
+    let mut left = input.left.clone();
+    let mut right = input.right.clone();
+    left.sort_unstable();
+    right.sort_unstable();
+    left.iter()
+        .zip(&right)
+        .map(|(a, b)| a.abs_diff(*b))
+        .checked_sum()
+}
+
+#[must_use]
+pub fn part2(input: &Lists) -> Option<u64> {
+    let counts: Counter<u64> = input.right.iter().copied().collect();
+    input.left.iter().try_fold(0_u64, |total, id| {
+        total.checked_add(id.checked_mul(counts.get(id) as u64)?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn test_lists_parser() {
+        let lists = lists_parser(EXAMPLE).unwrap();
+        assert_eq!(lists.left, vec![3, 4, 2, 1, 3, 3]);
+        assert_eq!(lists.right, vec![4, 3, 5, 3, 9, 3]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), Some(11));
+        assert_eq!(
+            part1(&input_generator("0   18446744073709551615\n0   1")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), Some(31));
+        let ids = "9223372036854775808   9223372036854775808\n1   9223372036854775808";
+        assert_eq!(part2(&input_generator(ids)), None);
+    }
+}
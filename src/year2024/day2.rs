@@ -0,0 +1,86 @@
+use crate::{prelude::*, util::parse::number_lines};
+
+type Report = Vec<u32>;
+
+/// Whether the levels all increase or all decrease, by between one and three each
+/// time.
+fn is_safe(levels: impl Iterator<Item = u32> + Clone) -> bool {
+    let mut steps = levels.windows_of::<2>();
+    let gradual = |[a, b]: [u32; 2]| (1..=3).contains(&a.abs_diff(b));
+    steps.clone().all(|[a, b]| a < b && gradual([a, b]))
+        || steps.all(|[a, b]| a > b && gradual([a, b]))
+}
+
+/// Whether the report is safe once at most one level is ignored.
+#[must_use]
+pub fn is_tolerably_safe(report: &[u32]) -> bool {
+    let without = |skipped: usize| {
+        report
+            .iter()
+            .enumerate()
+            .filter(move |&(index, _)| index != skipped)
+            .map(|(_, &level)| level)
+    };
+    is_safe(report.iter().copied()) || (0..report.len()).any(|skipped| is_safe(without(skipped)))
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<Report> {
+    match number_lines(input) {
+        Ok(reports) => reports,
+        Err(e) => panic!("{}", e.to_string()),
+    }
+}
+
+#[must_use]
+pub fn part1(input: &[Report]) -> usize {
+    input
+        .iter()
+        .filter(|report| is_safe(report.iter().copied()))
+        .count()
+}
+
+#[must_use]
+pub fn part2(input: &[Report]) -> usize {
+    input
+        .iter()
+        .filter(|report| is_tolerably_safe(report))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn test_is_safe() {
+        assert!(is_safe([7, 6, 4, 2, 1].into_iter()));
+        assert!(!is_safe([1, 2, 7, 8, 9].into_iter()));
+        assert!(!is_safe([8, 6, 4, 4, 1].into_iter()));
+    }
+
+    #[test]
+    fn test_is_tolerably_safe() {
+        assert!(is_tolerably_safe(&[1, 3, 2, 4, 5]));
+        assert!(!is_tolerably_safe(&[9, 7, 6, 2, 1]));
+        //Dropping the first level is enough.
+        assert!(is_tolerably_safe(&[10, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(EXAMPLE)), 4);
+    }
+}
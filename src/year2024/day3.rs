@@ -0,0 +1,114 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::char,
+    combinator::{map, map_res, value},
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+/// Parses a number of one to three digits, as `mul` requires.
+fn operand_parser(input: &str) -> IResult<&str, u32> {
+    map_res(
+        take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
+        str::parse,
+    )(input)
+}
+
+fn instruction_parser(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        map(
+            delimited(
+                tag("mul("),
+                separated_pair(operand_parser, char(','), operand_parser),
+                char(')'),
+            ),
+            |(a, b)| Instruction::Mul(a, b),
+        ),
+        value(Instruction::Do, tag("do()")),
+        value(Instruction::Dont, tag("don't()")),
+    ))(input)
+}
+
+/// Scans the corrupted memory for every intact instruction, skipping over anything
+/// else.
+#[must_use]
+pub fn scan(memory: &str) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    let mut rest = memory;
+    while let Some(c) = rest.chars().next() {
+        match instruction_parser(rest) {
+            Ok((remaining, instruction)) => {
+                instructions.push(instruction);
+                rest = remaining;
+            }
+            Err(_) => rest = &rest[c.len_utf8()..],
+        }
+    }
+    instructions
+}
+
+pub(super) fn input_generator(input: &str) -> Vec<Instruction> {
+    scan(input)
+}
+
+#[must_use]
+pub fn part1(input: &[Instruction]) -> u64 {
+    input
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(a, b) => u64::from(a * b),
+            Instruction::Do | Instruction::Dont => 0,
+        })
+        .sum()
+}
+
+#[must_use]
+pub fn part2(input: &[Instruction]) -> u64 {
+    let mut enabled = true;
+    let mut total = 0;
+    for instruction in input {
+        match instruction {
+            Instruction::Mul(a, b) if enabled => total += u64::from(a * b),
+            Instruction::Mul(..) => {}
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+    const CONDITIONAL: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            scan("mul(4*mul(1234,5)mul(12,345)do()"),
+            vec![Instruction::Mul(12, 345), Instruction::Do]
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&input_generator(EXAMPLE)), 161);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&input_generator(CONDITIONAL)), 48);
+    }
+}
@@ -0,0 +1,18 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+
+use crate::{runner::Solution, solution};
+
+/// Every solution for 2024.
+#[must_use]
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2024, 1, 1, day1::input_generator => day1::part1),
+        solution!(2024, 1, 2, day1::input_generator => day1::part2),
+        solution!(2024, 2, 1, day2::input_generator => day2::part1),
+        solution!(2024, 2, 2, day2::input_generator => day2::part2),
+        solution!(2024, 3, 1, day3::input_generator => day3::part1),
+        solution!(2024, 3, 2, day3::input_generator => day3::part2),
+    ]
+}